@@ -9,19 +9,34 @@ use crate::config::LintDependencyEntry;
 use crate::error::prelude::*;
 use crate::observability::display::{self, print_stage};
 use crate::observability::prelude::*;
-use camino::Utf8PathBuf;
-use itertools::Itertools;
-use std::collections::BTreeMap;
+use crate::utils::utf8::IntoUtf8;
+use camino::{Utf8Path, Utf8PathBuf};
+use cargo_metadata::diagnostic::{Diagnostic, DiagnosticLevel};
+use cargo_metadata::{CompilerMessage, Message, PackageId, Target};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::BufReader;
+use std::num::NonZeroUsize;
+use std::process::Stdio;
+use std::sync::mpsc;
+use std::time::Duration;
 
+pub mod baseline;
 pub mod cargo;
 pub mod driver;
+pub mod fix;
 pub mod lints;
+pub mod sarif;
+#[cfg(test)]
+mod test_utils;
+pub mod timeout;
 pub mod toolchain;
 
 /// Markers configuration for any action that requires lint crates to be available.
 ///
 /// It's assumed that all paths in this struct are absolute paths.
 #[derive(Debug)]
+// The bools are independent options
+#[allow(clippy::struct_excessive_bools)]
 pub struct Config {
     /// The base directory used by Marker to fetch and compile lints.
     /// This will default to something like `./target/marker`.
@@ -32,23 +47,81 @@ pub struct Config {
     pub marker_dir: Utf8PathBuf,
     /// The list of lints.
     pub lints: BTreeMap<String, LintDependencyEntry>,
+    /// Glob patterns of files, for which no lints should be emitted.
+    pub excluded_files: Vec<String>,
     /// Additional flags, which should be passed to rustc during the compilation
     /// of crates.
     pub build_rustc_flags: String,
     /// Indicates if this is a release or debug build.
     pub debug_build: bool,
+    /// The maximum number of lint crates, which are compiled in parallel.
+    pub build_jobs: NonZeroUsize,
+    /// The lockfile, which stores the resolved versions of the lint crates.
+    /// It's read before the lint crates are fetched and updated afterwards.
+    /// No lockfile is used, if this is `None` or if all lint crates are
+    /// specified by a path.
+    ///
+    /// The lockfile only pins the lint crates themselves. The dependencies of
+    /// the lint crates are resolved, when the lint crates are compiled.
+    pub lockfile: Option<Utf8PathBuf>,
+    /// Indicates that the lint crates have to be resolved to the versions in
+    /// the lockfile. Fetching the lint crates fails, if the lockfile is
+    /// missing or would be updated.
+    pub locked: bool,
+    /// Indicates that the driver should record and print the time spent in
+    /// the lint passes.
+    pub timings: bool,
+    /// Indicates that the diagnostics should be buffered and rendered sorted by
+    /// their location, once the check is done. See [`run_check_collect`].
+    pub sort_diagnostics: bool,
+    /// The baseline, which contains the findings that should not be reported.
+    /// See [`baseline::Baseline`].
+    pub baseline: Option<Utf8PathBuf>,
+    /// The file, where the findings of this run should be recorded as a baseline.
+    pub write_baseline: Option<Utf8PathBuf>,
+    /// The time after which the linting subprocess is terminated. The linting
+    /// can take indefinitely, if this is `None`. See [`timeout`].
+    pub timeout: Option<Duration>,
     pub toolchain: Toolchain,
 }
 
 impl Config {
     pub fn try_base_from(toolchain: Toolchain) -> Result<Self> {
-        Ok(Self {
-            marker_dir: toolchain.find_target_dir()?.join("marker"),
+        let marker_dir = toolchain.find_target_dir()?.join("marker");
+        Ok(Self::base_from(toolchain, marker_dir))
+    }
+
+    /// Creates the base configuration for linting a single file without a
+    /// Cargo project. The marker directory is placed in `CARGO_TARGET_DIR`, or
+    /// in the `target` directory of the current directory, like Cargo does.
+    pub fn try_standalone_from(toolchain: Toolchain) -> Result<Self> {
+        let target_dir = match std::env::var("CARGO_TARGET_DIR") {
+            Ok(dir) => Utf8PathBuf::from(dir),
+            Err(_) => Utf8PathBuf::from("target"),
+        };
+        let current_dir = std::env::current_dir()
+            .context(|| "Failed to get the current directory")?
+            .into_utf8()?;
+        Ok(Self::base_from(toolchain, current_dir.join(target_dir).join("marker")))
+    }
+
+    fn base_from(toolchain: Toolchain, marker_dir: Utf8PathBuf) -> Self {
+        Self {
+            marker_dir,
             lints: BTreeMap::default(),
+            excluded_files: vec![],
             build_rustc_flags: String::new(),
             debug_build: false,
+            build_jobs: std::thread::available_parallelism().unwrap_or(NonZeroUsize::MIN),
+            lockfile: None,
+            locked: false,
+            timings: false,
+            sort_diagnostics: false,
+            baseline: None,
+            write_baseline: None,
+            timeout: None,
             toolchain,
-        })
+        }
     }
 
     fn markers_target_dir(&self) -> Utf8PathBuf {
@@ -68,16 +141,23 @@ pub struct CheckInfo {
 
 pub fn prepare_check(config: &Config) -> Result<CheckInfo> {
     print_stage("compiling lints");
-    let lints = lints::build_lints(config)?
-        .iter()
-        .map(|LintCrate { name, file }| format!("{name}:{file}"))
-        .join(";");
+    let lints = lint_crates_env(&lints::build_lints(config)?);
 
     #[rustfmt::skip]
     let mut env = vec![
         ("RUSTC_WORKSPACE_WRAPPER", config.toolchain.driver_path.clone().into_string()),
         ("MARKER_LINT_CRATES", lints),
     ];
+    if let Some(configs) = lint_configs(config) {
+        env.push(("MARKER_LINT_CRATES_CONFIG", configs));
+    }
+    if !config.excluded_files.is_empty() {
+        let excluded = serde_json::to_string(&config.excluded_files).expect("strings can be represented as JSON");
+        env.push(("MARKER_EXCLUDED_FILES", excluded));
+    }
+    if config.timings {
+        env.push(("MARKER_TIMINGS", "1".to_string()));
+    }
     if let Some(toolchain) = &config.toolchain.cargo.toolchain {
         env.push(("RUSTUP_TOOLCHAIN", toolchain.into()));
     }
@@ -85,6 +165,35 @@ pub fn prepare_check(config: &Config) -> Result<CheckInfo> {
     Ok(CheckInfo { env })
 }
 
+/// This function serializes the names and paths of the compiled lint crates for
+/// the `MARKER_LINT_CRATES` environment value. The value is a JSON array, which
+/// allows any character in the paths, including `;`, `:` and spaces in Windows
+/// paths, like `\\?\C:\Program Files\lints.dll`.
+fn lint_crates_env(lints: &[LintCrate]) -> String {
+    let lints: Vec<_> = lints
+        .iter()
+        .map(|LintCrate { name, file }| serde_json::json!({ "name": name, "path": file }))
+        .collect();
+    serde_json::to_string(&lints).expect("strings can be represented as JSON")
+}
+
+/// This function serializes the configurations of all lint crates into a JSON
+/// object, mapping the crate names to their configuration. It returns `None`,
+/// if no lint crate has a configuration.
+fn lint_configs(config: &Config) -> Option<String> {
+    let configs: BTreeMap<_, _> = config
+        .lints
+        .iter()
+        .filter_map(|(name, entry)| entry.config.as_ref().map(|config| (name, config)))
+        .collect();
+
+    if configs.is_empty() {
+        return None;
+    }
+
+    Some(serde_json::to_string(&configs).expect("TOML tables can be represented as JSON"))
+}
+
 pub fn run_check(config: &Config, info: CheckInfo, additional_cargo_args: &[String]) -> Result {
     let stage = "linting";
     print_stage(stage);
@@ -95,12 +204,9 @@ pub fn run_check(config: &Config, info: CheckInfo, additional_cargo_args: &[Stri
 
     cmd.envs(info.env);
 
-    let exit_status = cmd
-        .log()
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
+    let deadline = timeout::Deadline::start(config.timeout);
+    let mut child = timeout::spawn(cmd.log(), deadline).expect("could not run cargo");
+    let exit_status = timeout::wait(&mut child, deadline)?;
 
     if exit_status.success() {
         return Ok(());
@@ -108,3 +214,634 @@ pub fn run_check(config: &Config, info: CheckInfo, additional_cargo_args: &[Stri
 
     Err(Error::root(format!("{} finished with an error", display::stage(stage))))
 }
+
+/// Runs the driver directly on the given file, without Cargo. The driver acts
+/// like `rustc` and only emits the metadata of the crate, like `cargo check`.
+/// The additional arguments are passed to rustc, they can be used to select
+/// the crate type or to add `--extern` dependencies.
+pub fn run_file(config: &Config, info: CheckInfo, file: &Utf8Path, additional_rustc_args: &[String]) -> Result {
+    let stage = "linting";
+    print_stage(stage);
+
+    let mut cmd = config.toolchain.driver_command();
+    cmd.arg(file.as_os_str());
+    if !additional_rustc_args.iter().any(|arg| arg.starts_with("--edition")) {
+        cmd.arg("--edition=2021");
+    }
+    cmd.arg("--emit=metadata");
+    cmd.arg("--out-dir");
+    cmd.arg(config.marker_dir.join("file").as_os_str());
+    cmd.args(additional_rustc_args);
+
+    cmd.envs(info.env);
+
+    let deadline = timeout::Deadline::start(config.timeout);
+    let mut child = timeout::spawn(cmd.log(), deadline).expect("could not run the driver");
+    let exit_status = timeout::wait(&mut child, deadline)?;
+
+    if exit_status.success() {
+        return Ok(());
+    }
+
+    Err(Error::root(format!("{} finished with an error", display::stage(stage))))
+}
+
+/// Prints the documentation of the given lint. The lint crates are loaded by
+/// the driver, which is invoked directly, like for [`run_file`].
+pub fn explain_lint(config: &Config, info: CheckInfo, lint: &str) -> Result {
+    let output = run_driver_info(config, info, &["--explain-lint", lint])
+        .context(|| "Failed to run the driver to explain the lint")?;
+
+    if !output.status.success() {
+        return Err(Error::wrap(
+            String::from_utf8_lossy(&output.stderr),
+            format!("Failed to explain the lint `{lint}`"),
+        ));
+    }
+
+    print!("{}", output.stdout.into_utf8()?);
+    Ok(())
+}
+
+/// Prints all lints of the lint crates in the given format, either `human` or
+/// `json`. Like for [`explain_lint`], the lint crates are only loaded by the
+/// driver, no code is linted.
+pub fn list_lints(config: &Config, info: CheckInfo, format: &str) -> Result {
+    let output = run_driver_info(config, info, &["--list-lints", format])
+        .context(|| "Failed to run the driver to list the lints")?;
+
+    if !output.status.success() {
+        return Err(Error::wrap(
+            String::from_utf8_lossy(&output.stderr),
+            "Failed to list the lints",
+        ));
+    }
+
+    print!("{}", output.stdout.into_utf8()?);
+    Ok(())
+}
+
+/// Runs the driver with the given arguments. This is used for arguments, which
+/// make the driver print information about the lint crates and exit, without
+/// compiling anything.
+fn run_driver_info(config: &Config, info: CheckInfo, driver_args: &[&str]) -> std::io::Result<std::process::Output> {
+    config
+        .toolchain
+        .driver_command()
+        .args(driver_args)
+        .envs(info.env)
+        .log()
+        .output()
+}
+
+/// The diagnostics emitted by Marker's lints, collected by [`run_check_collect`].
+#[derive(Debug)]
+pub struct CollectedDiagnostics {
+    /// The diagnostics in the order they were emitted, or sorted by their location,
+    /// if [`Config::sort_diagnostics`] is set. Diagnostics emitted several times,
+    /// like for different targets of a crate, are only included once. Diagnostics
+    /// suppressed by [`Config::baseline`] are not included.
+    pub diagnostics: Vec<Diagnostic>,
+    success: bool,
+}
+
+impl CollectedDiagnostics {
+    /// Returns an error, if the linting process finished with an error.
+    pub fn result(&self) -> Result {
+        if self.success {
+            return Ok(());
+        }
+
+        Err(Error::root(format!(
+            "{} finished with an error",
+            display::stage("linting")
+        )))
+    }
+}
+
+/// This function runs the check like [`run_check`], but uses Cargo's JSON output
+/// to collect the diagnostics emitted by Marker's lints. All diagnostics are
+/// still rendered to stderr.
+///
+/// With [`Config::sort_diagnostics`], all diagnostics are buffered until Cargo
+/// finishes. They are then rendered sorted by their location and lint name. This
+/// makes the output independent of the order in which crates and nodes are checked.
+///
+/// With [`Config::baseline`], diagnostics recorded in the baseline are neither
+/// rendered nor collected. Errors suppressed by the baseline don't fail the
+/// linting. With [`Config::write_baseline`], all diagnostics of Marker's lints
+/// are recorded as a new baseline, once Cargo finishes.
+pub fn run_check_collect(
+    config: &Config,
+    info: CheckInfo,
+    additional_cargo_args: &[String],
+) -> Result<CollectedDiagnostics> {
+    let baseline = config.baseline.as_deref().map(baseline::Baseline::load).transpose()?;
+
+    print_stage("linting");
+
+    let mut cmd = config.toolchain.cargo_with_driver();
+    cmd.arg("check");
+    cmd.arg("--message-format=json-diagnostic-rendered-ansi");
+    cmd.args(additional_cargo_args);
+    cmd.envs(info.env);
+    cmd.stdout(Stdio::piped());
+
+    let deadline = timeout::Deadline::start(config.timeout);
+    let mut child = timeout::spawn(cmd.log(), deadline).expect("could not run cargo");
+    let stdout = child.stdout.take().expect("the stdout of cargo was piped");
+
+    // The output is read on a separate thread, to enforce the timeout while
+    // cargo doesn't print anything.
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for message in Message::parse_stream(BufReader::new(stdout)) {
+            if sender.send(message).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut collector = DiagnosticCollector::new(baseline, config.sort_diagnostics);
+    while let Some(message) = timeout::recv(&mut child, &receiver, deadline)? {
+        let message = message.context(|| "Failed to read the output of cargo")?;
+        if let Some(rendered) = collector.add(message) {
+            eprint!("{rendered}");
+        }
+    }
+
+    let exit_status = timeout::wait(&mut child, deadline)?;
+    collector.finish(config, exit_status.success())
+}
+
+/// The number of errors, which were reported or suppressed by the baseline.
+#[derive(Debug, Default, Clone, Copy)]
+struct ErrorCount {
+    reported: usize,
+    suppressed: usize,
+}
+
+impl ErrorCount {
+    fn add(&mut self, suppressed: bool) {
+        if suppressed {
+            self.suppressed += 1;
+        } else {
+            self.reported += 1;
+        }
+    }
+
+    /// Returns `true`, if all errors were suppressed by the baseline.
+    fn all_suppressed(self) -> bool {
+        self.reported == 0 && self.suppressed > 0
+    }
+}
+
+/// Processes the compiler messages for [`run_check_collect`]. It collects the
+/// diagnostics of Marker's lints, applies the baseline and decides which messages
+/// should be rendered.
+struct DiagnosticCollector {
+    baseline: Option<baseline::Baseline>,
+    sort_diagnostics: bool,
+    /// The collected diagnostics in the order they were emitted.
+    diagnostics: Vec<Diagnostic>,
+    /// The diagnostics in [`Self::diagnostics`], to skip duplicates from other targets.
+    collected: HashSet<Diagnostic>,
+    /// The diagnostics suppressed by the baseline.
+    suppressed: HashSet<Diagnostic>,
+    /// The messages, which are rendered once Cargo finishes, if
+    /// [`Config::sort_diagnostics`] is set.
+    buffered: Vec<Diagnostic>,
+    buffered_set: HashSet<Diagnostic>,
+    /// The errors of every target, which hasn't finished yet.
+    target_errors: HashMap<(PackageId, Target), ErrorCount>,
+    errors: ErrorCount,
+    /// The number of targets, which failed only due to suppressed errors.
+    suppressed_failures: usize,
+    /// The targets, which emitted messages, but neither an artifact nor an
+    /// error summary. These might have failed without an error message.
+    unfinished_targets: HashSet<(PackageId, Target)>,
+    /// The packages, whose build script was compiled, but not executed.
+    unexecuted_build_scripts: HashSet<PackageId>,
+}
+
+impl DiagnosticCollector {
+    fn new(baseline: Option<baseline::Baseline>, sort_diagnostics: bool) -> Self {
+        Self {
+            baseline,
+            sort_diagnostics,
+            diagnostics: vec![],
+            collected: HashSet::new(),
+            suppressed: HashSet::new(),
+            buffered: vec![],
+            buffered_set: HashSet::new(),
+            target_errors: HashMap::new(),
+            errors: ErrorCount::default(),
+            suppressed_failures: 0,
+            unfinished_targets: HashSet::new(),
+            unexecuted_build_scripts: HashSet::new(),
+        }
+    }
+
+    /// Adds the message and returns the text, which should be rendered right away.
+    fn add(&mut self, message: Message) -> Option<String> {
+        match message {
+            Message::CompilerMessage(msg) => self.add_compiler_message(msg),
+            Message::CompilerArtifact(artifact) => {
+                if artifact.target.is_custom_build() {
+                    self.unexecuted_build_scripts.insert(artifact.package_id.clone());
+                }
+                self.unfinished_targets.remove(&(artifact.package_id, artifact.target));
+                None
+            },
+            Message::BuildScriptExecuted(script) => {
+                self.unexecuted_build_scripts.remove(&script.package_id);
+                None
+            },
+            _ => None,
+        }
+    }
+
+    fn add_compiler_message(&mut self, msg: CompilerMessage) -> Option<String> {
+        let CompilerMessage {
+            package_id,
+            target,
+            message,
+            ..
+        } = msg;
+        let target = (package_id, target);
+
+        if is_error_summary(&message) {
+            self.unfinished_targets.remove(&target);
+
+            // Rustc only knows the total number of errors. The summary is skipped,
+            // if all of them were suppressed.
+            let errors = self.target_errors.remove(&target).unwrap_or_default();
+            if errors.all_suppressed() {
+                self.suppressed_failures += 1;
+                return None;
+            }
+        } else {
+            self.unfinished_targets.insert(target.clone());
+
+            if is_marker_diagnostic(&message)
+                && !self.collected.contains(&message)
+                && !self.suppressed.contains(&message)
+            {
+                if self
+                    .baseline
+                    .as_mut()
+                    .is_some_and(|baseline| baseline.suppress(&message))
+                {
+                    self.suppressed.insert(message.clone());
+                } else {
+                    self.collected.insert(message.clone());
+                    self.diagnostics.push(message.clone());
+                }
+            }
+
+            let suppressed = self.suppressed.contains(&message);
+            if matches!(message.level, DiagnosticLevel::Error | DiagnosticLevel::Ice) {
+                self.target_errors.entry(target).or_default().add(suppressed);
+                self.errors.add(suppressed);
+            }
+            if suppressed {
+                return None;
+            }
+        }
+
+        if !self.sort_diagnostics {
+            return message.rendered;
+        }
+        if self.buffered_set.insert(message.clone()) {
+            self.buffered.push(message);
+        }
+        None
+    }
+
+    /// Returns `true`, if the linting should be considered successful. Cargo fails,
+    /// if errors were emitted, even if all of them were suppressed by the baseline.
+    ///
+    /// The failure of Cargo is only ignored, if it's explained by targets, which
+    /// failed only due to suppressed errors. Other failures, like panicking build
+    /// scripts, don't emit error messages. They are detected by targets and build
+    /// scripts, which didn't finish.
+    fn success(&self, cargo_success: bool) -> bool {
+        cargo_success
+            || (self.suppressed_failures > 0
+                && self.errors.reported == 0
+                && self.unfinished_targets.is_empty()
+                && self.unexecuted_build_scripts.is_empty())
+    }
+
+    /// Renders the buffered messages and writes the baseline, once Cargo finished.
+    fn finish(mut self, config: &Config, cargo_success: bool) -> Result<CollectedDiagnostics> {
+        let success = self.success(cargo_success);
+
+        if self.sort_diagnostics {
+            sort_diagnostics(&mut self.buffered);
+            for rendered in self.buffered.iter().filter_map(|diag| diag.rendered.as_ref()) {
+                eprint!("{rendered}");
+            }
+            sort_diagnostics(&mut self.diagnostics);
+        }
+
+        if let Some(path) = &config.baseline {
+            eprintln!(
+                "Suppressed {} finding(s) recorded in the baseline {path}",
+                self.suppressed.len()
+            );
+        }
+        if let Some(path) = &config.write_baseline {
+            let findings = self.diagnostics.len() + self.suppressed.len();
+            baseline::write_baseline(path, self.diagnostics.iter().chain(&self.suppressed))?;
+            eprintln!("Recorded {findings} finding(s) in the baseline {path}");
+        }
+
+        Ok(CollectedDiagnostics {
+            diagnostics: self.diagnostics,
+            success,
+        })
+    }
+}
+
+/// Returns `true` for the summary, which rustc emits, when it aborts due to errors.
+fn is_error_summary(diag: &Diagnostic) -> bool {
+    diag.code.is_none()
+        && diag.spans.is_empty()
+        && diag.level == DiagnosticLevel::Error
+        && diag.message.starts_with("aborting due to")
+}
+
+/// Sorts the diagnostics by the file, line and column of their primary span and
+/// then by their lint name. Diagnostics without a span, like the summaries of
+/// emitted warnings, are moved to the end, keeping their relative order.
+fn sort_diagnostics(diagnostics: &mut [Diagnostic]) {
+    diagnostics.sort_by_cached_key(|diag| {
+        let key = diag.spans.iter().find(|span| span.is_primary).map(|span| {
+            let code = diag.code.as_ref().map(|code| code.code.clone());
+            (span.file_name.clone(), span.line_start, span.column_start, code)
+        });
+        (key.is_none(), key)
+    });
+}
+
+/// Renders the diagnostics like they are rendered to stderr, but without the
+/// ANSI escape codes used for colors.
+pub fn render_plain(diagnostics: &[Diagnostic]) -> String {
+    let mut output = String::new();
+    for rendered in diagnostics.iter().filter_map(|diag| diag.rendered.as_ref()) {
+        let mut chars = rendered.chars();
+        while let Some(c) = chars.next() {
+            if c != '\x1b' {
+                output.push(c);
+                continue;
+            }
+
+            // Skip the control sequence up to and including its final byte
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    output
+}
+
+/// Lints emitted by Marker are always prefixed with this tool name.
+fn is_marker_diagnostic(diag: &Diagnostic) -> bool {
+    diag.code.as_ref().is_some_and(|code| code.code.starts_with("marker::"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::{artifact, build_script_executed, compiler_message, diagnostic};
+    use super::*;
+
+    #[test]
+    fn test_sort_diagnostics() {
+        let mut diags = vec![
+            diagnostic("summary_b", None),
+            diagnostic("marker::lints::b", Some(("src/main.rs", 2, "b"))),
+            diagnostic("marker::lints::b", Some(("src/lib.rs", 10, "    b"))),
+            diagnostic("summary_a", None),
+            diagnostic("marker::lints::a", Some(("src/main.rs", 2, "b"))),
+            diagnostic("marker::lints::c", Some(("src/lib.rs", 10, " c"))),
+            diagnostic("marker::lints::a", Some(("src/lib.rs", 3, "a"))),
+        ];
+        sort_diagnostics(&mut diags);
+
+        let codes: Vec<_> = diags
+            .iter()
+            .map(|diag| diag.code.as_ref().unwrap().code.as_str())
+            .collect();
+        assert_eq!(
+            codes,
+            [
+                "marker::lints::a",
+                "marker::lints::c",
+                "marker::lints::b",
+                "marker::lints::a",
+                "marker::lints::b",
+                "summary_b",
+                "summary_a",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_plain() {
+        let mut first = diagnostic("marker::lints::a", Some(("src/lib.rs", 1, "a")));
+        first.rendered = Some("\x1b[0m\x1b[1m\x1b[33mwarning\x1b[0m: first\n".to_string());
+        let mut second = diagnostic("marker::lints::b", Some(("src/lib.rs", 2, "b")));
+        second.rendered = Some("warning: second\n".to_string());
+        let mut unrendered = diagnostic("marker::lints::c", None);
+        unrendered.rendered = None;
+
+        assert_eq!(
+            render_plain(&[first, unrendered, second]),
+            "warning: first\nwarning: second\n"
+        );
+    }
+
+    fn error(code: &str) -> Diagnostic {
+        let mut diag = diagnostic(code, Some(("src/lib.rs", 1, code)));
+        diag.level = DiagnosticLevel::Error;
+        diag
+    }
+
+    fn error_summary() -> Diagnostic {
+        let mut diag = error("summary");
+        diag.code = None;
+        diag.spans.clear();
+        diag.message = "aborting due to 2 previous errors".to_string();
+        diag
+    }
+
+    fn collector(baseline: &[Diagnostic], sort_diagnostics: bool) -> DiagnosticCollector {
+        let baseline =
+            baseline::Baseline::from_fingerprints(baseline.iter().filter_map(baseline::Fingerprint::from_diagnostic));
+        DiagnosticCollector::new(Some(baseline), sort_diagnostics)
+    }
+
+    #[test]
+    fn test_collector_deduplicates() {
+        let warning = diagnostic("marker::lints::a", Some(("src/lib.rs", 1, "a")));
+
+        // Without sorting, every message is rendered, like by Cargo
+        let mut unsorted = collector(&[], false);
+        assert_eq!(
+            unsorted.add(compiler_message("a", &warning)).as_deref(),
+            Some("marker::lints::a")
+        );
+        assert_eq!(
+            unsorted.add(compiler_message("b", &warning)).as_deref(),
+            Some("marker::lints::a")
+        );
+        assert_eq!(unsorted.diagnostics, [warning.clone()]);
+
+        let mut sorted = collector(&[], true);
+        assert_eq!(sorted.add(compiler_message("a", &warning)), None);
+        assert_eq!(sorted.add(compiler_message("b", &warning)), None);
+        assert_eq!(sorted.buffered, [warning.clone()]);
+        assert_eq!(sorted.diagnostics, [warning]);
+        assert!(sorted.success(true));
+    }
+
+    #[test]
+    fn test_collector_suppresses_errors() {
+        let suppressed = error("marker::lints::a");
+        let mut collector = collector(&[suppressed.clone()], false);
+
+        // The diagnostic is suppressed for all targets, and the summaries are skipped
+        for package in ["a", "b"] {
+            assert_eq!(collector.add(compiler_message(package, &suppressed)), None);
+            assert_eq!(collector.add(compiler_message(package, &error_summary())), None);
+        }
+        assert!(collector.diagnostics.is_empty());
+        assert_eq!(collector.suppressed.len(), 1);
+        assert!(collector.success(false));
+    }
+
+    #[test]
+    fn test_collector_reports_new_errors() {
+        let suppressed = error("marker::lints::a");
+        let reported = error("marker::lints::b");
+        let mut collector = collector(&[suppressed.clone()], false);
+
+        assert_eq!(collector.add(compiler_message("a", &suppressed)), None);
+        assert_eq!(
+            collector.add(compiler_message("a", &reported)).as_deref(),
+            Some("marker::lints::b")
+        );
+        assert_eq!(
+            collector.add(compiler_message("a", &error_summary())).as_deref(),
+            Some("summary")
+        );
+        assert_eq!(collector.diagnostics, [reported]);
+        assert!(!collector.success(false));
+    }
+
+    #[test]
+    fn test_collector_keeps_other_failures() {
+        let suppressed = error("marker::lints::a");
+
+        // The target didn't abort due to the suppressed error, Cargo failed for
+        // another reason
+        let mut run = collector(&[suppressed.clone()], false);
+        assert_eq!(run.add(compiler_message("a", &suppressed)), None);
+        assert!(!run.success(false));
+
+        // A build script failed without an error message
+        let mut run = collector(&[suppressed.clone()], false);
+        assert_eq!(run.add(artifact("b", "custom-build")), None);
+        assert_eq!(run.add(compiler_message("a", &suppressed)), None);
+        assert_eq!(run.add(compiler_message("a", &error_summary())), None);
+        assert!(!run.success(false));
+        assert_eq!(run.add(build_script_executed("b")), None);
+        assert!(run.success(false));
+
+        // Cargo failed without any errors
+        let run = collector(&[suppressed], false);
+        assert!(!run.success(false));
+        assert!(run.success(true));
+    }
+
+    /// Creates a Cargo project in a new temporary directory and a configuration
+    /// with a driver, which starts a process that never finishes.
+    #[cfg(unix)]
+    fn sleeping_driver_config(name: &str) -> (Utf8PathBuf, Config) {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = Utf8PathBuf::try_from(std::env::temp_dir())
+            .unwrap()
+            .join(format!("marker_{name}_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(
+            dir.join("Cargo.toml"),
+            "[package]\nname = \"timeout_test\"\nversion = \"0.1.0\"\n\n[workspace]\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("src/lib.rs"), "").unwrap();
+
+        let driver = dir.join("driver.sh");
+        std::fs::write(&driver, "#!/bin/sh\nsleep 60 &\nwait\n").unwrap();
+        std::fs::set_permissions(&driver, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let toolchain = Toolchain {
+            driver_path: driver,
+            cargo: cargo::Cargo::default(),
+        };
+        let mut config = Config::base_from(toolchain, dir.join("marker"));
+        config.timeout = Some(Duration::from_secs(1));
+        (dir, config)
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_run_check_timeout() {
+        let (dir, config) = sleeping_driver_config("run_check_timeout");
+        let cargo_args = [
+            "--manifest-path".to_string(),
+            dir.join("Cargo.toml").into_string(),
+            "--target-dir".to_string(),
+            dir.join("target").into_string(),
+        ];
+        let info = || CheckInfo { env: vec![] };
+        let is_timeout = |err: Error| matches!(err.kind(), Some(ErrorKind::Timeout { seconds: 1 }));
+
+        let start = std::time::Instant::now();
+        assert!(is_timeout(run_check(&config, info(), &cargo_args).unwrap_err()));
+        assert!(is_timeout(run_check_collect(&config, info(), &cargo_args).unwrap_err()));
+        assert!(is_timeout(
+            run_file(&config, info(), &dir.join("src/lib.rs"), &[]).unwrap_err()
+        ));
+        // All functions return after their timeout, instead of waiting for the driver
+        assert!(start.elapsed() < Duration::from_secs(30));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_lint_crates_env() {
+        let lint = |name: &str, file: &str| LintCrate {
+            name: name.to_string(),
+            file: Utf8PathBuf::from(file),
+        };
+
+        assert_eq!(lint_crates_env(&[]), "[]");
+
+        // Separators and spaces are kept as is, backslashes are escaped
+        let lints = [
+            lint("lints", "/home/user/dir with spaces;and:separators/liblints.so"),
+            lint("win_lints", r"\\?\C:\Program Files\lints.dll"),
+        ];
+        assert_eq!(
+            lint_crates_env(&lints),
+            r#"[{"name":"lints","path":"/home/user/dir with spaces;and:separators/liblints.so"},"#.to_string()
+                + r#"{"name":"win_lints","path":"\\\\?\\C:\\Program Files\\lints.dll"}]"#
+        );
+    }
+}
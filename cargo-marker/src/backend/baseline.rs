@@ -0,0 +1,175 @@
+//! This module is responsible for `cargo marker --baseline` and `--write-baseline`.
+//! A baseline records the findings of Marker's lints, to only report new findings
+//! on later runs. This allows the adoption of new lints in large code bases.
+//!
+//! Findings are identified by a [`Fingerprint`], consisting of the lint name, the
+//! file and a hash of the linted code. Line numbers are not part of the fingerprint,
+//! findings therefore stay suppressed, when unrelated code is added or removed.
+
+use crate::error::prelude::*;
+use camino::Utf8Path;
+use cargo_metadata::diagnostic::Diagnostic;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The version of the baseline format. It has to be incremented, if the
+/// fingerprint changes, since old baselines would no longer match.
+const BASELINE_VERSION: u32 = 1;
+
+/// The stable identity of a finding.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Fingerprint {
+    lint: String,
+    file: String,
+    hash: String,
+}
+
+impl Fingerprint {
+    /// Creates the fingerprint of the given diagnostic. The code of the primary
+    /// span is hashed, with the indentation of every line removed. Diagnostics
+    /// without a lint name or a primary span have no fingerprint.
+    pub fn from_diagnostic(diag: &Diagnostic) -> Option<Self> {
+        let lint = &diag.code.as_ref()?.code;
+        let span = diag.spans.iter().find(|span| span.is_primary)?;
+
+        let content: Vec<_> = span
+            .text
+            .iter()
+            .map(|line| {
+                let start = line.highlight_start.saturating_sub(1);
+                let end = line.highlight_end.saturating_sub(1).max(start);
+                let highlighted: String = line.text.chars().skip(start).take(end - start).collect();
+                highlighted.trim().to_string()
+            })
+            .collect();
+
+        Some(Self {
+            lint: lint.clone(),
+            file: span.file_name.replace('\\', "/"),
+            hash: format!("{:016x}", fnv1a(content.join("\n").as_bytes())),
+        })
+    }
+}
+
+/// The 64-bit FNV-1a hash. The hashers of the standard library are not used,
+/// since their output is not guaranteed to be stable between Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    bytes
+        .iter()
+        .fold(OFFSET_BASIS, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(PRIME))
+}
+
+/// The format of the baseline file.
+#[derive(Serialize, Deserialize, Debug)]
+struct BaselineFile {
+    version: u32,
+    findings: Vec<Fingerprint>,
+}
+
+/// The findings of a loaded baseline. Every recorded finding suppresses one
+/// matching diagnostic. Findings with the same fingerprint, like two identical
+/// expressions in one file, are therefore counted.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    remaining: BTreeMap<Fingerprint, usize>,
+}
+
+impl Baseline {
+    pub fn load(path: &Utf8Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).context(|| format!("Failed to read the baseline at {path}"))?;
+        let file: BaselineFile =
+            serde_json::from_str(&content).context(|| format!("Failed to parse the baseline at {path}"))?;
+
+        if file.version != BASELINE_VERSION {
+            return Err(Error::root(format!(
+                "The baseline at {path} has the unsupported version {}, it has to be recreated with `--write-baseline`",
+                file.version
+            )));
+        }
+
+        Ok(Self::from_fingerprints(file.findings))
+    }
+
+    pub(crate) fn from_fingerprints(fingerprints: impl IntoIterator<Item = Fingerprint>) -> Self {
+        let mut remaining = BTreeMap::new();
+        for fingerprint in fingerprints {
+            *remaining.entry(fingerprint).or_default() += 1;
+        }
+        Self { remaining }
+    }
+
+    /// Returns `true`, if the diagnostic was recorded in the baseline. The
+    /// matching finding is consumed, to not suppress additional occurrences.
+    pub fn suppress(&mut self, diag: &Diagnostic) -> bool {
+        let Some(count) = Fingerprint::from_diagnostic(diag).and_then(|fp| self.remaining.get_mut(&fp)) else {
+            return false;
+        };
+        if *count == 0 {
+            return false;
+        }
+        *count -= 1;
+        true
+    }
+}
+
+/// Writes the findings of the given diagnostics as a baseline. The findings
+/// are sorted, to keep the file stable between runs.
+pub fn write_baseline<'a>(path: &Utf8Path, diagnostics: impl IntoIterator<Item = &'a Diagnostic>) -> Result {
+    let mut findings: Vec<_> = diagnostics
+        .into_iter()
+        .filter_map(Fingerprint::from_diagnostic)
+        .collect();
+    findings.sort();
+
+    let file = BaselineFile {
+        version: BASELINE_VERSION,
+        findings,
+    };
+    let content = serde_json::to_string_pretty(&file).expect("the baseline can be represented as JSON");
+    std::fs::write(path, content).context(|| format!("Failed to write the baseline to {path}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::test_utils::diagnostic;
+
+    #[test]
+    fn test_fingerprint_ignores_position() {
+        let before = diagnostic("marker::lints::a", Some(("src/lib.rs", 3, "    let x = 1;")));
+        let after = diagnostic("marker::lints::a", Some(("src/lib.rs", 10, "        let x = 1;")));
+        assert_eq!(
+            Fingerprint::from_diagnostic(&before),
+            Fingerprint::from_diagnostic(&after)
+        );
+
+        let other_lint = diagnostic("marker::lints::b", Some(("src/lib.rs", 3, "    let x = 1;")));
+        let other_file = diagnostic("marker::lints::a", Some(("src/main.rs", 3, "    let x = 1;")));
+        let other_code = diagnostic("marker::lints::a", Some(("src/lib.rs", 3, "    let x = 2;")));
+        for diag in [other_lint, other_file, other_code] {
+            assert_ne!(
+                Fingerprint::from_diagnostic(&before),
+                Fingerprint::from_diagnostic(&diag)
+            );
+        }
+    }
+
+    #[test]
+    fn test_baseline_suppress() {
+        let recorded = [
+            diagnostic("marker::lints::a", Some(("src/lib.rs", 3, "let x = 1;"))),
+            diagnostic("marker::lints::a", Some(("src/lib.rs", 5, "let x = 1;"))),
+        ];
+        let mut baseline = Baseline::from_fingerprints(recorded.iter().filter_map(Fingerprint::from_diagnostic));
+
+        // Both recorded findings are suppressed, even if they moved
+        assert!(baseline.suppress(&diagnostic("marker::lints::a", Some(("src/lib.rs", 4, "let x = 1;")))));
+        assert!(baseline.suppress(&diagnostic("marker::lints::a", Some(("src/lib.rs", 6, "let x = 1;")))));
+        // A third occurrence is new
+        assert!(!baseline.suppress(&diagnostic("marker::lints::a", Some(("src/lib.rs", 8, "let x = 1;")))));
+        assert!(!baseline.suppress(&diagnostic("marker::lints::a", Some(("src/lib.rs", 3, "let y = 1;")))));
+    }
+}
@@ -0,0 +1,210 @@
+//! This module is responsible for `cargo marker --fix`. It collects all
+//! [`MachineApplicable`] suggestions emitted by Marker's lints and applies
+//! them to the source files.
+//!
+//! Suggestions are applied as a whole. A suggestion is skipped, if one of its
+//! replacements overlaps with a replacement of an already accepted suggestion.
+//!
+//! [`MachineApplicable`]: cargo_metadata::diagnostic::Applicability::MachineApplicable
+
+use super::Config;
+use crate::error::prelude::*;
+use crate::observability::display::print_stage;
+use camino::{Utf8Path, Utf8PathBuf};
+use cargo_metadata::diagnostic::{Applicability, Diagnostic};
+use std::collections::BTreeMap;
+
+/// A single replacement in a source file.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Replacement {
+    file: Utf8PathBuf,
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+impl Replacement {
+    fn overlaps(&self, other: &Replacement) -> bool {
+        self.file == other.file
+            && ((self.start < other.end && other.start < self.end)
+                // Two insertions at the same position would depend on the order
+                || self.start == other.start)
+    }
+}
+
+/// A suggestion consists of several replacements, which have to be applied together.
+type Suggestion = Vec<Replacement>;
+
+/// The result of applying the collected suggestions.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct FixSummary {
+    applied: usize,
+    skipped: usize,
+    files: usize,
+}
+
+/// This function applies the machine-applicable suggestions of the given
+/// diagnostics to the source files.
+pub fn apply_fixes(config: &Config, diagnostics: &[Diagnostic]) -> Result {
+    print_stage("fixing");
+
+    let mut suggestions = vec![];
+    for diag in diagnostics {
+        collect_suggestions(diag, &mut suggestions);
+    }
+
+    // Cargo invokes rustc in the workspace root. The file names in the
+    // diagnostics are therefore relative to it.
+    let manifest = config.toolchain.cargo.cargo_locate_project()?;
+    let workspace_root = manifest.parent().expect("the manifest must have a parent directory");
+    let summary = apply_suggestions(workspace_root, suggestions)?;
+    println!(
+        "Applied {} suggestion(s) in {} file(s), skipped {} overlapping suggestion(s)",
+        summary.applied, summary.files, summary.skipped
+    );
+
+    Ok(())
+}
+
+/// This function collects all machine-applicable suggestions of the given
+/// diagnostic. Suggestions, which have already been collected, are ignored.
+fn collect_suggestions(diag: &Diagnostic, suggestions: &mut Vec<Suggestion>) {
+    for child in &diag.children {
+        let mut suggestion: Suggestion = child
+            .spans
+            .iter()
+            .filter(|span| matches!(span.suggestion_applicability, Some(Applicability::MachineApplicable)))
+            .filter_map(|span| {
+                Some(Replacement {
+                    file: span.file_name.clone().into(),
+                    start: span.byte_start as usize,
+                    end: span.byte_end as usize,
+                    text: span.suggested_replacement.clone()?,
+                })
+            })
+            .collect();
+        suggestion.sort();
+
+        if !suggestion.is_empty() && !suggestions.contains(&suggestion) {
+            suggestions.push(suggestion);
+        }
+    }
+}
+
+/// This function applies all non-overlapping suggestions to the source files.
+fn apply_suggestions(workspace_root: &Utf8Path, suggestions: Vec<Suggestion>) -> Result<FixSummary> {
+    let (files, skipped) = select_suggestions(suggestions);
+
+    let mut summary = FixSummary {
+        skipped,
+        files: files.len(),
+        ..FixSummary::default()
+    };
+    for (file, (replacements, applied)) in files {
+        let path = workspace_root.join(&file);
+        let source = std::fs::read_to_string(&path).context(|| format!("Failed to read the source file {path}"))?;
+        let fixed = apply_replacements(&source, &replacements)
+            .context(|| format!("Failed to apply the suggestions to {path}"))?;
+        std::fs::write(&path, fixed).context(|| format!("Failed to write the source file {path}"))?;
+        summary.applied += applied;
+    }
+
+    Ok(summary)
+}
+
+/// This function selects suggestions, which don't overlap with previously
+/// selected ones. The replacements are grouped by file, together with the
+/// number of suggestions affecting the file. It also returns the number of
+/// skipped suggestions.
+#[allow(clippy::type_complexity)]
+fn select_suggestions(suggestions: Vec<Suggestion>) -> (BTreeMap<Utf8PathBuf, (Vec<Replacement>, usize)>, usize) {
+    let mut files: BTreeMap<Utf8PathBuf, (Vec<Replacement>, usize)> = BTreeMap::new();
+    let mut skipped = 0;
+
+    for suggestion in suggestions {
+        let conflicts = suggestion.iter().any(|repl| {
+            files
+                .get(&repl.file)
+                .is_some_and(|(accepted, _)| accepted.iter().any(|other| repl.overlaps(other)))
+        });
+        if conflicts {
+            skipped += 1;
+            continue;
+        }
+
+        // A suggestion can affect several files. It's counted for the first one
+        for (index, repl) in suggestion.into_iter().enumerate() {
+            let (accepted, applied) = files.entry(repl.file.clone()).or_default();
+            accepted.push(repl);
+            if index == 0 {
+                *applied += 1;
+            }
+        }
+    }
+
+    (files, skipped)
+}
+
+/// This function applies the given non-overlapping replacements to the source.
+fn apply_replacements(source: &str, replacements: &[Replacement]) -> Result<String> {
+    let mut replacements: Vec<_> = replacements.iter().collect();
+    // Replacing from the back keeps the byte offsets of the other replacements valid
+    replacements.sort_by_key(|repl| std::cmp::Reverse(repl.start));
+
+    let mut fixed = source.to_string();
+    for repl in replacements {
+        let valid = repl.start <= repl.end
+            && repl.end <= fixed.len()
+            && fixed.is_char_boundary(repl.start)
+            && fixed.is_char_boundary(repl.end);
+        if !valid {
+            return Err(Error::root(format!(
+                "The replacement range {}..{} is invalid",
+                repl.start, repl.end
+            )));
+        }
+        fixed.replace_range(repl.start..repl.end, &repl.text);
+    }
+
+    Ok(fixed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repl(start: usize, end: usize, text: &str) -> Replacement {
+        Replacement {
+            file: "src/main.rs".into(),
+            start,
+            end,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_replacements() {
+        let source = "let x = 1+2; let y = x*3;";
+        let fixed = apply_replacements(source, &[repl(8, 11, "1 + 2"), repl(21, 24, "x * 3")]).unwrap();
+        assert_eq!(fixed, "let x = 1 + 2; let y = x * 3;");
+
+        assert!(apply_replacements(source, &[repl(20, 40, "")]).is_err());
+    }
+
+    #[test]
+    fn test_skip_overlapping_suggestions() {
+        let suggestions = vec![
+            vec![repl(8, 11, "1 + 2")],
+            // Overlaps with the first suggestion and is skipped
+            vec![repl(10, 14, "2; ")],
+            vec![repl(0, 3, "const"), repl(21, 24, "x * 3")],
+        ];
+
+        let (files, skipped) = select_suggestions(suggestions);
+        assert_eq!(skipped, 1);
+
+        let (replacements, applied) = &files[Utf8Path::new("src/main.rs")];
+        assert_eq!(*applied, 2);
+        assert_eq!(replacements.len(), 3);
+    }
+}
@@ -5,7 +5,7 @@ use crate::observability::prelude::*;
 use crate::utils::utf8::IntoUtf8;
 use camino::Utf8Path;
 use itertools::Itertools;
-use std::{collections::HashSet, ffi::OsStr};
+use std::{ffi::OsStr, io::Write, path::Path, sync::Mutex};
 use yansi::Paint;
 
 #[cfg(target_os = "linux")]
@@ -30,14 +30,13 @@ const ARTIFACT_ENDINGS: &[&str] = &[
 pub fn build_lints(sources: &[LintCrateSource], config: &Config) -> Result<Vec<LintCrate>> {
     // By default Cargo doesn't provide the path of the compiled lint crate.
     // As a work around, we use the `--out-dir` option to make cargo copy all
-    // created binaries into one folder. We then scan that folder as we build our crates
-    // and collect all dynamic libraries that show up, we link the name of the crate we just built
-    // with the file(s) we found after we built it, assuming that they are related.
+    // created binaries into a folder. Every lint crate gets its own folder,
+    // all dynamic libraries that show up in it belong to the crate.
     //
-    // TODO Look into how to optimize this a bit better:
-    // Another option that would be potentially more performant is if we built each
-    // crate in it's own target dir. this would eliminate the need for HashSet<_> below, without
-    // changing too much else about the implementation.
+    // If several lint crates are compiled in parallel, every lint crate is also
+    // built in its own target directory. Cargo locks the target directory during
+    // a build, a shared one would prevent the parallel compilation. Sequential
+    // builds share one target directory, to reuse the compiled dependencies.
     //
     // This would be so much simpler if we could get an output name from Cargo
 
@@ -45,34 +44,37 @@ pub fn build_lints(sources: &[LintCrateSource], config: &Config) -> Result<Vec<L
     let lints_dir = config.lint_crate_dir();
     clear_lints_dir(&lints_dir)?;
 
-    // Build lint crates and find the output of those builds
-    let mut found_paths = HashSet::new();
-    let ending = DYNAMIC_LIB_FILE_ENDING;
-    let mut lints = Vec::with_capacity(sources.len());
-
-    for lint_src in sources {
-        build_lint(lint_src, config)?;
-        match std::fs::read_dir(&lints_dir) {
-            Ok(dir) => {
-                for file in dir {
-                    let file = file.unwrap().path().into_utf8()?;
-                    if file.extension() == Some(ending) && !found_paths.contains(&file) {
-                        found_paths.insert(file.clone());
-                        lints.push(LintCrate {
-                            file,
-                            name: lint_src.name.clone(),
-                        });
-                    }
+    // The output of Cargo is buffered, if several crates are compiled at once.
+    // Otherwise, the output of the different builds would be interleaved.
+    let jobs = config.build_jobs.get().min(sources.len());
+    let parallel = jobs > 1;
+
+    let queue = Mutex::new(sources.iter().enumerate());
+    let results = Mutex::new(Vec::with_capacity(sources.len()));
+    std::thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| {
+                loop {
+                    let Some((index, lint_src)) = queue.lock().unwrap().next() else {
+                        break;
+                    };
+                    let result = build_lint(lint_src, config, parallel);
+                    results.lock().unwrap().push((index, result));
                 }
-            },
-            Err(err) => {
-                // This shouldn't really be a point of failure. In this case, I'm
-                // more interested in the HOW?
-                panic!("unable to read lints dir after lint compilation: {lints_dir} ({err:#?})");
-            },
+            });
         }
+    });
+
+    // Sorting the results keeps the order of the lint crates and errors stable
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    let (lints, errors): (Vec<_>, Vec<_>) = results.into_iter().map(|(_, result)| result).partition_result();
+
+    match errors.len() {
+        0 => Ok(lints.into_iter().flatten().collect()),
+        1 => Err(errors.into_iter().next().unwrap()),
+        _ => Err(Error::many(errors, "Failed to compile the lint crates")),
     }
-    Ok(lints)
 }
 
 /// This function clears the `marker/lints` directory holding all compiled lints. This
@@ -82,16 +84,25 @@ pub fn build_lints(sources: &[LintCrateSource], config: &Config) -> Result<Vec<L
 /// This is an extra function to not call `delete_dir_all` and just accidentally delete
 /// the entire system.
 fn clear_lints_dir(lints_dir: &Utf8Path) -> Result {
+    clear_artifacts_dir(lints_dir.as_std_path(), true)
+}
+
+/// This function removes the given directory, which should only contain lint
+/// crate artifacts. If `crate_dirs` is `true`, the directory can also contain
+/// the output directories of the individual lint crates.
+fn clear_artifacts_dir(dir: &Path, crate_dirs: bool) -> Result {
     // Delete all files
-    let dir = match std::fs::read_dir(lints_dir) {
-        Ok(dir) => dir,
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
         Err(err) if std::io::ErrorKind::NotFound == err.kind() => return Ok(()),
         Err(err) => return Err(Error::wrap(err, "Failed to read lints artifacts directory")),
     };
 
     let endings: Vec<_> = ARTIFACT_ENDINGS.iter().map(OsStr::new).collect();
 
-    let (files, errors): (Vec<_>, Vec<_>) = dir.map(|result| result.map_err(Error::transparent)).partition_result();
+    let (files, errors): (Vec<_>, Vec<_>) = entries
+        .map(|result| result.map_err(Error::transparent))
+        .partition_result();
 
     if !errors.is_empty() {
         return Err(Error::many(errors, "Failed to read the lints directory entries"));
@@ -100,6 +111,11 @@ fn clear_lints_dir(lints_dir: &Utf8Path) -> Result {
     for file in files {
         let file = file.path();
 
+        if crate_dirs && file.is_dir() {
+            clear_artifacts_dir(&file, false)?;
+            continue;
+        }
+
         let is_expected_ending = file.extension().map(|ending| endings.contains(&ending)) == Some(true);
 
         if !is_expected_ending {
@@ -114,31 +130,74 @@ fn clear_lints_dir(lints_dir: &Utf8Path) -> Result {
     }
 
     // The dir should now be empty
-    std::fs::remove_dir(lints_dir).context(|| format!("Failed to remove lints directory {lints_dir}"))
+    std::fs::remove_dir(dir).context(|| format!("Failed to remove lints directory {}", dir.display()))
 }
 
-fn build_lint(lint_src: &LintCrateSource, config: &Config) -> Result {
-    let mut cmd = config.toolchain.cargo_build_command(config, &lint_src.manifest);
+/// This function builds a single lint crate and returns the dynamic libraries,
+/// which were created by it. If `parallel` is `true`, the crate is built in its
+/// own target directory and the output of Cargo is only printed, once the build
+/// has finished.
+fn build_lint(lint_src: &LintCrateSource, config: &Config, parallel: bool) -> Result<Vec<LintCrate>> {
+    let target_dir = if parallel {
+        config.markers_target_dir().join(&lint_src.name)
+    } else {
+        config.markers_target_dir()
+    };
+    let out_dir = config.lint_crate_dir().join(&lint_src.name);
+    let mut cmd = config
+        .toolchain
+        .cargo_build_command(config, &lint_src.manifest, &target_dir);
 
     // Set output dir. This currently requires unstable options
     cmd.arg("-Z");
     cmd.arg("unstable-options");
     cmd.arg("--out-dir");
-    cmd.arg(config.lint_crate_dir().as_os_str());
-
-    let exit_status = cmd
-        .log()
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
+    cmd.arg(out_dir.as_os_str());
+
+    let success = if parallel {
+        let output = cmd.log().output().expect("could not run cargo");
+        // Printing the output with a single call keeps it together
+        std::io::stdout()
+            .write_all(&output.stdout)
+            .expect("failed to write to stdout");
+        std::io::stderr()
+            .write_all(&output.stderr)
+            .expect("failed to write to stderr");
+        output.status.success()
+    } else {
+        cmd.log()
+            .spawn()
+            .expect("could not run cargo")
+            .wait()
+            .expect("failed to wait for cargo?")
+            .success()
+    };
 
-    if exit_status.success() {
-        return Ok(());
+    if !success {
+        return Err(Error::root(format!(
+            "Failed to compile the lint crate {}",
+            lint_src.name.red().bold()
+        )));
     }
 
-    Err(Error::root(format!(
-        "Failed to compile the lint crate {}",
-        lint_src.name.red().bold()
-    )))
+    let dir = match std::fs::read_dir(&out_dir) {
+        Ok(dir) => dir,
+        Err(err) => {
+            // This shouldn't really be a point of failure. In this case, I'm
+            // more interested in the HOW?
+            panic!("unable to read lints dir after lint compilation: {out_dir} ({err:#?})");
+        },
+    };
+
+    let mut lints = vec![];
+    for file in dir {
+        let file = file.unwrap().path().into_utf8()?;
+        if file.extension() == Some(DYNAMIC_LIB_FILE_ENDING) {
+            lints.push(LintCrate {
+                file,
+                name: lint_src.name.clone(),
+            });
+        }
+    }
+    Ok(lints)
 }
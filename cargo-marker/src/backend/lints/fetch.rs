@@ -9,11 +9,18 @@
 //! specified lint crates as dependencies. Then `cargo fetch` is called, which
 //! will download the crates into Cargo's cache. The absolute path to the lints
 //! can then be retrieved from `cargo metadata`.
+//!
+//! The `Cargo.lock` file of the dummy crate is synchronized with Marker's
+//! lockfile, usually called `marker.lock`. This pins the resolved versions of
+//! the lint crates and allows reproducible runs on different machines.
 
 use super::LintCrateSource;
 use crate::error::prelude::*;
 use crate::observability::prelude::*;
-use crate::{backend::Config, config::LintDependencyEntry};
+use crate::{
+    backend::Config,
+    config::{LintDependencyEntry, Source},
+};
 use camino::{Utf8Path, Utf8PathBuf};
 use cargo_metadata::Metadata;
 use std::collections::BTreeMap;
@@ -26,7 +33,9 @@ pub fn fetch_crates(config: &Config) -> Result<Vec<LintCrateSource>> {
 
     let manifest = setup_dummy_crate(config)?;
 
+    restore_lockfile(config)?;
     call_cargo_fetch(&manifest, config)?;
+    store_lockfile(config)?;
 
     let metadata = call_cargo_metadata(&manifest, config)?;
 
@@ -58,6 +67,72 @@ fn setup_dummy_crate(config: &Config) -> Result<Utf8PathBuf> {
     Ok(manifest_path)
 }
 
+/// Returns the lockfile, which should be used for the lint crates of the
+/// given config. Lint crates specified by a path are used as they are, a
+/// lockfile is therefore only used, if some lint crates are fetched from a
+/// registry or git repository.
+fn used_lockfile(config: &Config) -> Option<&Utf8Path> {
+    config.lockfile.as_deref().filter(|_| has_remote_lints(&config.lints))
+}
+
+/// Returns `true`, if Cargo should fail, when the lockfile of the dummy crate
+/// needs to be updated. This only applies, if a lockfile is used, since the
+/// dummy crate doesn't have a lockfile for lint crates specified by a path.
+fn use_locked(config: &Config) -> bool {
+    config.locked && used_lockfile(config).is_some()
+}
+
+fn has_remote_lints(lints: &BTreeMap<String, LintDependencyEntry>) -> bool {
+    lints.values().any(|dep| !matches!(dep.source, Source::Path { .. }))
+}
+
+/// This function copies the lockfile, if it exists, next to the manifest of
+/// the dummy crate. Cargo will then use the locked versions of the lint crates.
+fn restore_lockfile(config: &Config) -> Result {
+    let Some(lockfile) = used_lockfile(config) else {
+        return Ok(());
+    };
+    let dummy_lockfile = config.marker_dir.join("Cargo.lock");
+
+    if lockfile.exists() {
+        std::fs::copy(lockfile, &dummy_lockfile)
+            .context(|| format!("Failed to copy the lockfile {lockfile} to {dummy_lockfile}"))?;
+        return Ok(());
+    }
+
+    if config.locked {
+        return Err(Error::root(format!(
+            "The lockfile {lockfile} doesn't exist, but `--locked` was specified"
+        )));
+    }
+
+    // Without a lockfile, the lint crates should be resolved from scratch,
+    // instead of reusing the versions from a previous run.
+    if dummy_lockfile.exists() {
+        std::fs::remove_file(&dummy_lockfile)
+            .context(|| format!("Failed to remove the outdated lockfile {dummy_lockfile}"))?;
+    }
+
+    Ok(())
+}
+
+/// This function writes the `Cargo.lock` file of the dummy crate to the lockfile,
+/// if the resolved versions have changed.
+fn store_lockfile(config: &Config) -> Result {
+    let Some(lockfile) = used_lockfile(config) else {
+        return Ok(());
+    };
+    let dummy_lockfile = config.marker_dir.join("Cargo.lock");
+
+    let content = std::fs::read_to_string(&dummy_lockfile)
+        .context(|| format!("Failed to read the lockfile of the lint crates at {dummy_lockfile}"))?;
+    if std::fs::read_to_string(lockfile).is_ok_and(|old_content| old_content == content) {
+        return Ok(());
+    }
+
+    write_to_file(lockfile, &content)
+}
+
 fn write_to_file(path: &Utf8Path, content: &str) -> Result {
     let parent = path
         .parent()
@@ -99,6 +174,11 @@ fn call_cargo_fetch(manifest: &Utf8Path, config: &Config) -> Result {
     cmd.arg("--manifest-path");
     cmd.arg(manifest.as_os_str());
 
+    // Cargo will fail, if the lockfile needs to be updated
+    if use_locked(config) {
+        cmd.arg("--locked");
+    }
+
     // Only fetch for the specified target. Cargo will just fetch everything,
     // if the `--target` flag is not specified.
     if let Ok(target) = std::env::var("TARGET") {
@@ -121,12 +201,12 @@ fn call_cargo_fetch(manifest: &Utf8Path, config: &Config) -> Result {
 }
 
 fn call_cargo_metadata(manifest: &Utf8Path, config: &Config) -> Result<Metadata> {
-    config
-        .toolchain
-        .cargo
-        .metadata()
-        .manifest_path(manifest)
-        .exec()
+    let mut cmd = config.toolchain.cargo.metadata();
+    cmd.manifest_path(manifest);
+    if use_locked(config) {
+        cmd.other_options(vec!["--locked".to_string()]);
+    }
+    cmd.exec()
         .context(|| format!("Failed to get cargo metadata for the lint crates at {manifest}"))
 }
 
@@ -141,3 +221,83 @@ fn extract_lint_crate_sources(metadata: &Metadata, marker_config: &Config) -> Ve
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(source: Source) -> LintDependencyEntry {
+        LintDependencyEntry {
+            source,
+            package: None,
+            default_features: None,
+            features: None,
+            config: None,
+        }
+    }
+
+    #[test]
+    fn test_has_remote_lints() {
+        let path = entry(Source::Path {
+            path: "/home/user/lints".to_string(),
+        });
+        let registry = entry(Source::Registry {
+            version: "0.1.0".to_string(),
+            registry: None,
+        });
+        let git = entry(Source::Git {
+            git: "https://github.com/rust-marker/marker".to_string(),
+            git_ref: None,
+        });
+
+        let mut lints = BTreeMap::new();
+        assert!(!has_remote_lints(&lints));
+
+        lints.insert("path_lints".to_string(), path);
+        assert!(!has_remote_lints(&lints));
+
+        lints.insert("git_lints".to_string(), git);
+        assert!(has_remote_lints(&lints));
+
+        lints.remove("git_lints");
+        lints.insert("registry_lints".to_string(), registry);
+        assert!(has_remote_lints(&lints));
+    }
+
+    #[test]
+    fn test_fetch_locked_path_lints() {
+        let dir = Utf8PathBuf::try_from(std::env::temp_dir())
+            .unwrap()
+            .join(format!("marker_fetch_locked_{}", std::process::id()));
+        let lint_dir = dir.join("path_lints");
+        write_to_file(
+            &lint_dir.join("Cargo.toml"),
+            "[package]\nname = \"path_lints\"\nversion = \"0.1.0\"\n\n[workspace]\n",
+        )
+        .unwrap();
+        write_to_file(&lint_dir.join("src/lib.rs"), "").unwrap();
+
+        let toolchain = crate::backend::toolchain::Toolchain {
+            driver_path: Utf8PathBuf::new(),
+            cargo: crate::backend::cargo::Cargo::default(),
+        };
+        let mut config = Config::base_from(toolchain, dir.join("marker"));
+        config.lints.insert(
+            "path_lints".to_string(),
+            entry(Source::Path {
+                path: lint_dir.to_string(),
+            }),
+        );
+        config.lockfile = Some(dir.join("marker.lock"));
+        config.locked = true;
+
+        // The dummy crate doesn't have a lockfile, which Cargo could use
+        assert!(!use_locked(&config));
+        let sources = fetch_crates(&config).unwrap();
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].name, "path_lints");
+        assert!(!dir.join("marker.lock").exists());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
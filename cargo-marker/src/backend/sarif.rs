@@ -0,0 +1,206 @@
+//! This module converts the diagnostics emitted by Marker's lints into the
+//! [SARIF 2.1.0] format. SARIF files can be uploaded to code scanning services,
+//! like GitHub's code scanning.
+//!
+//! Only the parts of the format, which can be filled with information from
+//! the diagnostics, are represented here.
+//!
+//! [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use cargo_metadata::diagnostic::{Diagnostic, DiagnosticLevel};
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+#[derive(Serialize, Debug)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run<'a>>,
+}
+
+#[derive(Serialize, Debug)]
+struct Run<'a> {
+    tool: Tool<'a>,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Serialize, Debug)]
+struct Tool<'a> {
+    driver: ToolComponent<'a>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ToolComponent<'a> {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<Rule<'a>>,
+}
+
+#[derive(Serialize, Debug)]
+struct Rule<'a> {
+    id: &'a str,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    rule_id: &'a str,
+    level: &'static str,
+    message: Message<'a>,
+    locations: Vec<Location<'a>>,
+}
+
+#[derive(Serialize, Debug)]
+struct Message<'a> {
+    text: &'a str,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Location<'a> {
+    physical_location: PhysicalLocation<'a>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation<'a> {
+    artifact_location: ArtifactLocation<'a>,
+    region: Region,
+}
+
+#[derive(Serialize, Debug)]
+struct ArtifactLocation<'a> {
+    uri: &'a str,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+/// This function serializes the given diagnostics into a SARIF log. The lint
+/// names are used as rule ids. File names are relative to the workspace root.
+pub fn to_sarif(diagnostics: &[Diagnostic]) -> String {
+    let results: Vec<_> = diagnostics.iter().filter_map(to_result).collect();
+    let rules = results
+        .iter()
+        .map(|result| result.rule_id)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|id| Rule { id })
+        .collect();
+
+    let log = SarifLog {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: vec![Run {
+            tool: Tool {
+                driver: ToolComponent {
+                    name: "marker",
+                    information_uri: "https://github.com/rust-marker/marker",
+                    version: env!("CARGO_PKG_VERSION"),
+                    rules,
+                },
+            },
+            results,
+        }],
+    };
+
+    serde_json::to_string_pretty(&log).expect("SARIF logs can be represented as JSON")
+}
+
+fn to_result(diag: &Diagnostic) -> Option<SarifResult<'_>> {
+    let rule_id = diag.code.as_ref()?.code.as_str();
+    let locations = diag
+        .spans
+        .iter()
+        .filter(|span| span.is_primary)
+        .map(|span| Location {
+            physical_location: PhysicalLocation {
+                artifact_location: ArtifactLocation { uri: &span.file_name },
+                region: Region {
+                    start_line: span.line_start,
+                    start_column: span.column_start,
+                    end_line: span.line_end,
+                    end_column: span.column_end,
+                },
+            },
+        })
+        .collect();
+
+    Some(SarifResult {
+        rule_id,
+        level: to_sarif_level(diag.level),
+        message: Message { text: &diag.message },
+        locations,
+    })
+}
+
+/// Lints at the `deny` and `forbid` level are emitted as errors, lints at the
+/// `warn` level as warnings.
+fn to_sarif_level(level: DiagnosticLevel) -> &'static str {
+    match level {
+        DiagnosticLevel::Ice | DiagnosticLevel::Error => "error",
+        DiagnosticLevel::Warning => "warning",
+        _ => "note",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_sarif() {
+        let diag: Diagnostic = serde_json::from_str(
+            r#"{
+                "message": "this closure is called immediately",
+                "code": { "code": "marker::marker_uilints::test_lint", "explanation": null },
+                "level": "warning",
+                "spans": [{
+                    "file_name": "src/main.rs",
+                    "byte_start": 40,
+                    "byte_end": 48,
+                    "line_start": 2,
+                    "line_end": 2,
+                    "column_start": 37,
+                    "column_end": 45,
+                    "is_primary": true,
+                    "text": [],
+                    "label": null,
+                    "suggested_replacement": null,
+                    "suggestion_applicability": null,
+                    "expansion": null
+                }],
+                "children": [],
+                "rendered": null
+            }"#,
+        )
+        .unwrap();
+
+        let sarif: serde_json::Value = serde_json::from_str(&to_sarif(&[diag])).unwrap();
+        let run = &sarif["runs"][0];
+        assert_eq!(
+            run["tool"]["driver"]["rules"][0]["id"],
+            "marker::marker_uilints::test_lint"
+        );
+
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "marker::marker_uilints::test_lint");
+        assert_eq!(result["level"], "warning");
+        let location = &result["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/main.rs");
+        assert_eq!(location["region"]["startLine"], 2);
+        assert_eq!(location["region"]["startColumn"], 37);
+    }
+}
@@ -0,0 +1,113 @@
+//! Helpers to create the JSON messages of Cargo and rustc in tests.
+
+use cargo_metadata::diagnostic::Diagnostic;
+use cargo_metadata::Message;
+
+/// Creates a warning with the given lint code. The optional span is given as
+/// the file, the line and the text of the line. The text is highlighted, except
+/// for its indentation, which also determines the column.
+pub fn diagnostic(code: &str, span: Option<(&str, usize, &str)>) -> Diagnostic {
+    let spans = span.map(|(file, line, text)| {
+        let indent = text.len() - text.trim_start().len();
+        serde_json::json!({
+            "file_name": file,
+            "byte_start": 0,
+            "byte_end": 0,
+            "line_start": line,
+            "line_end": line,
+            "column_start": indent + 1,
+            "column_end": text.len() + 1,
+            "is_primary": true,
+            "text": [{
+                "text": text,
+                "highlight_start": indent + 1,
+                "highlight_end": text.len() + 1
+            }],
+            "label": null,
+            "suggested_replacement": null,
+            "suggestion_applicability": null,
+            "expansion": null
+        })
+    });
+    serde_json::from_value(serde_json::json!({
+        "message": "",
+        "code": { "code": code, "explanation": null },
+        "level": "warning",
+        "spans": spans.into_iter().collect::<Vec<_>>(),
+        "children": [],
+        "rendered": code
+    }))
+    .unwrap()
+}
+
+fn package_id(package: &str) -> String {
+    format!("{package} 0.1.0 (path+file:///{package})")
+}
+
+/// The target of the given kind, like `lib` or `custom-build`, of the package.
+fn target(package: &str, kind: &str) -> serde_json::Value {
+    let (name, src_path) = if kind == "custom-build" {
+        ("build-script-build".to_string(), format!("/{package}/build.rs"))
+    } else {
+        (package.to_string(), format!("/{package}/src/lib.rs"))
+    };
+    serde_json::json!({
+        "name": name,
+        "kind": [kind],
+        "crate_types": ["lib"],
+        "required-features": [],
+        "src_path": src_path,
+        "edition": "2021",
+        "doctest": true,
+        "test": true,
+        "doc": true
+    })
+}
+
+/// Wraps the diagnostic in a message of Cargo, for the library of the given package.
+pub fn compiler_message(package: &str, message: &Diagnostic) -> Message {
+    serde_json::from_value(serde_json::json!({
+        "reason": "compiler-message",
+        "package_id": package_id(package),
+        "manifest_path": format!("/{package}/Cargo.toml"),
+        "target": target(package, "lib"),
+        "message": message
+    }))
+    .unwrap()
+}
+
+/// The message of Cargo, that the target of the given kind was compiled.
+pub fn artifact(package: &str, kind: &str) -> Message {
+    serde_json::from_value(serde_json::json!({
+        "reason": "compiler-artifact",
+        "package_id": package_id(package),
+        "manifest_path": format!("/{package}/Cargo.toml"),
+        "target": target(package, kind),
+        "profile": {
+            "opt_level": "0",
+            "debuginfo": 2,
+            "debug_assertions": true,
+            "overflow_checks": true,
+            "test": false
+        },
+        "features": [],
+        "filenames": [],
+        "executable": null,
+        "fresh": false
+    }))
+    .unwrap()
+}
+
+/// The message of Cargo, that the build script of the package was executed.
+pub fn build_script_executed(package: &str) -> Message {
+    serde_json::from_value(serde_json::json!({
+        "reason": "build-script-executed",
+        "package_id": package_id(package),
+        "linked_libs": [],
+        "linked_paths": [],
+        "cfgs": [],
+        "env": [],
+        "out_dir": format!("/target/{package}/out")
+    }))
+    .unwrap()
+}
@@ -0,0 +1,186 @@
+//! This module implements the `--timeout` option of `cargo marker`. The linting
+//! subprocess is terminated, if it doesn't finish in time, to prevent CI jobs
+//! from hanging indefinitely, for example, when the driver waits on a lock.
+//!
+//! The subprocess is terminated together with all processes started by it, like
+//! the driver instances started by Cargo. On unix, the subprocess is started in
+//! a new process group, which is killed. On Windows, the process tree is killed
+//! with `taskkill`. On other platforms, only the subprocess itself is killed.
+
+use crate::error::prelude::*;
+use std::process::{Child, Command, ExitStatus};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+/// The interval in which the subprocess is checked, while waiting for it to exit.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The point in time, at which the subprocess should be terminated.
+#[derive(Debug, Clone, Copy)]
+pub struct Deadline {
+    timeout: Duration,
+    end: Instant,
+}
+
+impl Deadline {
+    /// Starts the deadline, if a timeout is given.
+    pub fn start(timeout: Option<Duration>) -> Option<Self> {
+        timeout.map(|timeout| Self {
+            timeout,
+            end: Instant::now() + timeout,
+        })
+    }
+
+    fn remaining(&self) -> Duration {
+        self.end.saturating_duration_since(Instant::now())
+    }
+
+    fn error(&self) -> Error {
+        Error::from_kind(ErrorKind::Timeout {
+            seconds: self.timeout.as_secs(),
+        })
+    }
+}
+
+/// Spawns the command. If a deadline is given, the child is started in a new
+/// process group on unix, to be able to terminate it together with all processes
+/// started by it. Processes in the new group don't receive the signals sent by
+/// the terminal, like the interrupt of `Ctrl+C`.
+#[cfg_attr(not(unix), allow(unused_variables))]
+pub fn spawn(cmd: &mut Command, deadline: Option<Deadline>) -> std::io::Result<Child> {
+    #[cfg(unix)]
+    if deadline.is_some() {
+        use std::os::unix::process::CommandExt;
+
+        // `0` creates a new group, with the ID of the child
+        cmd.process_group(0);
+    }
+    cmd.spawn()
+}
+
+/// Waits for the child process to exit. The child is terminated and an error is
+/// returned, if it's still running once the deadline has passed.
+pub fn wait(child: &mut Child, deadline: Option<Deadline>) -> Result<ExitStatus> {
+    let Some(deadline) = deadline else {
+        return Ok(child.wait().expect("failed to wait for the subprocess?"));
+    };
+
+    loop {
+        if let Some(status) = child.try_wait().expect("failed to wait for the subprocess?") {
+            return Ok(status);
+        }
+
+        let remaining = deadline.remaining();
+        if remaining.is_zero() {
+            return Err(terminate(child, deadline));
+        }
+        std::thread::sleep(remaining.min(POLL_INTERVAL));
+    }
+}
+
+/// Receives the next value from a channel, which is filled with the output of
+/// the child process. The child is terminated and an error is returned, if no
+/// value arrives before the deadline. `Ok(None)` is returned, once the channel
+/// is closed.
+pub fn recv<T>(child: &mut Child, receiver: &Receiver<T>, deadline: Option<Deadline>) -> Result<Option<T>> {
+    let Some(deadline) = deadline else {
+        return Ok(receiver.recv().ok());
+    };
+
+    match receiver.recv_timeout(deadline.remaining()) {
+        Ok(value) => Ok(Some(value)),
+        Err(RecvTimeoutError::Disconnected) => Ok(None),
+        Err(RecvTimeoutError::Timeout) => Err(terminate(child, deadline)),
+    }
+}
+
+fn terminate(child: &mut Child, deadline: Deadline) -> Error {
+    kill_tree(child);
+    let _ = child.wait();
+    deadline.error()
+}
+
+/// Kills the child and all processes started by it. The child might have exited
+/// in the meantime, in which case killing it fails. This is fine, since the
+/// deadline has passed anyway.
+#[cfg(unix)]
+fn kill_tree(child: &mut Child) {
+    // The child is the leader of its own process group, see [`spawn`]. The group
+    // still exists, until the child has been waited for.
+    match libc::pid_t::try_from(child.id()) {
+        // SAFETY: `kill` doesn't access any memory of this process
+        Ok(pid) => unsafe {
+            libc::kill(-pid, libc::SIGKILL);
+        },
+        Err(_) => {
+            let _ = child.kill();
+        },
+    }
+}
+
+#[cfg(windows)]
+fn kill_tree(child: &mut Child) {
+    // `/T` also terminates all processes started by the child
+    let status = Command::new("taskkill")
+        .args(["/F", "/T", "/PID", &child.id().to_string()])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status();
+    if !status.is_ok_and(|status| status.success()) {
+        let _ = child.kill();
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn kill_tree(child: &mut Child) {
+    let _ = child.kill();
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::process::Stdio;
+
+    #[test]
+    fn test_wait_within_timeout() {
+        let deadline = Deadline::start(Some(Duration::from_secs(60)));
+        let mut child = spawn(&mut Command::new("true"), deadline).unwrap();
+        let deadline = Deadline::start(Some(Duration::from_secs(60)));
+        assert!(wait(&mut child, deadline).unwrap().success());
+    }
+
+    #[test]
+    fn test_wait_terminates_child() {
+        let start = Instant::now();
+        let deadline = Deadline::start(Some(Duration::from_millis(50)));
+        let mut child = spawn(Command::new("sleep").arg("60"), deadline).unwrap();
+        assert!(wait(&mut child, deadline).is_err());
+        assert!(child.try_wait().unwrap().is_some());
+        assert!(start.elapsed() < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_wait_terminates_grandchildren() {
+        let start = Instant::now();
+        let deadline = Deadline::start(Some(Duration::from_millis(50)));
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", "sleep 60 & wait"]).stdout(Stdio::piped());
+        let mut child = spawn(&mut cmd, deadline).unwrap();
+        let mut stdout = child.stdout.take().unwrap();
+        assert!(wait(&mut child, deadline).is_err());
+
+        // The pipe is closed, once `sleep`, which inherited it, has been killed
+        stdout.read_to_end(&mut vec![]).unwrap();
+        assert!(start.elapsed() < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_recv_terminates_child() {
+        let (_sender, receiver) = std::sync::mpsc::channel::<()>();
+        let deadline = Deadline::start(Some(Duration::from_millis(50)));
+        let mut child = spawn(Command::new("sleep").arg("60"), deadline).unwrap();
+        assert!(recv(&mut child, &receiver, deadline).is_err());
+        assert!(child.try_wait().unwrap().is_some());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::error::prelude::*;
+use crate::observability::display;
 use crate::observability::prelude::*;
 use crate::utils::{is_local_driver, utf8::IntoUtf8};
 use crate::Result;
@@ -30,7 +31,21 @@ impl Toolchain {
         cmd
     }
 
-    pub fn cargo_build_command(&self, config: &Config, manifest: &Utf8Path) -> Command {
+    /// This returns a command invoking the driver directly, like `rustc`. The
+    /// driver is invoked via rustup, if the toolchain is known, to make rustc's
+    /// shared libraries available. See [`Cargo::command`] for more information.
+    pub fn driver_command(&self) -> Command {
+        if let Some(toolchain) = &self.cargo.toolchain {
+            let mut cmd = Command::new("rustup");
+            cmd.args(["run", toolchain]);
+            cmd.arg(self.driver_path.as_os_str());
+            cmd
+        } else {
+            Command::new(self.driver_path.as_os_str())
+        }
+    }
+
+    pub fn cargo_build_command(&self, config: &Config, manifest: &Utf8Path, target_dir: &Utf8Path) -> Command {
         let mut cmd = self.cargo.command();
         cmd.arg("build");
 
@@ -40,7 +55,7 @@ impl Toolchain {
 
         // Target dir
         cmd.arg("--target-dir");
-        cmd.arg(config.markers_target_dir().as_os_str());
+        cmd.arg(target_dir.as_os_str());
 
         // Potential "--release" flag
         if !config.debug_build {
@@ -96,6 +111,40 @@ impl Toolchain {
         Err(Error::from_kind(ErrorKind::DriverNotFound { errors }))
     }
 
+    /// Uses the driver binary at the given path, instead of searching for an
+    /// installed one. The driver is invoked via the given toolchain, or the
+    /// toolchain of the default driver, if no toolchain is specified.
+    ///
+    /// The `RUSTUP_TOOLCHAIN` environment value is intentionally ignored. Rustup
+    /// sets it for `cargo +<toolchain> marker`, which selects the toolchain used
+    /// to run `cargo-marker` and not necessarily the one of the driver.
+    ///
+    /// The version of the driver is not checked. It's the users responsibility,
+    /// to ensure that the driver is compatible with `cargo-marker` and the lint crates.
+    pub fn with_driver_path(driver_path: &Utf8Path, toolchain: Option<&str>) -> Result<Toolchain> {
+        let driver_path = driver_path
+            .canonicalize_utf8()
+            .context(|| format!("The driver path is invalid: {}", driver_path.red().bold()))?;
+        if !driver_path.is_file() {
+            return Err(Error::root(format!(
+                "The driver path doesn't point to a file: {}",
+                driver_path.red().bold()
+            )));
+        }
+
+        display::print_warning(&format!(
+            "using the driver at `{driver_path}`, its compatibility with cargo-marker \
+            and the lint crates is not checked"
+        ));
+        info!(path = %driver_path, "Using custom driver");
+
+        let toolchain = toolchain.map_or_else(|| default_driver_info().toolchain, ToString::to_string);
+        Ok(Toolchain {
+            driver_path,
+            cargo: Cargo::with_toolchain(toolchain),
+        })
+    }
+
     fn search_driver(toolchain: &str) -> Result<Toolchain> {
         let driver_path = rustup_which(toolchain, "marker_rustc_driver")?;
 
@@ -165,3 +214,35 @@ pub(crate) fn rustup_which(toolchain: &str, tool: &str) -> Result<Utf8PathBuf> {
 
     Ok(path)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_driver_path_toolchain() {
+        // Any existing file can be used, since the driver is not executed
+        let driver = Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+
+        let toolchain = Toolchain::with_driver_path(&driver, Some("nightly-2000-01-01")).unwrap();
+        assert_eq!(toolchain.cargo.toolchain.as_deref(), Some("nightly-2000-01-01"));
+        assert!(toolchain.driver_path.is_absolute());
+
+        // `cargo test` is usually invoked via rustup, which sets `RUSTUP_TOOLCHAIN`.
+        // The toolchain of the default driver should still be used.
+        let toolchain = Toolchain::with_driver_path(&driver, None).unwrap();
+        assert_eq!(toolchain.cargo.toolchain, Some(default_driver_info().toolchain));
+
+        let command = toolchain.driver_command();
+        let args: Vec<_> = command.get_args().collect();
+        assert_eq!(command.get_program(), "rustup");
+        assert_eq!(args[..2], ["run", default_driver_info().toolchain.as_str()]);
+    }
+
+    #[test]
+    fn test_with_driver_path_invalid() {
+        let dir = Utf8Path::new(env!("CARGO_MANIFEST_DIR"));
+        assert!(Toolchain::with_driver_path(dir, None).is_err());
+        assert!(Toolchain::with_driver_path(&dir.join("missing_driver"), None).is_err());
+    }
+}
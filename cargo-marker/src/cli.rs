@@ -6,6 +6,10 @@ use crate::config::Config;
 use crate::error::prelude::*;
 use clap::{Parser, Subcommand};
 
+/// The name of the lockfile for lint crates. It's stored next to the
+/// `Cargo.toml` file of the workspace.
+const LOCKFILE_NAME: &str = "marker.lock";
+
 /// Marker's CLI interface
 ///
 /// This binary should be invoked by Cargo with the new `marker` subcommand. If
@@ -56,15 +60,19 @@ impl MarkerCli {
     }
 
     pub(crate) fn run(self) -> Result {
+        let command = match self.command.unwrap_or(CliCommand::Check(self.check)) {
+            // Single files are linted without a Cargo project
+            CliCommand::Check(cmd) if cmd.file.is_some() => return cmd.run_file(),
+            command => command,
+        };
+
         let manifest_path = crate::backend::cargo::Cargo::default().cargo_locate_project()?;
         let config = Config::try_from_manifest(&manifest_path)?;
+        let lockfile = manifest_path.with_file_name(LOCKFILE_NAME);
 
-        let Some(command) = self.command else {
-            return self.check.run(config);
-        };
         match command {
             CliCommand::Setup(cmd) => cmd.run(),
-            CliCommand::Check(cmd) => cmd.run(config),
+            CliCommand::Check(cmd) => cmd.run(config, lockfile),
             CliCommand::TestSetup(cmd) => cmd.run(config),
         }
     }
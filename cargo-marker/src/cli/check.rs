@@ -1,12 +1,17 @@
-use crate::config::{Config, LintDependency};
+use crate::config::{Config, LintDependency, LintDependencyEntry};
 use crate::error::prelude::*;
 use crate::{backend, utils};
-use camino::Utf8Path;
-use clap::Args;
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::{Args, ValueEnum};
+use serde::Serialize;
 use std::collections::BTreeMap;
+use std::num::{NonZeroU64, NonZeroUsize};
+use std::time::Duration;
 
 #[derive(Args, Debug)]
 #[command(override_usage = "cargo marker check [OPTIONS] -- <CARGO ARGS>")]
+// The bools are independent CLI flags
+#[allow(clippy::struct_excessive_bools)]
 pub(crate) struct CheckCommand {
     /// Specifies lint crates which should be used. (Lints in `Cargo.toml` will be ignored)
     #[arg(short, long)]
@@ -16,41 +21,171 @@ pub(crate) struct CheckCommand {
     #[arg(long)]
     pub(crate) forward_rust_flags: bool,
 
+    /// The number of lint crates, which are compiled in parallel. Defaults to the number of CPUs
+    #[arg(short, long)]
+    pub(crate) jobs: Option<NonZeroUsize>,
+
+    /// Automatically apply the machine-applicable suggestions of Marker's lints
+    #[arg(long)]
+    pub(crate) fix: bool,
+
+    /// The format of the diagnostics written to `--output-file`
+    #[arg(long, value_enum, default_value_t = OutputFormat::Human, requires = "output_file")]
+    pub(crate) output_format: OutputFormat,
+
+    /// The file the diagnostics are written to, in the selected `--output-format`
+    #[arg(long)]
+    pub(crate) output_file: Option<Utf8PathBuf>,
+
+    /// Require the lint crates to be resolved to the versions in `marker.lock`
+    #[arg(long)]
+    pub(crate) locked: bool,
+
+    /// Print the time spent in each lint crate, grouped by node kind, after each checked crate
+    #[arg(long)]
+    pub(crate) timings: bool,
+
+    /// Render the diagnostics sorted by file, location and lint name, once all
+    /// crates have been checked. This makes the output deterministic between runs
+    #[arg(long)]
+    pub(crate) sort_diagnostics: bool,
+
+    /// Only report diagnostics, which are not recorded in the given baseline file
+    #[arg(long, value_name = "FILE")]
+    pub(crate) baseline: Option<Utf8PathBuf>,
+
+    /// Record the diagnostics of Marker's lints in the given baseline file. The
+    /// file can be passed to `--baseline` on later runs
+    #[arg(long, value_name = "FILE")]
+    pub(crate) write_baseline: Option<Utf8PathBuf>,
+
+    /// Terminate the linting, if it doesn't finish within the given number of seconds.
+    /// The linting can take indefinitely by default
+    #[arg(long, value_name = "SECONDS", env = "MARKER_TIMEOUT")]
+    pub(crate) timeout: Option<NonZeroU64>,
+
+    /// Print the resolved lint crate configuration as TOML and exit, without linting
+    #[arg(long)]
+    pub(crate) print_config: bool,
+
+    /// Print the documentation of the given lint and exit, without linting
+    #[arg(long, value_name = "LINT")]
+    pub(crate) explain: Option<String>,
+
+    /// Print all lints of the lint crates and exit, without linting
+    #[arg(long, conflicts_with = "explain")]
+    pub(crate) list_lints: bool,
+
+    /// The format of the lints printed by `--list-lints`
+    #[arg(long, value_enum, default_value_t = ListFormat::Human, requires = "list_lints")]
+    pub(crate) list_format: ListFormat,
+
+    /// Lint a single file without a Cargo project. The lint crates have to be
+    /// specified with `--lints`, arguments after `--` are passed to rustc
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = [
+            "fix",
+            "output_file",
+            "locked",
+            "sort_diagnostics",
+            "baseline",
+            "write_baseline",
+            "print_config",
+            "explain",
+            "list_lints"
+        ]
+    )]
+    pub(crate) file: Option<Utf8PathBuf>,
+
+    /// Use the driver binary at the given path, instead of the installed driver.
+    /// The compatibility of the driver with the lint crates is not checked
+    #[arg(long, value_name = "PATH")]
+    pub(crate) driver_path: Option<Utf8PathBuf>,
+
+    /// The toolchain used to invoke the driver given by `--driver-path`. This
+    /// defaults to the toolchain of the driver installed by cargo-marker
+    #[arg(long, requires = "driver_path")]
+    pub(crate) toolchain: Option<String>,
+
     /// Arguments which will be forwarded to Cargo. See `cargo check --help`
     #[clap(last = true)]
     pub(crate) cargo_args: Vec<String>,
 }
 
 impl CheckCommand {
-    pub(crate) fn run(self, config: Option<Config>) -> Result {
-        self.compile_lints(config)?.lint()
+    pub(crate) fn run(mut self, config: Option<Config>, lockfile: Utf8PathBuf) -> Result {
+        if self.print_config {
+            print!("{}", self.render_config(config)?);
+            return Ok(());
+        }
+
+        // `--explain` and `--list-lints` don't lint anything. They shouldn't
+        // create or update the lockfile as a side effect.
+        if let Some(lint) = self.explain.take() {
+            let lints = self.compile_lints(config, None)?;
+            return backend::explain_lint(&lints.backend_conf, lints.info, &lint);
+        }
+
+        if self.list_lints {
+            let format = match self.list_format {
+                ListFormat::Human => "human",
+                ListFormat::Json => "json",
+            };
+            let lints = self.compile_lints(config, None)?;
+            return backend::list_lints(&lints.backend_conf, lints.info, format);
+        }
+
+        self.compile_lints(config, Some(lockfile))?.lint()
     }
 
-    pub(crate) fn compile_lints(self, config: Option<Config>) -> Result<CompiledLints> {
-        // determine lints
-        let lints: BTreeMap<_, _> = self
-            .lints_from_cli()?
-            .or_else(|| config.map(|config| config.lints))
-            .into_iter()
-            .flatten()
-            .map(|(name, dep)| (name, dep.into_dep_entry()))
-            .collect();
+    /// Lints the file given by `--file`. The lint crates from `Cargo.toml`
+    /// and the lockfile are not used, since the file doesn't belong to a project.
+    pub(crate) fn run_file(self) -> Result {
+        let file = self.file.clone().expect("this is only called with `--file`");
+        let lints = self.compile_lints(None, None)?;
+        backend::run_file(&lints.backend_conf, lints.info, &file, &lints.cargo_args)
+    }
+
+    /// Fetches and compiles the lint crates. The resolved versions of the lint
+    /// crates are stored in the given lockfile, if one is provided.
+    pub(crate) fn compile_lints(self, config: Option<Config>, lockfile: Option<Utf8PathBuf>) -> Result<CompiledLints> {
+        let excluded_files = config.as_ref().map(|config| config.exclude.clone()).unwrap_or_default();
+        let (_, lints) = self.resolve_lints(config)?;
 
         // Validation
         if lints.is_empty() {
             return Err(Error::from_kind(ErrorKind::LintsNotFound));
         }
 
-        // If this is a dev build, we want to rebuild the driver before checking
-        if utils::is_local_driver() {
-            backend::driver::install_driver(false, None)?;
-        }
-
         // Configure backend
-        let toolchain = backend::toolchain::Toolchain::try_find_toolchain()?;
+        let toolchain = if let Some(driver_path) = &self.driver_path {
+            backend::toolchain::Toolchain::with_driver_path(driver_path, self.toolchain.as_deref())?
+        } else {
+            // If this is a dev build, we want to rebuild the driver before checking
+            if utils::is_local_driver() {
+                backend::driver::install_driver(false, None)?;
+            }
+            backend::toolchain::Toolchain::try_find_toolchain()?
+        };
+        let base_conf = if self.file.is_some() {
+            backend::Config::try_standalone_from(toolchain)?
+        } else {
+            backend::Config::try_base_from(toolchain)?
+        };
         let backend_conf = backend::Config {
             lints,
-            ..backend::Config::try_base_from(toolchain)?
+            excluded_files,
+            build_jobs: self.jobs.unwrap_or(base_conf.build_jobs),
+            lockfile,
+            locked: self.locked,
+            timings: self.timings,
+            sort_diagnostics: self.sort_diagnostics,
+            baseline: self.baseline,
+            write_baseline: self.write_baseline,
+            timeout: self.timeout.map(|seconds| Duration::from_secs(seconds.get())),
+            ..base_conf
         };
 
         // Prepare backend
@@ -60,9 +195,112 @@ impl CheckCommand {
             backend_conf,
             info,
             cargo_args: self.cargo_args,
+            fix: self.fix,
+            output_format: self.output_format,
+            output_file: self.output_file,
         })
     }
 
+    /// Determines the lint crates, which should be used. Lints specified with
+    /// `--lints` take precedence over the lints from `Cargo.toml`.
+    fn resolve_lints(&self, config: Option<Config>) -> Result<(LintsOrigin, BTreeMap<String, LintDependencyEntry>)> {
+        let (origin, lints) = match self.lints_from_cli()? {
+            Some(lints) => (LintsOrigin::Cli, Some(lints)),
+            None => (LintsOrigin::Manifest, config.map(|config| config.lints)),
+        };
+
+        let lints = lints
+            .into_iter()
+            .flatten()
+            .map(|(name, dep)| (name, dep.into_dep_entry()))
+            .collect();
+
+        Ok((origin, lints))
+    }
+
+    /// Renders the resolved lint crates, including their configuration, the
+    /// excluded files and the options passed on the command line, as TOML.
+    /// The first line is a comment, explaining where the lint crates came from.
+    fn render_config(&self, config: Option<Config>) -> Result<String> {
+        #[derive(Serialize)]
+        struct PrintedConfig<'a> {
+            exclude: Vec<String>,
+            lints: BTreeMap<&'a str, PrintedLint<'a>>,
+            #[serde(skip_serializing_if = "PrintedCli::is_empty")]
+            cli: PrintedCli<'a>,
+        }
+
+        /// The options passed on the command line, which affect the linting
+        #[derive(Serialize, Default, PartialEq)]
+        #[serde(rename_all = "kebab-case")]
+        struct PrintedCli<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            jobs: Option<usize>,
+            #[serde(skip_serializing_if = "std::ops::Not::not")]
+            locked: bool,
+            #[serde(skip_serializing_if = "std::ops::Not::not")]
+            forward_rust_flags: bool,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            timeout: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            baseline: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            write_baseline: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            driver_path: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            toolchain: Option<&'a str>,
+        }
+
+        impl PrintedCli<'_> {
+            fn is_empty(&self) -> bool {
+                *self == Self::default()
+            }
+        }
+
+        #[derive(Serialize)]
+        struct PrintedLint<'a> {
+            #[serde(flatten)]
+            dep: &'a LintDependencyEntry,
+            config: Option<&'a toml::Table>,
+        }
+
+        let exclude = config.as_ref().map(|config| config.exclude.clone()).unwrap_or_default();
+        let (origin, lints) = self.resolve_lints(config)?;
+        let printed = PrintedConfig {
+            exclude,
+            cli: PrintedCli {
+                jobs: self.jobs.map(NonZeroUsize::get),
+                locked: self.locked,
+                forward_rust_flags: self.forward_rust_flags,
+                timeout: self.timeout.map(NonZeroU64::get),
+                baseline: self.baseline.as_deref().map(Utf8Path::as_str),
+                write_baseline: self.write_baseline.as_deref().map(Utf8Path::as_str),
+                driver_path: self.driver_path.as_deref().map(Utf8Path::as_str),
+                toolchain: self.toolchain.as_deref(),
+            },
+            lints: lints
+                .iter()
+                .map(|(name, dep)| {
+                    let lint = PrintedLint {
+                        dep,
+                        config: dep.config.as_ref(),
+                    };
+                    (name.as_str(), lint)
+                })
+                .collect(),
+        };
+        let toml = toml::to_string(&printed).expect("the config can be represented as TOML");
+
+        let origin = match origin {
+            LintsOrigin::Cli => "The lint crates are specified by `--lints`, the lints in `Cargo.toml` are ignored",
+            LintsOrigin::Manifest => {
+                "The lint crates are specified by `[workspace.metadata.marker.lints]` in `Cargo.toml`"
+            },
+        };
+        Ok(format!("# {origin}\n{toml}"))
+    }
+
     fn lints_from_cli(&self) -> Result<Option<BTreeMap<String, LintDependency>>> {
         if self.lints.is_empty() {
             return Ok(None);
@@ -76,7 +314,7 @@ impl CheckCommand {
 
         let path = Utf8Path::new(".");
 
-        let Config { lints } = Config::try_from_str(&virtual_manifest, path)?.unwrap_or_else(|| {
+        let Config { lints, .. } = Config::try_from_str(&virtual_manifest, path)?.unwrap_or_else(|| {
             panic!(
                 "BUG: the config must definitely contain the marker metadata:\
                 \n---\n{virtual_manifest}\n---"
@@ -87,16 +325,257 @@ impl CheckCommand {
     }
 }
 
+/// The place, where the used lint crates were specified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LintsOrigin {
+    Cli,
+    Manifest,
+}
+
+/// The format of the diagnostics, written to the output file.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OutputFormat {
+    /// The diagnostics as they are rendered to stderr, without colors
+    Human,
+    /// A JSON array of the diagnostics, in the format used by rustc
+    Json,
+    /// A SARIF 2.1.0 log, which can be uploaded to code scanning services
+    Sarif,
+}
+
+/// The format of the lints, printed by `--list-lints`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ListFormat {
+    /// One line per lint, with the name and default level
+    Human,
+    /// A JSON array of the lints, with the name, default level, category and description
+    Json,
+}
+
 /// The result of discovering and compiling the lint libraries
 #[derive(Debug)]
 pub(crate) struct CompiledLints {
     pub(crate) backend_conf: backend::Config,
     pub(crate) info: backend::CheckInfo,
     pub(crate) cargo_args: Vec<String>,
+    pub(crate) fix: bool,
+    pub(crate) output_format: OutputFormat,
+    pub(crate) output_file: Option<Utf8PathBuf>,
 }
 
 impl CompiledLints {
     fn lint(self) -> Result {
-        backend::run_check(&self.backend_conf, self.info, &self.cargo_args)
+        let conf = &self.backend_conf;
+        let collect = self.fix
+            || conf.sort_diagnostics
+            || conf.baseline.is_some()
+            || conf.write_baseline.is_some()
+            || self.output_file.is_some();
+        if !collect {
+            return backend::run_check(&self.backend_conf, self.info, &self.cargo_args);
+        }
+
+        let collected = backend::run_check_collect(&self.backend_conf, self.info, &self.cargo_args)?;
+
+        if self.fix {
+            backend::fix::apply_fixes(&self.backend_conf, &collected.diagnostics)?;
+        }
+
+        if let Some(path) = self.output_file {
+            let output = match self.output_format {
+                OutputFormat::Human => backend::render_plain(&collected.diagnostics),
+                OutputFormat::Json => serde_json::to_string_pretty(&collected.diagnostics)
+                    .expect("diagnostics can be represented as JSON"),
+                OutputFormat::Sarif => backend::sarif::to_sarif(&collected.diagnostics),
+            };
+            std::fs::write(&path, output).context(|| format!("Failed to write the diagnostics to {path}"))?;
+        }
+
+        collected.result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{CliCommand, MarkerCli};
+    use clap::Parser;
+
+    const MANIFEST: &str = r#"
+        [workspace.metadata.marker]
+        exclude = ["**/generated/**"]
+
+        [workspace.metadata.marker.lints]
+        naming_lints = { version = "0.1.0", config = { allowed_prefixes = ["get_"] } }
+    "#;
+
+    fn manifest_config() -> Option<Config> {
+        Config::try_from_str(MANIFEST, Utf8Path::new("./Cargo.toml")).unwrap()
+    }
+
+    #[test]
+    fn test_print_config_from_manifest() {
+        let cli = MarkerCli::parse_from(["cargo-marker", "--print-config"]);
+        let printed = cli.check.render_config(manifest_config()).unwrap();
+
+        assert!(printed.starts_with("# The lint crates are specified by `[workspace.metadata.marker.lints]`"));
+        let printed: toml::Table = toml::from_str(&printed).unwrap();
+        let lint = &printed["lints"]["naming_lints"];
+        assert_eq!(lint["version"].as_str(), Some("0.1.0"));
+        assert_eq!(lint["config"]["allowed_prefixes"][0].as_str(), Some("get_"));
+        assert_eq!(printed["exclude"][0].as_str(), Some("**/generated/**"));
+        assert!(printed.get("cli").is_none());
+    }
+
+    #[test]
+    fn test_jobs_arg() {
+        let cli = MarkerCli::parse_from(["cargo-marker", "--jobs", "3"]);
+        assert_eq!(cli.check.jobs, NonZeroUsize::new(3));
+
+        let cli = MarkerCli::parse_from(["cargo-marker"]);
+        assert_eq!(cli.check.jobs, None);
+
+        assert!(MarkerCli::try_parse_from(["cargo-marker", "--jobs", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_timeout_arg() {
+        let cli = MarkerCli::parse_from(["cargo-marker", "--timeout", "600"]);
+        assert_eq!(cli.check.timeout, NonZeroU64::new(600));
+
+        let cli = MarkerCli::parse_from(["cargo-marker", "check", "--timeout", "30"]);
+        assert!(matches!(cli.command, Some(CliCommand::Check(check)) if check.timeout == NonZeroU64::new(30)));
+
+        assert!(MarkerCli::try_parse_from(["cargo-marker", "--timeout", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_locked_arg() {
+        let cli = MarkerCli::parse_from(["cargo-marker", "--locked"]);
+        assert!(cli.check.locked);
+
+        let cli = MarkerCli::parse_from(["cargo-marker", "check", "--locked"]);
+        assert!(matches!(cli.command, Some(CliCommand::Check(check)) if check.locked));
+
+        let cli = MarkerCli::parse_from(["cargo-marker"]);
+        assert!(!cli.check.locked);
+    }
+
+    #[test]
+    fn test_timings_arg() {
+        let cli = MarkerCli::parse_from(["cargo-marker", "--timings"]);
+        assert!(cli.check.timings);
+
+        let cli = MarkerCli::parse_from(["cargo-marker"]);
+        assert!(!cli.check.timings);
+    }
+
+    #[test]
+    fn test_explain_arg() {
+        let cli = MarkerCli::parse_from(["cargo-marker", "--explain", "not_using_has_span_trait"]);
+        assert_eq!(cli.check.explain.as_deref(), Some("not_using_has_span_trait"));
+
+        let cli = MarkerCli::parse_from(["cargo-marker"]);
+        assert!(cli.check.explain.is_none());
+    }
+
+    #[test]
+    fn test_list_lints_arg() {
+        let cli = MarkerCli::parse_from(["cargo-marker", "--list-lints", "--list-format", "json"]);
+        assert!(cli.check.list_lints);
+        assert_eq!(cli.check.list_format, ListFormat::Json);
+
+        let cli = MarkerCli::parse_from(["cargo-marker", "--list-lints"]);
+        assert_eq!(cli.check.list_format, ListFormat::Human);
+
+        let cli = MarkerCli::parse_from(["cargo-marker"]);
+        assert!(!cli.check.list_lints);
+
+        assert!(MarkerCli::try_parse_from(["cargo-marker", "--list-format", "json"]).is_err());
+    }
+
+    #[test]
+    fn test_file_arg() {
+        let cli = MarkerCli::parse_from(["cargo-marker", "--file", "foo.rs", "--", "--crate-type=lib"]);
+        assert_eq!(cli.check.file.as_deref(), Some(Utf8Path::new("foo.rs")));
+        assert_eq!(cli.check.cargo_args, ["--crate-type=lib"]);
+
+        let cli = MarkerCli::parse_from(["cargo-marker"]);
+        assert!(cli.check.file.is_none());
+
+        assert!(MarkerCli::try_parse_from(["cargo-marker", "--file", "foo.rs", "--fix"]).is_err());
+    }
+
+    #[test]
+    fn test_baseline_args() {
+        let cli = MarkerCli::parse_from(["cargo-marker", "--baseline", "baseline.json"]);
+        assert_eq!(cli.check.baseline.as_deref(), Some(Utf8Path::new("baseline.json")));
+        assert!(cli.check.write_baseline.is_none());
+
+        let cli = MarkerCli::parse_from(["cargo-marker", "--write-baseline", "baseline.json"]);
+        assert_eq!(
+            cli.check.write_baseline.as_deref(),
+            Some(Utf8Path::new("baseline.json"))
+        );
+        assert!(cli.check.baseline.is_none());
+
+        assert!(
+            MarkerCli::try_parse_from(["cargo-marker", "--file", "foo.rs", "--baseline", "baseline.json"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_driver_path_arg() {
+        let cli = MarkerCli::parse_from(["cargo-marker", "--driver-path", "target/debug/marker_rustc_driver"]);
+        assert_eq!(
+            cli.check.driver_path.as_deref(),
+            Some(Utf8Path::new("target/debug/marker_rustc_driver"))
+        );
+
+        let cli = MarkerCli::parse_from(["cargo-marker"]);
+        assert!(cli.check.driver_path.is_none());
+
+        let cli = MarkerCli::parse_from(["cargo-marker", "--driver-path", "driver", "--toolchain", "nightly"]);
+        assert_eq!(cli.check.toolchain.as_deref(), Some("nightly"));
+
+        assert!(MarkerCli::try_parse_from(["cargo-marker", "--toolchain", "nightly"]).is_err());
+    }
+
+    #[test]
+    fn test_print_config_with_cli_override() {
+        let cli = MarkerCli::parse_from(["cargo-marker", "--print-config", "--lints", "naming_lints = '0.2.0'"]);
+        let printed = cli.check.render_config(manifest_config()).unwrap();
+
+        assert!(printed.starts_with("# The lint crates are specified by `--lints`"));
+        let printed: toml::Table = toml::from_str(&printed).unwrap();
+        let lint = &printed["lints"]["naming_lints"];
+        assert_eq!(lint["version"].as_str(), Some("0.2.0"));
+        assert!(lint.get("config").is_none());
+        // The excluded files of the manifest are still used
+        assert_eq!(printed["exclude"][0].as_str(), Some("**/generated/**"));
+    }
+
+    #[test]
+    fn test_print_config_with_cli_options() {
+        let cli = MarkerCli::parse_from([
+            "cargo-marker",
+            "--print-config",
+            "--jobs",
+            "2",
+            "--locked",
+            "--timeout",
+            "60",
+            "--baseline",
+            "marker-baseline.json",
+        ]);
+        let printed = cli.check.render_config(manifest_config()).unwrap();
+
+        let printed: toml::Table = toml::from_str(&printed).unwrap();
+        let cli = printed["cli"].as_table().unwrap();
+        assert_eq!(cli["jobs"].as_integer(), Some(2));
+        assert_eq!(cli["locked"].as_bool(), Some(true));
+        assert_eq!(cli["timeout"].as_integer(), Some(60));
+        assert_eq!(cli["baseline"].as_str(), Some("marker-baseline.json"));
+        assert!(cli.get("write-baseline").is_none());
     }
 }
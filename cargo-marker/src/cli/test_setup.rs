@@ -12,7 +12,8 @@ pub(crate) struct TestSetupCommand {
 
 impl TestSetupCommand {
     pub(crate) fn run(self, config: Option<Config>) -> Result {
-        let lints = self.check.compile_lints(config)?;
+        // UI tests use local lint crates, a lockfile is not needed
+        let lints = self.check.compile_lints(config, None)?;
 
         for (name, value) in lints.info.env {
             println!("env:{name}={value}");
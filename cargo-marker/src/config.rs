@@ -36,6 +36,13 @@ struct WorkspaceMetadata {
 pub struct Config {
     /// A list of lints.
     pub lints: BTreeMap<String, LintDependency>,
+    /// Glob patterns of files, which should not be linted, like:
+    /// ```toml
+    /// [workspace.metadata.marker]
+    /// exclude = ["**/generated/**"]
+    /// ```
+    #[serde(default)]
+    pub exclude: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -67,6 +74,7 @@ impl LintDependency {
                 package: None,
                 default_features: None,
                 features: None,
+                config: None,
             },
             LintDependency::Full(entry) => entry,
         }
@@ -82,6 +90,15 @@ pub struct LintDependencyEntry {
     #[serde(rename = "default-features")]
     pub(crate) default_features: Option<bool>,
     pub(crate) features: Option<Vec<String>>,
+    /// The configuration of the lint crate, like:
+    /// ```toml
+    /// [workspace.metadata.marker.lints.lint_crate.config]
+    /// allowed_prefixes = ["get_", "set_"]
+    /// ```
+    ///
+    /// It's passed to the lint crate and therefore not a part of the dependency.
+    #[serde(skip_serializing)]
+    pub(crate) config: Option<toml::Table>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -160,3 +177,63 @@ impl Config {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lint_crate_config() {
+        let manifest = r#"
+            [workspace.metadata.marker.lints]
+            simple_lints = "0.1.0"
+
+            [workspace.metadata.marker.lints.naming_lints]
+            version = "0.2.0"
+
+            [workspace.metadata.marker.lints.naming_lints.config]
+            allowed_prefixes = ["get_", "set_"]
+        "#;
+
+        let config = Config::try_from_str(manifest, Utf8Path::new("./Cargo.toml"))
+            .unwrap()
+            .unwrap();
+        let mut lints = config.lints.into_iter().map(|(name, dep)| (name, dep.into_dep_entry()));
+
+        let (name, naming) = lints.next().unwrap();
+        assert_eq!(name, "naming_lints");
+        let config = naming.config.expect("the config should be parsed");
+        assert_eq!(config["allowed_prefixes"].as_array().unwrap().len(), 2);
+        assert!(matches!(naming.source, Source::Registry { version, .. } if version == "0.2.0"));
+
+        let (name, simple) = lints.next().unwrap();
+        assert_eq!(name, "simple_lints");
+        assert!(simple.config.is_none());
+    }
+
+    #[test]
+    fn test_malformed_lint_crate_config() {
+        let manifest = r#"
+            [workspace.metadata.marker.lints]
+            naming_lints = { version = "0.2.0", config = 17 }
+        "#;
+
+        assert!(Config::try_from_str(manifest, Utf8Path::new("./Cargo.toml")).is_err());
+    }
+
+    #[test]
+    fn test_excluded_files() {
+        let manifest = r#"
+            [workspace.metadata.marker]
+            exclude = ["**/generated/**"]
+
+            [workspace.metadata.marker.lints]
+            simple_lints = "0.1.0"
+        "#;
+
+        let config = Config::try_from_str(manifest, Utf8Path::new("./Cargo.toml"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(config.exclude, vec!["**/generated/**"]);
+    }
+}
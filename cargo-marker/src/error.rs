@@ -48,6 +48,16 @@ or:
         display::cli("rustup toolchain install {toolchain} --component rustc-dev llvm-tools")
     ))]
     BuildDriver,
+
+    #[error("The linting didn't finish within {seconds} seconds and was terminated")]
+    #[diagnostic(help(
+        "\
+The driver might be waiting on a lock, like the build directory lock of another
+Cargo process. The timeout can be increased with {} or the {} environment value.",
+        "--timeout".blue(),
+        "MARKER_TIMEOUT".blue(),
+    ))]
+    Timeout { seconds: u64 },
 }
 
 // region replace marker version stable
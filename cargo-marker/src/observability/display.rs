@@ -42,6 +42,11 @@ pub(crate) fn print_stage(name: &str) {
     println!("\n      {} {}", "Marker".bold().green(), stage(name));
 }
 
+/// Displays a warning, formatted like the warnings emitted by cargo.
+pub(crate) fn print_warning(msg: &str) {
+    eprintln!("{}: {msg}", "warning".bold().yellow());
+}
+
 pub(crate) fn stage(name: &str) -> impl fmt::Display + '_ {
     name.white().bold()
 }
@@ -91,12 +91,15 @@
 mod map;
 pub use map::*;
 
+use std::{cell::RefCell, collections::HashSet};
+
 use marker_api::{
-    common::{ExpnId, ExprId, SpanId, SymbolId},
+    common::{DriverTyId, ExpnId, ExprId, PatId, SpanId, SymbolId, SynTyId, VariantId},
     context::{MarkerContextCallbacks, MarkerContextData},
     diagnostic::Diagnostic,
     ffi::{self, FfiOption},
     prelude::*,
+    sem::{AdtField, AdtVariant, ConstValue},
     span::{ExpnInfo, FileInfo, FilePos, SpanPos, SpanSource},
 };
 
@@ -114,11 +117,22 @@ use marker_api::{
 #[repr(C)]
 pub struct MarkerContextWrapper<'ast> {
     driver: &'ast dyn MarkerContextDriver<'ast>,
+    sink: Option<&'ast dyn DiagnosticSink>,
+    /// The lint names and keys of diagnostics emitted via
+    /// [`MarkerContext::emit_lint_once`].
+    emitted_keys: RefCell<HashSet<(&'static str, String)>>,
 }
 
 impl<'ast> MarkerContextWrapper<'ast> {
-    pub fn new(driver: &'ast dyn MarkerContextDriver<'ast>) -> Self {
-        Self { driver }
+    /// Creates a new wrapper for the given driver. Diagnostics are emitted via
+    /// the given `sink` or [`MarkerContextDriver::emit_diag`], if no sink was
+    /// provided.
+    pub fn new(driver: &'ast dyn MarkerContextDriver<'ast>, sink: Option<&'ast dyn DiagnosticSink>) -> Self {
+        Self {
+            driver,
+            sink,
+            emitted_keys: RefCell::default(),
+        }
     }
 
     #[must_use]
@@ -126,8 +140,20 @@ impl<'ast> MarkerContextWrapper<'ast> {
         MarkerContextCallbacks {
             data: unsafe { &*(self as *const MarkerContextWrapper).cast::<MarkerContextData>() },
             emit_diag,
+            is_key_emitted,
             resolve_ty_ids,
+            resolve_fn_ids,
+            impls_of_trait,
+            impls_for_type,
+            implements_trait,
+            ty_is_copy,
+            ty_is_sized,
+            ty_is_uninhabited,
+            ty_size,
+            ty_align,
             expr_ty,
+            expr_adjusted_ty,
+            pat_ty,
             span,
             span_snippet,
             span_source,
@@ -135,27 +161,110 @@ impl<'ast> MarkerContextWrapper<'ast> {
             span_expn_info,
             symbol_str,
             resolve_method_target,
+            resolve_call_target,
+            is_union_field_access,
+            item_const_value,
+            const_expr_value,
+            resolve_syn_ty,
+            variant_discriminant,
+            adt_fields,
+            adt_variants,
         }
     }
 }
 
+/// A sink receiving all diagnostics emitted by lint crates.
+///
+/// By default, diagnostics are emitted by the driver via
+/// [`MarkerContextDriver::emit_diag`]. Tools embedding Marker can provide a
+/// custom sink to the [`Adapter`](crate::Adapter), to collect or forward the
+/// diagnostics themselves.
+///
+/// The sink only receives diagnostics, which should actually be emitted.
+/// Diagnostics of allowed lints, in excluded files, suppressed by a
+/// `// marker:ignore` comment or with an already emitted
+/// [`emit_lint_once`](MarkerContext::emit_lint_once) key are filtered out
+/// beforehand.
+pub trait DiagnosticSink: std::fmt::Debug {
+    /// Called for every emitted diagnostic. The [`Diagnostic`] provides the
+    /// lint, the effective lint level at the emission node, the message, the
+    /// main span and all additional parts, like notes and suggestions.
+    fn emit_diag(&self, diag: &Diagnostic<'_, '_>);
+}
+
 pub trait MarkerContextDriver<'ast> {
-    fn emit_diag(&'ast self, diag: &Diagnostic<'_, 'ast>);
+    /// Returns `true`, if the diagnostic shouldn't be emitted, since its span
+    /// is in an excluded file or it's suppressed by a `// marker:ignore` comment.
+    /// This is checked, before the diagnostic is passed to the
+    /// [`DiagnosticSink`] or [`MarkerContextDriver::emit_diag`].
+    fn is_diag_suppressed(&'ast self, diag: &Diagnostic<'_, 'ast>) -> bool;
+    /// Emits the diagnostic. Returns `false`, if the driver couldn't emit it.
+    fn emit_diag(&'ast self, diag: &Diagnostic<'_, 'ast>) -> bool;
 
     fn resolve_ty_ids(&'ast self, path: &str) -> &'ast [TyDefId];
+    fn resolve_fn_ids(&'ast self, path: &str) -> &'ast [ItemId];
+    fn impls_of_trait(&'ast self, trait_id: ItemId) -> &'ast [ItemId];
+    fn impls_for_type(&'ast self, ty_id: TyDefId) -> &'ast [ItemId];
+    fn implements_trait(&'ast self, ty: DriverTyId, trait_id: TyDefId, generics: &[DriverTyId]) -> bool;
+    fn ty_is_copy(&'ast self, ty: DriverTyId) -> bool;
+    fn ty_is_sized(&'ast self, ty: DriverTyId) -> bool;
+    fn ty_is_uninhabited(&'ast self, ty: DriverTyId) -> bool;
+    fn ty_size(&'ast self, ty: DriverTyId) -> Option<u64>;
+    fn ty_align(&'ast self, ty: DriverTyId) -> Option<u64>;
 
     fn expr_ty(&'ast self, expr: ExprId) -> marker_api::sem::TyKind<'ast>;
+    fn expr_adjusted_ty(&'ast self, expr: ExprId) -> marker_api::sem::TyKind<'ast>;
+    fn pat_ty(&'ast self, pat: PatId) -> marker_api::sem::TyKind<'ast>;
     fn span(&'ast self, owner: SpanId) -> &'ast Span<'ast>;
     fn span_snippet(&'ast self, span: &Span<'_>) -> Option<&'ast str>;
     fn span_source(&'ast self, span: &Span<'_>) -> SpanSource<'ast>;
     fn span_expn_info(&'ast self, expn_id: ExpnId) -> Option<&'ast ExpnInfo<'ast>>;
     fn span_pos_to_file_loc(&'ast self, file: &FileInfo<'ast>, pos: SpanPos) -> Option<FilePos<'ast>>;
     fn symbol_str(&'ast self, api_id: SymbolId) -> &'ast str;
-    fn resolve_method_target(&'ast self, id: ExprId) -> ItemId;
+    fn resolve_method_target(&'ast self, id: ExprId) -> Option<ItemId>;
+    fn resolve_call_target(&'ast self, id: ExprId) -> Option<ItemId>;
+    fn is_union_field_access(&'ast self, id: ExprId) -> bool;
+    fn item_const_value(&'ast self, id: ItemId) -> Option<&'ast ConstValue<'ast>>;
+    fn const_expr_value(&'ast self, expr: ExprId) -> Option<&'ast ConstValue<'ast>>;
+    fn resolve_syn_ty(&'ast self, id: SynTyId) -> Option<marker_api::sem::TyKind<'ast>>;
+    fn variant_discriminant(&'ast self, id: VariantId) -> Option<i128>;
+    fn adt_fields(&'ast self, ty: DriverTyId) -> &'ast [AdtField<'ast>];
+    fn adt_variants(&'ast self, ty: DriverTyId) -> &'ast [AdtVariant<'ast>];
 }
 
 extern "C" fn emit_diag<'a, 'ast>(data: &'ast MarkerContextData, diag: &Diagnostic<'a, 'ast>) {
-    unsafe { as_driver(data) }.emit_diag(diag);
+    let wrapper = unsafe { as_wrapper(data) };
+    let key = diag.key.get().map(|key| (diag.lint.name, key.get().to_string()));
+    // Another diagnostic with the same key might have been emitted, since the
+    // builder of this one was created.
+    if key
+        .as_ref()
+        .is_some_and(|key| wrapper.emitted_keys.borrow().contains(key))
+    {
+        return;
+    }
+    if wrapper.driver.is_diag_suppressed(diag) {
+        return;
+    }
+
+    let emitted = match wrapper.sink {
+        Some(sink) => {
+            sink.emit_diag(diag);
+            true
+        },
+        None => wrapper.driver.emit_diag(diag),
+    };
+    if let Some(key) = key.filter(|_| emitted) {
+        wrapper.emitted_keys.borrow_mut().insert(key);
+    }
+}
+
+extern "C" fn is_key_emitted<'ast>(data: &'ast MarkerContextData, lint: &'static Lint, key: ffi::FfiStr<'_>) -> bool {
+    let wrapper = unsafe { as_wrapper(data) };
+    wrapper
+        .emitted_keys
+        .borrow()
+        .contains(&(lint.name, key.get().to_string()))
 }
 
 extern "C" fn resolve_ty_ids<'ast>(
@@ -165,12 +274,68 @@ extern "C" fn resolve_ty_ids<'ast>(
     unsafe { as_driver(data) }.resolve_ty_ids((&path).into()).into()
 }
 
+extern "C" fn resolve_fn_ids<'ast>(
+    data: &'ast MarkerContextData,
+    path: ffi::FfiStr<'_>,
+) -> ffi::FfiSlice<'ast, ItemId> {
+    unsafe { as_driver(data) }.resolve_fn_ids((&path).into()).into()
+}
+
+extern "C" fn impls_of_trait<'ast>(data: &'ast MarkerContextData, trait_id: ItemId) -> ffi::FfiSlice<'ast, ItemId> {
+    unsafe { as_driver(data) }.impls_of_trait(trait_id).into()
+}
+
+extern "C" fn impls_for_type<'ast>(data: &'ast MarkerContextData, ty_id: TyDefId) -> ffi::FfiSlice<'ast, ItemId> {
+    unsafe { as_driver(data) }.impls_for_type(ty_id).into()
+}
+
+extern "C" fn implements_trait<'ast>(
+    data: &'ast MarkerContextData,
+    ty: DriverTyId,
+    trait_id: TyDefId,
+    generics: ffi::FfiSlice<'_, DriverTyId>,
+) -> bool {
+    unsafe { as_driver(data) }.implements_trait(ty, trait_id, generics.get())
+}
+
+extern "C" fn ty_is_copy<'ast>(data: &'ast MarkerContextData, ty: DriverTyId) -> bool {
+    unsafe { as_driver(data) }.ty_is_copy(ty)
+}
+
+extern "C" fn ty_is_sized<'ast>(data: &'ast MarkerContextData, ty: DriverTyId) -> bool {
+    unsafe { as_driver(data) }.ty_is_sized(ty)
+}
+
+extern "C" fn ty_is_uninhabited<'ast>(data: &'ast MarkerContextData, ty: DriverTyId) -> bool {
+    unsafe { as_driver(data) }.ty_is_uninhabited(ty)
+}
+
+extern "C" fn ty_size<'ast>(data: &'ast MarkerContextData, ty: DriverTyId) -> FfiOption<u64> {
+    unsafe { as_driver(data) }.ty_size(ty).into()
+}
+
+extern "C" fn ty_align<'ast>(data: &'ast MarkerContextData, ty: DriverTyId) -> FfiOption<u64> {
+    unsafe { as_driver(data) }.ty_align(ty).into()
+}
+
 // False positive because `SemTyKind` is non-exhaustive
 #[allow(improper_ctypes_definitions)]
 extern "C" fn expr_ty<'ast>(data: &'ast MarkerContextData, expr: ExprId) -> marker_api::sem::TyKind<'ast> {
     unsafe { as_driver(data) }.expr_ty(expr)
 }
 
+// False positive because `SemTyKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn expr_adjusted_ty<'ast>(data: &'ast MarkerContextData, expr: ExprId) -> marker_api::sem::TyKind<'ast> {
+    unsafe { as_driver(data) }.expr_adjusted_ty(expr)
+}
+
+// False positive because `SemTyKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn pat_ty<'ast>(data: &'ast MarkerContextData, pat: PatId) -> marker_api::sem::TyKind<'ast> {
+    unsafe { as_driver(data) }.pat_ty(pat)
+}
+
 extern "C" fn span<'ast>(data: &'ast MarkerContextData, span_id: SpanId) -> &'ast Span<'ast> {
     unsafe { as_driver(data) }.span(span_id)
 }
@@ -204,13 +369,272 @@ extern "C" fn symbol_str<'ast>(data: &'ast MarkerContextData, sym: SymbolId) ->
     unsafe { as_driver(data) }.symbol_str(sym).into()
 }
 
-extern "C" fn resolve_method_target<'ast>(data: &'ast MarkerContextData, id: ExprId) -> ItemId {
-    unsafe { as_driver(data) }.resolve_method_target(id)
+extern "C" fn resolve_method_target<'ast>(data: &'ast MarkerContextData, id: ExprId) -> FfiOption<ItemId> {
+    unsafe { as_driver(data) }.resolve_method_target(id).into()
+}
+
+extern "C" fn resolve_call_target<'ast>(data: &'ast MarkerContextData, id: ExprId) -> FfiOption<ItemId> {
+    unsafe { as_driver(data) }.resolve_call_target(id).into()
+}
+
+extern "C" fn is_union_field_access<'ast>(data: &'ast MarkerContextData, id: ExprId) -> bool {
+    unsafe { as_driver(data) }.is_union_field_access(id)
+}
+
+extern "C" fn item_const_value<'ast>(data: &'ast MarkerContextData, id: ItemId) -> FfiOption<&'ast ConstValue<'ast>> {
+    unsafe { as_driver(data) }.item_const_value(id).into()
+}
+
+extern "C" fn const_expr_value<'ast>(data: &'ast MarkerContextData, expr: ExprId) -> FfiOption<&'ast ConstValue<'ast>> {
+    unsafe { as_driver(data) }.const_expr_value(expr).into()
+}
+
+// False positive because `SemTyKind` is non-exhaustive
+#[allow(improper_ctypes_definitions)]
+extern "C" fn resolve_syn_ty<'ast>(
+    data: &'ast MarkerContextData,
+    id: SynTyId,
+) -> FfiOption<marker_api::sem::TyKind<'ast>> {
+    unsafe { as_driver(data) }.resolve_syn_ty(id).into()
+}
+
+// `i128` has no stable ABI, but the API and driver are always compiled
+// with the same toolchain
+#[allow(improper_ctypes_definitions)]
+extern "C" fn variant_discriminant(data: &MarkerContextData, id: VariantId) -> FfiOption<i128> {
+    unsafe { as_driver(data) }.variant_discriminant(id).into()
+}
+
+extern "C" fn adt_fields<'ast>(data: &'ast MarkerContextData, ty: DriverTyId) -> ffi::FfiSlice<'ast, AdtField<'ast>> {
+    unsafe { as_driver(data) }.adt_fields(ty).into()
+}
+
+extern "C" fn adt_variants<'ast>(
+    data: &'ast MarkerContextData,
+    ty: DriverTyId,
+) -> ffi::FfiSlice<'ast, AdtVariant<'ast>> {
+    unsafe { as_driver(data) }.adt_variants(ty).into()
+}
+
+/// # Safety
+/// The `data` must be a valid pointer to a [`MarkerContextWrapper`]
+unsafe fn as_wrapper<'ast>(data: &'ast MarkerContextData) -> &'ast MarkerContextWrapper<'ast> {
+    &*(data as *const MarkerContextData).cast::<MarkerContextWrapper>()
 }
 
 /// # Safety
 /// The `data` must be a valid pointer to a [`MarkerContextWrapper`]
 unsafe fn as_driver<'ast>(data: &'ast MarkerContextData) -> &'ast dyn MarkerContextDriver<'ast> {
-    let wrapper = &*(data as *const MarkerContextData).cast::<MarkerContextWrapper>();
-    wrapper.driver
+    as_wrapper(data).driver
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::cell::RefCell;
+
+    use marker_api::{
+        common::{ExprId, Level, SpanSrcId},
+        ffi::FfiSlice,
+    };
+
+    use super::*;
+
+    marker_api::declare_lint! {
+        /// A lint used to test diagnostic sinks.
+        TEST_LINT,
+        Warn,
+    }
+
+    /// A driver, which only provides spans. All spans start at the beginning of
+    /// `src/lib.rs`. Diagnostics should be passed to the sink. Diagnostics with
+    /// `marker:ignore` in their message are suppressed, to test the filtering
+    /// before the sink.
+    pub(crate) struct TestDriver;
+
+    impl<'ast> MarkerContextDriver<'ast> for TestDriver {
+        fn is_diag_suppressed(&'ast self, diag: &Diagnostic<'_, 'ast>) -> bool {
+            diag.msg().contains("marker:ignore")
+        }
+        fn emit_diag(&'ast self, _diag: &Diagnostic<'_, 'ast>) -> bool {
+            unreachable!("diagnostics should be passed to the sink")
+        }
+        fn resolve_ty_ids(&'ast self, _path: &str) -> &'ast [TyDefId] {
+            unreachable!()
+        }
+        fn resolve_fn_ids(&'ast self, _path: &str) -> &'ast [ItemId] {
+            unreachable!()
+        }
+        fn impls_of_trait(&'ast self, _trait_id: ItemId) -> &'ast [ItemId] {
+            unreachable!()
+        }
+        fn impls_for_type(&'ast self, _ty_id: TyDefId) -> &'ast [ItemId] {
+            unreachable!()
+        }
+        fn implements_trait(&'ast self, _ty: DriverTyId, _trait_id: TyDefId, _generics: &[DriverTyId]) -> bool {
+            unreachable!()
+        }
+        fn ty_is_copy(&'ast self, _ty: DriverTyId) -> bool {
+            unreachable!()
+        }
+        fn ty_is_sized(&'ast self, _ty: DriverTyId) -> bool {
+            unreachable!()
+        }
+        fn ty_is_uninhabited(&'ast self, _ty: DriverTyId) -> bool {
+            unreachable!()
+        }
+        fn ty_size(&'ast self, _ty: DriverTyId) -> Option<u64> {
+            unreachable!()
+        }
+        fn ty_align(&'ast self, _ty: DriverTyId) -> Option<u64> {
+            unreachable!()
+        }
+        fn expr_ty(&'ast self, _expr: ExprId) -> marker_api::sem::TyKind<'ast> {
+            unreachable!()
+        }
+
+        fn expr_adjusted_ty(&'ast self, _expr: ExprId) -> marker_api::sem::TyKind<'ast> {
+            unreachable!()
+        }
+        fn pat_ty(&'ast self, _pat: PatId) -> marker_api::sem::TyKind<'ast> {
+            unreachable!()
+        }
+        fn span(&'ast self, _owner: SpanId) -> &'ast Span<'ast> {
+            Box::leak(Box::new(Span::new(
+                SpanSrcId::new(0),
+                false,
+                SpanPos::new(0),
+                SpanPos::new(0),
+            )))
+        }
+        fn span_snippet(&'ast self, _span: &Span<'_>) -> Option<&'ast str> {
+            unreachable!()
+        }
+        fn span_source(&'ast self, _span: &Span<'_>) -> SpanSource<'ast> {
+            SpanSource::File(Box::leak(Box::new(FileInfo::new("src/lib.rs", SpanSrcId::new(0)))))
+        }
+        fn span_expn_info(&'ast self, _expn_id: ExpnId) -> Option<&'ast ExpnInfo<'ast>> {
+            unreachable!()
+        }
+        fn span_pos_to_file_loc(&'ast self, _file: &FileInfo<'ast>, _pos: SpanPos) -> Option<FilePos<'ast>> {
+            Some(FilePos::new(1, 1))
+        }
+        fn symbol_str(&'ast self, _api_id: SymbolId) -> &'ast str {
+            unreachable!()
+        }
+        fn resolve_method_target(&'ast self, _id: ExprId) -> Option<ItemId> {
+            unreachable!()
+        }
+        fn resolve_call_target(&'ast self, _id: ExprId) -> Option<ItemId> {
+            unreachable!()
+        }
+        fn is_union_field_access(&'ast self, _id: ExprId) -> bool {
+            unreachable!()
+        }
+        fn item_const_value(&'ast self, _id: ItemId) -> Option<&'ast ConstValue<'ast>> {
+            unreachable!()
+        }
+        fn const_expr_value(&'ast self, _expr: ExprId) -> Option<&'ast ConstValue<'ast>> {
+            unreachable!()
+        }
+        fn resolve_syn_ty(&'ast self, _id: SynTyId) -> Option<marker_api::sem::TyKind<'ast>> {
+            unreachable!()
+        }
+        fn variant_discriminant(&'ast self, _id: VariantId) -> Option<i128> {
+            unreachable!()
+        }
+        fn adt_fields(&'ast self, _ty: DriverTyId) -> &'ast [AdtField<'ast>] {
+            unreachable!()
+        }
+        fn adt_variants(&'ast self, _ty: DriverTyId) -> &'ast [AdtVariant<'ast>] {
+            unreachable!()
+        }
+    }
+
+    /// A sink, which records the lint, level and message of every diagnostic.
+    #[derive(Debug, Default)]
+    pub(crate) struct VecSink {
+        pub(crate) diags: RefCell<Vec<(&'static str, Level, String)>>,
+    }
+
+    impl DiagnosticSink for VecSink {
+        fn emit_diag(&self, diag: &Diagnostic<'_, '_>) {
+            self.diags
+                .borrow_mut()
+                .push((diag.lint.name, diag.level, diag.msg().to_string()));
+        }
+    }
+
+    /// Emits a diagnostic of [`TEST_LINT`], with the `deny` level, instead of
+    /// the default `warn` level.
+    fn emit(callbacks: &MarkerContextCallbacks<'_>, msg: &str, key: Option<&str>) {
+        let span = Span::new(SpanSrcId::new(0), false, SpanPos::new(0), SpanPos::new(4));
+        let diag = Diagnostic {
+            lint: TEST_LINT,
+            level: Level::Deny,
+            msg: msg.into(),
+            node: ExprId::new(0).into(),
+            span: &span,
+            parts: FfiSlice::from(&[][..]),
+            key: key.map(Into::into).into(),
+        };
+        (callbacks.emit_diag)(callbacks.data, &diag);
+    }
+
+    #[test]
+    fn test_emitted_keys() {
+        let driver = TestDriver;
+        let sink = VecSink::default();
+        let wrapper = MarkerContextWrapper::new(&driver, Some(&sink));
+        let callbacks = wrapper.create_callbacks();
+
+        // Keys are only recorded, once a diagnostic has been emitted
+        assert!(!(callbacks.is_key_emitted)(callbacks.data, TEST_LINT, "a".into()));
+        emit(&callbacks, "first", Some("a"));
+        assert!((callbacks.is_key_emitted)(callbacks.data, TEST_LINT, "a".into()));
+        assert!(!(callbacks.is_key_emitted)(callbacks.data, TEST_LINT, "b".into()));
+
+        // Diagnostics with an emitted key are dropped
+        emit(&callbacks, "second", Some("a"));
+        emit(&callbacks, "third", Some("b"));
+        emit(&callbacks, "fourth", None);
+
+        assert_eq!(
+            sink.diags.into_inner(),
+            vec![
+                (TEST_LINT.name, Level::Deny, "first".to_string()),
+                (TEST_LINT.name, Level::Deny, "third".to_string()),
+                (TEST_LINT.name, Level::Deny, "fourth".to_string()),
+            ]
+        );
+
+        // The keys are tracked per context
+        let other_wrapper = MarkerContextWrapper::new(&driver, None);
+        let other_callbacks = other_wrapper.create_callbacks();
+        assert!(!(other_callbacks.is_key_emitted)(
+            other_callbacks.data,
+            TEST_LINT,
+            "a".into()
+        ));
+    }
+
+    #[test]
+    fn test_emit_diag_to_sink() {
+        let driver = TestDriver;
+        let sink = VecSink::default();
+        let wrapper = MarkerContextWrapper::new(&driver, Some(&sink));
+        let callbacks = wrapper.create_callbacks();
+
+        emit(&callbacks, "first", None);
+        // Suppressed diagnostics don't reach the sink and don't record their key
+        emit(&callbacks, "marker:ignore", Some("a"));
+        emit(&callbacks, "second", Some("a"));
+
+        assert_eq!(
+            sink.diags.into_inner(),
+            vec![
+                (TEST_LINT.name, Level::Deny, "first".to_string()),
+                (TEST_LINT.name, Level::Deny, "second".to_string()),
+            ]
+        );
+    }
 }
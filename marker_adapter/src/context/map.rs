@@ -27,6 +27,8 @@ impl<'ast> AstMapWrapper<'ast> {
                 body,
                 stmt,
                 expr,
+                parent_expr,
+                parent_item,
                 lint_level_at,
             })
             .build()
@@ -41,6 +43,8 @@ pub trait AstMapDriver<'ast> {
     fn body(&'ast self, id: BodyId) -> &'ast ast::Body<'ast>;
     fn stmt(&'ast self, id: StmtId) -> StmtKind<'ast>;
     fn expr(&'ast self, id: ExprId) -> ExprKind<'ast>;
+    fn parent_expr(&'ast self, id: ExprId) -> Option<ExprKind<'ast>>;
+    fn parent_item(&'ast self, id: ExprId) -> Option<ItemId>;
 
     fn lint_level_at(&'ast self, lint: &'static Lint, node: NodeId) -> Level;
 }
@@ -66,6 +70,13 @@ extern "C" fn stmt<'ast>(data: &'ast AstMapData, id: StmtId) -> StmtKind<'ast> {
 extern "C" fn expr<'ast>(data: &'ast AstMapData, id: ExprId) -> ExprKind<'ast> {
     unsafe { as_driver(data) }.expr(id)
 }
+#[allow(improper_ctypes_definitions)] // FP because `ExprKind` is non-exhaustive
+extern "C" fn parent_expr<'ast>(data: &'ast AstMapData, id: ExprId) -> ffi::FfiOption<ExprKind<'ast>> {
+    unsafe { as_driver(data) }.parent_expr(id).into()
+}
+extern "C" fn parent_item<'ast>(data: &'ast AstMapData, id: ExprId) -> ffi::FfiOption<ItemId> {
+    unsafe { as_driver(data) }.parent_item(id).into()
+}
 
 #[allow(improper_ctypes_definitions)] // FP because `NodeId` is non-exhaustive
 extern "C" fn lint_level_at<'ast>(data: &'ast AstMapData, lint: &'static Lint, node: NodeId) -> Level {
@@ -0,0 +1,118 @@
+use crate::error::prelude::*;
+
+use super::EXCLUDED_FILES_ENV;
+
+/// A list of glob patterns for files, which should not be linted. Drivers
+/// should not emit diagnostics for spans in excluded files.
+///
+/// The patterns support the following wildcards:
+/// * `*` matches any number of characters inside a single path segment
+/// * `?` matches a single character inside a path segment
+/// * `**` matches any number of path segments, including none
+#[derive(Debug, Default, Clone)]
+pub struct FileFilter {
+    patterns: Vec<String>,
+}
+
+impl FileFilter {
+    #[must_use]
+    pub fn new(patterns: Vec<String>) -> Self {
+        Self { patterns }
+    }
+
+    /// This function tries to load the patterns from the [`EXCLUDED_FILES_ENV`]
+    /// environment value. The value is expected to be a JSON array of strings.
+    /// An empty filter is returned, if the value is not set.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the content is malformed.
+    pub fn from_env() -> Result<Self> {
+        let Ok(env_str) = std::env::var(EXCLUDED_FILES_ENV) else {
+            return Ok(Self::default());
+        };
+
+        let patterns = serde_json::from_str(&env_str).context(|| {
+            format!(
+                "The content of the `{EXCLUDED_FILES_ENV}` environment variable is malformed. \
+                Dumped its content on the next line:\n---\n{env_str}\n---",
+            )
+        })?;
+        Ok(Self::new(patterns))
+    }
+
+    /// Returns `true`, if the given file matches one of the patterns. Windows
+    /// path separators are normalized to `/` before matching.
+    #[must_use]
+    pub fn is_excluded(&self, file: &str) -> bool {
+        if self.patterns.is_empty() {
+            return false;
+        }
+
+        let file = file.replace('\\', "/");
+        let segments: Vec<_> = file.split('/').collect();
+        self.patterns.iter().any(|pattern| {
+            let pattern: Vec<_> = pattern.split('/').collect();
+            matches_segments(&pattern, &segments)
+        })
+    }
+}
+
+fn matches_segments(pattern: &[&str], segments: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => segments.is_empty(),
+        Some((&"**", rest)) => (0..=segments.len()).any(|skip| matches_segments(rest, &segments[skip..])),
+        Some((first, rest)) => segments
+            .split_first()
+            .is_some_and(|(segment, segments)| matches_segment(first, segment) && matches_segments(rest, segments)),
+    }
+}
+
+fn matches_segment(pattern: &str, segment: &str) -> bool {
+    fn matches(pattern: &[char], segment: &[char]) -> bool {
+        match pattern.split_first() {
+            None => segment.is_empty(),
+            Some(('*', rest)) => (0..=segment.len()).any(|skip| matches(rest, &segment[skip..])),
+            Some(('?', rest)) => !segment.is_empty() && matches(rest, &segment[1..]),
+            Some((c, rest)) => segment.first() == Some(c) && matches(rest, &segment[1..]),
+        }
+    }
+
+    let pattern: Vec<_> = pattern.chars().collect();
+    let segment: Vec<_> = segment.chars().collect();
+    matches(&pattern, &segment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(patterns: &[&str]) -> FileFilter {
+        FileFilter::new(patterns.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn test_double_star() {
+        let filter = filter(&["**/generated/**"]);
+        assert!(filter.is_excluded("src/generated/proto.rs"));
+        assert!(filter.is_excluded("generated/proto.rs"));
+        assert!(filter.is_excluded("/home/user/project/crates/a/generated/nested/file.rs"));
+        assert!(filter.is_excluded(r"src\generated\proto.rs"));
+        assert!(!filter.is_excluded("src/not_generated/proto.rs"));
+        assert!(!filter.is_excluded("src/main.rs"));
+    }
+
+    #[test]
+    fn test_single_segment_wildcards() {
+        let filter = filter(&["src/*_gen.rs", "tests/case_?.rs"]);
+        assert!(filter.is_excluded("src/parser_gen.rs"));
+        assert!(!filter.is_excluded("src/nested/parser_gen.rs"));
+        assert!(filter.is_excluded("tests/case_1.rs"));
+        assert!(!filter.is_excluded("tests/case_12.rs"));
+    }
+
+    #[test]
+    fn test_empty_filter() {
+        assert!(!FileFilter::default().is_excluded("src/main.rs"));
+    }
+}
@@ -4,17 +4,27 @@
 #![allow(clippy::module_name_repetitions)]
 
 mod error;
+mod filter;
 mod loader;
+mod suppression;
+mod timings;
 
 pub mod context;
 
 pub use error::{Error, Result};
+pub use filter::FileFilter;
 pub use loader::LintCrateInfo;
+pub use suppression::is_suppressed_by_comment;
+
+use context::DiagnosticSink;
 
 use loader::LintCrateRegistry;
 use marker_api::Lint;
 use marker_api::{
-    ast::{Body, Crate, EnumVariant, ExprKind, ItemField, ItemKind, StmtKind},
+    ast::{
+        Body, Crate, EnumVariant, ExprKind, GenericParamKind, GenericParams, ItemField, ItemKind, PatKind, StmtKind,
+        TyKind,
+    },
     context::MarkerContext,
     LintPass, LintPassInfo,
 };
@@ -22,6 +32,11 @@ use marker_utils::visitor::{self, Visitor};
 use std::{cell::RefCell, ops::ControlFlow};
 
 pub const LINT_CRATES_ENV: &str = "MARKER_LINT_CRATES";
+pub const LINT_CRATES_CONFIG_ENV: &str = "MARKER_LINT_CRATES_CONFIG";
+pub const EXCLUDED_FILES_ENV: &str = "MARKER_EXCLUDED_FILES";
+/// Setting this environment value to `1` enables the recording of the time
+/// spent in the lint passes. A summary is printed after each crate.
+pub const TIMINGS_ENV: &str = "MARKER_TIMINGS";
 
 /// This struct is the interface used by lint drivers to load lint crates, pass
 /// `marker_api` objects to external lint passes and all other magic you can think of.
@@ -34,6 +49,12 @@ pub struct Adapter {
     /// The effects of the mutability should never reach the driver anyways and
     /// this just makes it way easier to handle the adapter in drivers.
     inner: RefCell<AdapterInner>,
+    /// A custom sink for emitted diagnostics. Drivers emit diagnostics
+    /// themselves, if this is `None`.
+    sink: Option<Box<dyn DiagnosticSink>>,
+    /// Files, which shouldn't be linted. Drivers should check this filter,
+    /// before emitting a diagnostic.
+    file_filter: FileFilter,
 }
 
 #[derive(Debug)]
@@ -49,20 +70,106 @@ impl Adapter {
     /// This function will return an error if an error occurs during the lint
     /// loading process.
     pub fn new(lint_crates: &[LintCrateInfo]) -> Result<Self> {
-        let external_lint_crates = LintCrateRegistry::new(lint_crates)?;
+        Self::with_static_passes(lint_crates, vec![])
+    }
+
+    /// This creates a new [`Adapter`] instance, which loads the given lint crates
+    /// and additionally calls lint passes, which are linked statically into the
+    /// driver. The static passes are called after the lint crates, in the given
+    /// order. This allows drivers to bundle a fixed set of lints, without loading
+    /// them as dynamic libraries.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if an error occurs during the lint
+    /// loading process, if the passes define conflicting lints or if a static
+    /// pass requires a configuration.
+    pub fn with_static_passes(lint_crates: &[LintCrateInfo], passes: Vec<Box<dyn LintPass>>) -> Result<Self> {
+        let external_lint_crates = LintCrateRegistry::new(lint_crates, passes)?;
         Ok(Self {
             inner: RefCell::new(AdapterInner { external_lint_crates }),
+            sink: None,
+            file_filter: FileFilter::default(),
         })
     }
 
+    /// Sets the [`DiagnosticSink`] of this adapter. All emitted diagnostics
+    /// are then passed to the sink, instead of the driver.
+    #[must_use]
+    pub fn with_sink(mut self, sink: impl DiagnosticSink + 'static) -> Self {
+        self.sink = Some(Box::new(sink));
+        self
+    }
+
+    /// Sets the [`FileFilter`] of this adapter. Diagnostics for spans in
+    /// excluded files should not be emitted by the driver.
+    #[must_use]
+    pub fn with_file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// The [`FileFilter`] of this adapter. See [`Adapter::with_file_filter`].
+    pub fn file_filter(&self) -> &FileFilter {
+        &self.file_filter
+    }
+
+    /// The custom [`DiagnosticSink`] of this adapter, if one was provided.
+    /// Drivers should pass it to [`MarkerContextWrapper::new`](context::MarkerContextWrapper::new).
+    pub fn diagnostic_sink(&self) -> Option<&dyn DiagnosticSink> {
+        self.sink.as_deref()
+    }
+
+    /// All lints that should be registered by the driver. This includes the
+    /// lints of the loaded lint crates and the lints emitted by the adapter.
     pub fn marker_lints(&self) -> Vec<&'static Lint> {
         self.lint_pass_infos()
             .iter()
             .flat_map(marker_api::LintPassInfo::lints)
             .copied()
+            .chain(std::iter::once(loader::LINT_CRATE_PANIC))
             .collect()
     }
 
+    /// Searches the lint with the given name in [`Adapter::marker_lints`]. The
+    /// name can either be the full name, like `marker::lint_crate::lint_name`,
+    /// or only the lint name. Dashes and upper case letters are normalized like
+    /// in rustc's command line arguments. The first matching lint is returned,
+    /// if several lint crates declare a lint with the same name.
+    pub fn lint_by_name(&self, name: &str) -> Option<&'static Lint> {
+        let name = name.to_lowercase().replace('-', "_");
+        self.marker_lints().into_iter().find(|lint| {
+            let lint_name = lint.name.to_lowercase();
+            lint_name == name || lint_name.rsplit("::").next() == Some(name.as_str())
+        })
+    }
+
+    /// Renders [`Adapter::marker_lints`] as a JSON array, for tools and editor
+    /// integrations. Each lint is an object with the lowercase `name`, the default
+    /// `level`, the `category`, which can be `null`, and the `description`, which
+    /// is the documentation of the lint.
+    pub fn lints_json(&self) -> String {
+        let lints: Vec<_> = self
+            .marker_lints()
+            .into_iter()
+            .map(|lint| {
+                // Doc comments are stored with the space after the `///`
+                let description: Vec<_> = lint
+                    .explanation
+                    .lines()
+                    .map(|line| line.strip_prefix(' ').unwrap_or(line))
+                    .collect();
+                serde_json::json!({
+                    "name": lint.name.to_lowercase(),
+                    "level": format!("{:?}", lint.default_level).to_lowercase(),
+                    "category": lint.category.map(|category| format!("{category:?}").to_lowercase()),
+                    "description": description.join("\n"),
+                })
+            })
+            .collect();
+        format!("{:#}", serde_json::Value::Array(lints))
+    }
+
     #[must_use]
     fn lint_pass_infos(&self) -> Vec<LintPassInfo> {
         self.inner.borrow().external_lint_crates.collect_lint_pass_info()
@@ -75,6 +182,9 @@ impl Adapter {
 
         inner.external_lint_crates.check_crate(cx, krate);
         visitor::traverse_item::<()>(cx, inner, ItemKind::Mod(krate.root_mod()));
+        inner.external_lint_crates.check_crate_post(cx, krate);
+
+        inner.external_lint_crates.print_timings();
     }
 }
 
@@ -116,4 +226,235 @@ impl Visitor<()> for AdapterInner {
         self.external_lint_crates.check_expr(cx, expr);
         ControlFlow::Continue(())
     }
+
+    fn visit_pat<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, pat: PatKind<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_pat(cx, pat);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_ty<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, ty: TyKind<'ast>) -> ControlFlow<()> {
+        self.external_lint_crates.check_ty(cx, ty);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_generic_param<'ast>(
+        &mut self,
+        cx: &'ast MarkerContext<'ast>,
+        param: &'ast GenericParamKind<'ast>,
+        generics: &'ast GenericParams<'ast>,
+    ) -> ControlFlow<()> {
+        self.external_lint_crates.check_generic_param(cx, param, generics);
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use marker_api::{
+        ast::{CommonItemData, ModItem, Visibility},
+        common::{CrateId, ExprId, Level, NodeId, SpanId, SymbolId},
+        prelude::*,
+        sem,
+        span::Ident,
+    };
+
+    use super::*;
+    use crate::context::{
+        tests::{TestDriver, VecSink},
+        AstMapDriver, AstMapWrapper, MarkerContextWrapper,
+    };
+
+    marker_api::declare_lint! {
+        /// A lint used to test static lint passes.
+        STATIC_LINT,
+        Warn,
+    }
+
+    /// A lint pass, which records the names of the called `check_*` functions.
+    struct RecordingPass {
+        calls: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl LintPass for RecordingPass {
+        fn info(&self) -> LintPassInfo {
+            marker_api::LintPassInfoBuilder::new(Box::new([STATIC_LINT])).build()
+        }
+
+        fn setup(&mut self, _config: marker_api::ConfigValue<'_>) {
+            self.calls.borrow_mut().push("setup");
+        }
+
+        fn check_crate<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _krate: &'ast Crate<'ast>) {
+            self.calls.borrow_mut().push("check_crate");
+        }
+
+        fn check_item<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _item: ItemKind<'ast>) {
+            self.calls.borrow_mut().push("check_item");
+        }
+
+        fn check_crate_post<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _krate: &'ast Crate<'ast>) {
+            self.calls.borrow_mut().push("check_crate_post");
+        }
+    }
+
+    /// A lint pass, which panics while checking the crate.
+    struct PanickingPass;
+
+    impl LintPass for PanickingPass {
+        fn info(&self) -> LintPassInfo {
+            marker_api::LintPassInfoBuilder::new(Box::new([STATIC_LINT])).build()
+        }
+
+        fn check_crate<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _krate: &'ast Crate<'ast>) {
+            panic!("checking the crate failed");
+        }
+    }
+
+    /// An AST map for the empty crate. Only lint levels can be requested, which
+    /// are always the default level of the lint.
+    struct TestAstMap;
+
+    impl<'ast> AstMapDriver<'ast> for TestAstMap {
+        fn item(&'ast self, _id: ItemId) -> Option<ItemKind<'ast>> {
+            unreachable!()
+        }
+        fn variant(&'ast self, _id: VariantId) -> Option<&'ast EnumVariant<'ast>> {
+            unreachable!()
+        }
+        fn field(&'ast self, _id: FieldId) -> Option<&'ast ItemField<'ast>> {
+            unreachable!()
+        }
+        fn body(&'ast self, _id: BodyId) -> &'ast Body<'ast> {
+            unreachable!()
+        }
+        fn stmt(&'ast self, _id: StmtId) -> StmtKind<'ast> {
+            unreachable!()
+        }
+        fn expr(&'ast self, _id: ExprId) -> ExprKind<'ast> {
+            unreachable!()
+        }
+        fn parent_expr(&'ast self, _id: ExprId) -> Option<ExprKind<'ast>> {
+            unreachable!()
+        }
+        fn parent_item(&'ast self, _id: ExprId) -> Option<ItemId> {
+            unreachable!()
+        }
+        fn lint_level_at(&'ast self, lint: &'static Lint, _node: NodeId) -> Level {
+            lint.default_level
+        }
+    }
+
+    fn empty_crate() -> Crate<'static> {
+        let vis = Visibility::builder()
+            .sem(sem::Visibility::builder().kind(sem::VisibilityKind::Public).build())
+            .build();
+        let data = CommonItemData::builder()
+            .id(ItemId::new(0))
+            .span(SpanId::new(0))
+            .vis(vis)
+            .ident(Ident::new(SymbolId::new(0), SpanId::new(0)))
+            .attrs(&[][..])
+            .build();
+        Crate::builder()
+            .id(CrateId::new(0))
+            .root_mod(
+                ModItem::builder()
+                    .data(data)
+                    .inner_span(SpanId::new(0))
+                    .items(&[][..])
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_static_lint_passes() {
+        let calls = Rc::new(RefCell::new(vec![]));
+        let pass = RecordingPass { calls: calls.clone() };
+        let adapter = Adapter::with_static_passes(&[], vec![Box::new(pass)]).unwrap();
+        assert_eq!(adapter.marker_lints(), vec![STATIC_LINT, loader::LINT_CRATE_PANIC]);
+        assert_eq!(adapter.lint_by_name("static_lint"), Some(STATIC_LINT));
+        assert_eq!(adapter.lint_by_name("static-lint"), Some(STATIC_LINT));
+        assert_eq!(
+            adapter.lint_by_name("marker::marker_adapter::STATIC_LINT"),
+            Some(STATIC_LINT)
+        );
+        assert_eq!(adapter.lint_by_name("marker::other_crate::static_lint"), None);
+        assert_eq!(adapter.lint_by_name("missing_lint"), None);
+
+        let lints: serde_json::Value = serde_json::from_str(&adapter.lints_json()).unwrap();
+        assert_eq!(
+            lints[0],
+            serde_json::json!({
+                "name": "marker::marker_adapter::static_lint",
+                "level": "warn",
+                "category": null,
+                "description": "A lint used to test static lint passes.",
+            })
+        );
+        assert_eq!(lints.as_array().map(Vec::len), Some(2));
+
+        let driver = TestDriver;
+        let map_driver = TestAstMap;
+        let context = MarkerContextWrapper::new(&driver, None);
+        let map = AstMapWrapper::new(&map_driver);
+        let cx = MarkerContext::builder()
+            .callbacks(context.create_callbacks())
+            .ast(map.create_callbacks())
+            .build();
+        adapter.process_krate(&cx, &empty_crate());
+
+        assert_eq!(
+            *calls.borrow(),
+            vec!["setup", "check_crate", "check_item", "check_crate_post"]
+        );
+    }
+
+    #[test]
+    fn test_static_lint_pass_conflicts() {
+        let calls = Rc::new(RefCell::new(vec![]));
+
+        // Both passes define the same lint, none of them is set up
+        let passes: Vec<Box<dyn LintPass>> = vec![
+            Box::new(RecordingPass { calls: calls.clone() }),
+            Box::new(RecordingPass { calls: calls.clone() }),
+        ];
+        assert!(Adapter::with_static_passes(&[], passes).is_err());
+        assert!(calls.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_static_lint_passes_with_sink() {
+        let adapter = Adapter::with_static_passes(&[], vec![Box::new(PanickingPass)]).unwrap();
+        assert!(adapter.diagnostic_sink().is_none());
+        let adapter = adapter.with_sink(VecSink::default());
+        assert!(adapter.diagnostic_sink().is_some());
+    }
+
+    #[test]
+    fn test_static_lint_pass_panic() {
+        let adapter = Adapter::with_static_passes(&[], vec![Box::new(PanickingPass)]).unwrap();
+
+        let driver = TestDriver;
+        let map_driver = TestAstMap;
+        let sink = VecSink::default();
+        let context = MarkerContextWrapper::new(&driver, Some(&sink));
+        let map = AstMapWrapper::new(&map_driver);
+        let cx = MarkerContext::builder()
+            .callbacks(context.create_callbacks())
+            .ast(map.create_callbacks())
+            .build();
+        adapter.process_krate(&cx, &empty_crate());
+
+        assert_eq!(
+            *sink.diags.borrow(),
+            vec![(
+                loader::LINT_CRATE_PANIC.name,
+                Level::Deny,
+                "the static lint pass `marker_adapter` panicked while checking this node".to_string()
+            )]
+        );
+    }
 }
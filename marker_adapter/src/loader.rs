@@ -1,11 +1,20 @@
 use crate::error::prelude::*;
+use crate::timings::Timings;
 use camino::Utf8PathBuf;
 use itertools::Itertools;
 use libloading::Library;
-use marker_api::{LintCrateBindings, MarkerContext};
-use marker_api::{LintPass, LintPassInfo, MARKER_API_VERSION};
-
-use super::LINT_CRATES_ENV;
+use marker_api::{
+    ast::{ExprKind, ItemKind},
+    catch_lint_pass_panic,
+    common::{ItemId, Level},
+    diagnostic::EmissionNode,
+    span::{Span, SpanSource},
+    Lint, LintCrateBindings, MarkerContext,
+};
+use marker_api::{ConfigValue, LintPass, LintPassInfo, MARKER_API_VERSION};
+use std::collections::BTreeMap;
+
+use super::{LINT_CRATES_CONFIG_ENV, LINT_CRATES_ENV};
 
 /// A struct describing a lint crate that can be loaded.
 #[derive(Debug, Clone)]
@@ -14,11 +23,15 @@ pub struct LintCrateInfo {
     pub name: String,
     /// The absolute path of the compiled dynamic library, which can be loaded as a lint crate.
     pub path: Utf8PathBuf,
+    /// The configuration of this lint crate, serialized as JSON, if the user
+    /// provided one.
+    pub config: Option<String>,
 }
 
 impl LintCrateInfo {
     /// This function tries to load the list of [`LintCrateInfo`]s from the
-    /// [`LINT_CRATES_ENV`] environment value.
+    /// [`LINT_CRATES_ENV`] environment value. The configurations of the lint crates
+    /// are read from the [`LINT_CRATES_CONFIG_ENV`] environment value.
     ///
     /// # Errors
     ///
@@ -30,66 +43,161 @@ impl LintCrateInfo {
             return Ok(None);
         };
 
-        let mut lint_crates = vec![];
-        for item in env_str.split(';') {
-            let (name, path) = item.split_once(':').context(|| {
-                format!(
-                    "The content of the `{LINT_CRATES_ENV}` environment variable is malformed. \
-                    Dumped its content on the next line:\n---\n{env_str}\n---",
-                )
-            })?;
+        let mut configs = match std::env::var(LINT_CRATES_CONFIG_ENV) {
+            Ok(config_str) => parse_configs(&config_str)?,
+            Err(_) => BTreeMap::new(),
+        };
 
-            lint_crates.push(LintCrateInfo {
-                name: name.to_string(),
-                path: path.into(),
-            });
-        }
+        let lint_crates = parse_lint_crates(&env_str)?
+            .into_iter()
+            .map(|LintCrateEntry { name, path }| LintCrateInfo {
+                config: configs.remove(&name),
+                name,
+                path,
+            })
+            .collect();
         Ok(Some(lint_crates))
     }
 }
 
+/// An entry of the [`LINT_CRATES_ENV`] environment value.
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LintCrateEntry {
+    name: String,
+    path: Utf8PathBuf,
+}
+
+/// This function parses the content of the [`LINT_CRATES_ENV`] environment value.
+/// It's expected to be a JSON array of objects, with the `name` and `path` of each
+/// lint crate. JSON is used, since paths can contain any character, like `;` or
+/// `:`, which would require escaping in a separated list.
+fn parse_lint_crates(env_str: &str) -> Result<Vec<LintCrateEntry>> {
+    serde_json::from_str(env_str).context(|| {
+        format!(
+            "The content of the `{LINT_CRATES_ENV}` environment variable is malformed. \
+            Dumped its content on the next line:\n---\n{env_str}\n---",
+        )
+    })
+}
+
+/// This function parses the content of the [`LINT_CRATES_CONFIG_ENV`] environment
+/// value. It's expected to be a JSON object, mapping the names of lint crates to
+/// their configuration. The configurations are returned as serialized JSON.
+fn parse_configs(config_str: &str) -> Result<BTreeMap<String, String>> {
+    let configs: BTreeMap<String, serde_json::Value> = serde_json::from_str(config_str).context(|| {
+        format!(
+            "The content of the `{LINT_CRATES_CONFIG_ENV}` environment variable is malformed. \
+            Dumped its content on the next line:\n---\n{config_str}\n---",
+        )
+    })?;
+
+    Ok(configs
+        .into_iter()
+        .map(|(name, config)| (name, config.to_string()))
+        .collect())
+}
+
 /// This struct loads external lint crates into memory and provides a safe API
 /// to call the respective methods on all of them.
 #[derive(Debug, Default)]
 pub struct LintCrateRegistry {
     passes: Vec<LoadedLintCrate>,
+    /// Lint passes, which are linked statically into the driver. They are
+    /// called after the dynamically loaded lint crates.
+    static_passes: Vec<StaticLintPass>,
+    /// The root module of the checked crate. It's used to report panics for
+    /// nodes without a node ID.
+    crate_root: Option<ItemId>,
+    timings: Timings,
 }
 
 impl LintCrateRegistry {
-    pub fn new(lint_crates: &[LintCrateInfo]) -> Result<Self> {
-        let mut new_self = Self::default();
+    /// Loads the given lint crates. The lint passes, which are linked statically,
+    /// are called after the lint crates, in the given order.
+    pub fn new(lint_crates: &[LintCrateInfo], static_passes: Vec<Box<dyn LintPass>>) -> Result<Self> {
+        let mut new_self = Self {
+            static_passes: static_passes
+                .into_iter()
+                .enumerate()
+                .map(|(index, pass)| StaticLintPass::new(index, pass))
+                .collect(),
+            timings: Timings::from_env(),
+            ..Self::default()
+        };
 
         for krate in lint_crates {
             new_self.passes.push(LoadedLintCrate::try_from_info(krate.clone())?);
         }
 
-        let lint_passes = new_self.collect_lint_pass_info();
-
-        let errors = lint_passes
-            .iter()
-            .flat_map(LintPassInfo::lints)
-            .into_group_map_by(|lint| lint.name.to_ascii_lowercase())
-            .into_iter()
-            .filter(|(_, lints)| lints.len() > 1)
-            .map(|(lint_name, lints)| {
-                let defs = lints.iter().map(|lint| format!("- {}", lint.fqn)).format("\n");
+        new_self.validate_and_setup()?;
+        Ok(new_self)
+    }
 
-                Error::root(format!("The lint `{lint_name}` is defined multiple times:\n{defs}",))
-            });
+    fn validate_and_setup(&mut self) -> Result {
+        let lint_passes = self.collect_lint_pass_info();
+        check_lint_conflicts(&lint_passes)?;
+        self.setup_passes(&lint_passes)
+    }
 
-        Error::try_many(errors, "Found several lint name conflicts")?;
+    /// This function validates the configuration of every lint crate and passes
+    /// it to [`LintPass::setup`]. `lint_passes` has to contain the infos of all
+    /// passes in the same order. Static lint passes don't have a configuration.
+    fn setup_passes(&mut self, lint_passes: &[LintPassInfo]) -> Result {
+        let (loaded_infos, static_infos) = lint_passes.split_at(self.passes.len());
+        let errors = self.passes.iter().zip(loaded_infos).filter_map(|(pass, info)| {
+            let config = ConfigValue::new(pass.info.config.as_deref());
+            info.validate_config(config).err().map(|msg| {
+                Error::root(format!(
+                    "The configuration of the lint crate `{}` is malformed: {msg}",
+                    pass.info.name
+                ))
+            })
+        });
+
+        Error::try_many(errors, "Found malformed lint crate configurations")?;
+        check_static_configs(static_infos)?;
+
+        for pass in &self.passes {
+            if !(pass.bindings.setup)(ConfigValue::new(pass.info.config.as_deref())) {
+                return Err(Error::root(format!(
+                    "The lint crate `{}` panicked while setting up its lint pass",
+                    pass.info.name
+                )));
+            }
+        }
+        for pass in &mut self.static_passes {
+            pass.pass.setup(ConfigValue::new(None));
+        }
 
-        Ok(new_self)
+        Ok(())
     }
 
     pub(super) fn set_ast_context<'ast>(&self, cx: &'ast MarkerContext<'ast>) {
         for lint_pass in &self.passes {
             (lint_pass.bindings.set_ast_context)(cx);
         }
+
+        // Static lint passes and panic reports use the `marker_api` crate
+        // linked into the adapter
+        marker_api::context::set_ast_cx(cx);
+    }
+
+    /// Prints the recorded timings of the lint passes to stderr, if the
+    /// recording is enabled. See [`Timings`].
+    pub(crate) fn print_timings(&self) {
+        if self.timings.is_enabled() {
+            let crate_name = std::env::var("CARGO_CRATE_NAME").unwrap_or_else(|_| "<unknown>".to_string());
+            eprint!("{}", self.timings.render(&crate_name));
+        }
     }
 
     pub(crate) fn collect_lint_pass_info(&self) -> Vec<LintPassInfo> {
-        self.passes.iter().map(|pass| (pass.bindings.info)()).collect()
+        self.passes
+            .iter()
+            .map(|pass| pass.pass_info.clone())
+            .chain(self.static_passes.iter().map(|pass| pass.pass.info()))
+            .collect()
     }
 }
 
@@ -99,21 +207,82 @@ impl LintPass for LintCrateRegistry {
         panic!("`registered_lints` should not be called on `LintCrateRegistry`");
     }
 
+    fn setup(&mut self, _config: ConfigValue<'_>) {
+        panic!("`setup` should not be called on `LintCrateRegistry`, lint crates are set up when they're loaded");
+    }
+
     fn check_crate<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, krate: &'ast marker_api::ast::Crate<'ast>) {
+        let node = ItemKind::Mod(krate.root_mod());
+        self.crate_root = Some(node.id());
+        for lp in &self.passes {
+            if !self
+                .timings
+                .measure(lp.name(), "crate", || (lp.bindings.check_crate)(cx, krate))
+            {
+                report_panic(cx, &lp.description(), "crate", node);
+            }
+        }
+        for sp in &mut self.static_passes {
+            if !self.timings.measure(&sp.name, "crate", || {
+                catch_lint_pass_panic(|| sp.pass.check_crate(cx, krate))
+            }) {
+                report_panic(cx, &sp.description(), "crate", node);
+            }
+        }
+    }
+
+    fn check_crate_post<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, krate: &'ast marker_api::ast::Crate<'ast>) {
+        let node = ItemKind::Mod(krate.root_mod());
         for lp in &self.passes {
-            (lp.bindings.check_crate)(cx, krate);
+            if !self
+                .timings
+                .measure(lp.name(), "crate_post", || (lp.bindings.check_crate_post)(cx, krate))
+            {
+                report_panic(cx, &lp.description(), "crate", node);
+            }
+        }
+        for sp in &mut self.static_passes {
+            if !self.timings.measure(&sp.name, "crate_post", || {
+                catch_lint_pass_panic(|| sp.pass.check_crate_post(cx, krate))
+            }) {
+                report_panic(cx, &sp.description(), "crate", node);
+            }
         }
     }
 
-    fn check_item<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, item: marker_api::ast::ItemKind<'ast>) {
+    fn check_item<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
         for lp in &self.passes {
-            (lp.bindings.check_item)(cx, item);
+            if !self
+                .timings
+                .measure(lp.name(), "item", || (lp.bindings.check_item)(cx, item))
+            {
+                report_panic(cx, &lp.description(), "item", item);
+            }
+        }
+        for sp in &mut self.static_passes {
+            if !self.timings.measure(&sp.name, "item", || {
+                catch_lint_pass_panic(|| sp.pass.check_item(cx, item))
+            }) {
+                report_panic(cx, &sp.description(), "item", item);
+            }
         }
     }
 
     fn check_field<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, field: &'ast marker_api::ast::ItemField<'ast>) {
         for lp in &self.passes {
-            (lp.bindings.check_field)(cx, field);
+            if !self
+                .timings
+                .measure(lp.name(), "field", || (lp.bindings.check_field)(cx, field))
+            {
+                report_panic(cx, &lp.description(), "field", field);
+            }
+        }
+        for sp in &mut self.static_passes {
+            if !self.timings.measure(&sp.name, "field", || {
+                catch_lint_pass_panic(|| sp.pass.check_field(cx, field))
+            }) {
+                report_panic(cx, &sp.description(), "field", field);
+            }
         }
     }
 
@@ -123,33 +292,218 @@ impl LintPass for LintCrateRegistry {
         variant: &'ast marker_api::ast::EnumVariant<'ast>,
     ) {
         for lp in &self.passes {
-            (lp.bindings.check_variant)(cx, variant);
+            if !self
+                .timings
+                .measure(lp.name(), "variant", || (lp.bindings.check_variant)(cx, variant))
+            {
+                report_panic(cx, &lp.description(), "variant", variant);
+            }
+        }
+        for sp in &mut self.static_passes {
+            if !self.timings.measure(&sp.name, "variant", || {
+                catch_lint_pass_panic(|| sp.pass.check_variant(cx, variant))
+            }) {
+                report_panic(cx, &sp.description(), "variant", variant);
+            }
         }
     }
 
     fn check_body<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, body: &'ast marker_api::ast::Body<'ast>) {
+        // Bodies don't have a node ID, the panic is reported on the body expression instead
+        let node = body.expr();
         for lp in &self.passes {
-            (lp.bindings.check_body)(cx, body);
+            if !self
+                .timings
+                .measure(lp.name(), "body", || (lp.bindings.check_body)(cx, body))
+            {
+                report_panic(cx, &lp.description(), "body", node);
+            }
+        }
+        for sp in &mut self.static_passes {
+            if !self.timings.measure(&sp.name, "body", || {
+                catch_lint_pass_panic(|| sp.pass.check_body(cx, body))
+            }) {
+                report_panic(cx, &sp.description(), "body", node);
+            }
         }
     }
 
     fn check_stmt<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, stmt: marker_api::ast::StmtKind<'ast>) {
         for lp in &self.passes {
-            (lp.bindings.check_stmt)(cx, stmt);
+            if !self
+                .timings
+                .measure(lp.name(), "stmt", || (lp.bindings.check_stmt)(cx, stmt))
+            {
+                report_panic(cx, &lp.description(), "statement", stmt);
+            }
+        }
+        for sp in &mut self.static_passes {
+            if !self.timings.measure(&sp.name, "stmt", || {
+                catch_lint_pass_panic(|| sp.pass.check_stmt(cx, stmt))
+            }) {
+                report_panic(cx, &sp.description(), "statement", stmt);
+            }
         }
     }
 
     fn check_expr<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, expr: marker_api::ast::ExprKind<'ast>) {
         for lp in &self.passes {
-            (lp.bindings.check_expr)(cx, expr);
+            if all_expr_lints_allowed(cx, lp.expr_lints, expr) {
+                continue;
+            }
+
+            if !self
+                .timings
+                .measure(lp.name(), "expr", || (lp.bindings.check_expr)(cx, expr))
+            {
+                report_panic(cx, &lp.description(), "expression", expr);
+            }
+        }
+        for sp in &mut self.static_passes {
+            if all_expr_lints_allowed(cx, sp.expr_lints, expr) {
+                continue;
+            }
+
+            if !self.timings.measure(&sp.name, "expr", || {
+                catch_lint_pass_panic(|| sp.pass.check_expr(cx, expr))
+            }) {
+                report_panic(cx, &sp.description(), "expression", expr);
+            }
+        }
+    }
+
+    fn check_pat<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, pat: marker_api::ast::PatKind<'ast>) {
+        // Patterns don't have a node ID. The panic is reported on the crate root,
+        // with the span of the pattern.
+        let crate_root = self.crate_root;
+        let report = |name: &str| {
+            if let Some(root) = crate_root.and_then(|id| cx.ast().item(id)) {
+                report_panic_at(cx, name, "pattern", root, pat.span());
+            }
+        };
+        for lp in &self.passes {
+            if !self
+                .timings
+                .measure(lp.name(), "pat", || (lp.bindings.check_pat)(cx, pat))
+            {
+                report(&lp.description());
+            }
+        }
+        for sp in &mut self.static_passes {
+            if !self
+                .timings
+                .measure(&sp.name, "pat", || catch_lint_pass_panic(|| sp.pass.check_pat(cx, pat)))
+            {
+                report(&sp.description());
+            }
+        }
+    }
+
+    fn check_ty<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, ty: marker_api::ast::TyKind<'ast>) {
+        for lp in &self.passes {
+            if !self.timings.measure(lp.name(), "ty", || (lp.bindings.check_ty)(cx, ty)) {
+                report_panic(cx, &lp.description(), "type", ty);
+            }
+        }
+        for sp in &mut self.static_passes {
+            if !self
+                .timings
+                .measure(&sp.name, "ty", || catch_lint_pass_panic(|| sp.pass.check_ty(cx, ty)))
+            {
+                report_panic(cx, &sp.description(), "type", ty);
+            }
+        }
+    }
+
+    fn check_generic_param<'ast>(
+        &mut self,
+        cx: &'ast MarkerContext<'ast>,
+        param: &'ast marker_api::ast::GenericParamKind<'ast>,
+        generics: &'ast marker_api::ast::GenericParams<'ast>,
+    ) {
+        for lp in &self.passes {
+            if !self.timings.measure(lp.name(), "generic_param", || {
+                (lp.bindings.check_generic_param)(cx, param, generics)
+            }) {
+                report_panic(cx, &lp.description(), "generic parameter", param);
+            }
+        }
+        for sp in &mut self.static_passes {
+            if !self.timings.measure(&sp.name, "generic_param", || {
+                catch_lint_pass_panic(|| sp.pass.check_generic_param(cx, param, generics))
+            }) {
+                report_panic(cx, &sp.description(), "generic parameter", param);
+            }
         }
     }
 }
 
+marker_api::declare_lint! {
+    /// ### What it does
+    /// This lint is emitted, if a lint pass panicked while checking a node. The
+    /// lint pass is skipped for this node, but Marker continues with the other
+    /// lint passes and nodes.
+    ///
+    /// ### Why is this bad?
+    /// Panics are bugs in the lint crate. The node might not have been checked
+    /// completely. Please report these bugs to the authors of the lint crate.
+    LINT_CRATE_PANIC,
+    Deny,
+}
+
+/// Emits a [`LINT_CRATE_PANIC`] diagnostic on the given node. The `pass` describes
+/// the panicking lint pass, like ``the lint crate `name` ``. The `kind` is the
+/// displayed name of the checked node kind, like `item` or `expression`.
+fn report_panic<'ast>(cx: &'ast MarkerContext<'ast>, pass: &str, kind: &'static str, node: impl EmissionNode<'ast>) {
+    let span = node.span().clone();
+    report_panic_at(cx, pass, kind, node, &span);
+}
+
+/// Like [`report_panic`], but the diagnostic is emitted at the given span. This
+/// is used for nodes without a node ID, which are reported on a parent node.
+fn report_panic_at<'ast>(
+    cx: &'ast MarkerContext<'ast>,
+    pass: &str,
+    kind: &'static str,
+    node: impl EmissionNode<'ast>,
+    span: &Span<'ast>,
+) {
+    cx.emit_lint(
+        LINT_CRATE_PANIC,
+        node,
+        format!("{pass} panicked while checking this node"),
+    )
+    .span(span)
+    .decorate(|diag| {
+        let location = span_location(span).unwrap_or_else(|| "an unknown location".to_string());
+        diag.note(format!("while checking the {kind} at {location}"));
+        diag.note("the lint pass has been skipped for this node, other lint passes are unaffected");
+        diag.help("this is a bug in the lint crate, please report it to its authors");
+    });
+}
+
+/// Returns the start location of the span as `file:line:column`. Spans from
+/// macro expansions are mapped to the outermost macro invocation.
+fn span_location(span: &Span<'_>) -> Option<String> {
+    let span = span.outermost_call_site();
+    // Builtin spans don't belong to a file
+    let SpanSource::File(file) = span.source() else {
+        return None;
+    };
+    let pos = file.try_to_file_pos(span.start())?;
+    Some(format!("{}:{}:{}", file.file(), pos.line(), pos.column()))
+}
+
 struct LoadedLintCrate {
     _lib: &'static Library,
     info: LintCrateInfo,
     bindings: LintCrateBindings,
+    /// The [`LintPassInfo`] provided by the lint pass. It's requested once, when
+    /// the lint crate is loaded.
+    pass_info: LintPassInfo,
+    /// The lints emitted by `check_expr`, declared with
+    /// [`LintPassInfoBuilder::expr_lint`](marker_api::LintPassInfoBuilder::expr_lint).
+    expr_lints: &'static [&'static Lint],
 }
 
 #[allow(clippy::missing_fields_in_debug)]
@@ -160,6 +514,15 @@ impl std::fmt::Debug for LoadedLintCrate {
 }
 
 impl LoadedLintCrate {
+    fn name(&self) -> &str {
+        &self.info.name
+    }
+
+    /// The description of this lint crate, used in diagnostics.
+    fn description(&self) -> String {
+        format!("the lint crate `{}`", self.info.name)
+    }
+
     fn try_from_info(info: LintCrateInfo) -> Result<Self> {
         let lib = unsafe { Library::new(&info.path) };
 
@@ -190,12 +553,109 @@ impl LoadedLintCrate {
             unsafe { get_symbol::<extern "C" fn() -> LintCrateBindings>(lib, &info, b"marker_lint_crate_bindings\0")? };
 
         let bindings = get_lint_crate_bindings();
+        let Some(pass_info) = (bindings.info)().copy() else {
+            return Err(Error::root(format!(
+                "The lint crate `{}` panicked while providing its `LintPassInfo`",
+                info.name
+            )));
+        };
+        let expr_lints = pass_info.expr_lints();
 
         Ok(Self {
             _lib: lib,
             info,
             bindings,
+            pass_info,
+            expr_lints,
+        })
+    }
+}
+
+/// Returns `true`, if the lint pass declared the lints emitted by `check_expr`
+/// and all of them are allowed at the given expression. The call can be skipped
+/// in that case.
+fn all_expr_lints_allowed(cx: &MarkerContext<'_>, expr_lints: &[&'static Lint], expr: ExprKind<'_>) -> bool {
+    !expr_lints.is_empty()
+        && expr_lints
+            .iter()
+            .all(|lint| cx.ast().lint_level_at(lint, expr) == Level::Allow)
+}
+
+/// Checks that every lint name is only defined once by the given passes.
+fn check_lint_conflicts(lint_passes: &[LintPassInfo]) -> Result {
+    let errors = lint_passes
+        .iter()
+        .flat_map(LintPassInfo::lints)
+        .into_group_map_by(|lint| lint.name.to_ascii_lowercase())
+        .into_iter()
+        .filter(|(_, lints)| lints.len() > 1)
+        .map(|(lint_name, lints)| {
+            let defs = lints.iter().map(|lint| format!("- {}", lint.fqn)).format("\n");
+
+            Error::root(format!("The lint `{lint_name}` is defined multiple times:\n{defs}",))
+        });
+
+    Error::try_many(errors, "Found several lint name conflicts")
+}
+
+/// Static lint passes don't have a configuration. This checks that the given
+/// passes accept a missing configuration.
+fn check_static_configs(static_infos: &[LintPassInfo]) -> Result {
+    let errors = static_infos.iter().filter_map(|info| {
+        info.validate_config(ConfigValue::new(None)).err().map(|msg| {
+            Error::root(format!(
+                "The static lint pass with the lints `{}` requires a configuration: {msg}",
+                info.lints().iter().map(|lint| lint.name).format(", ")
+            ))
         })
+    });
+
+    Error::try_many(errors, "Found static lint passes with missing configurations")
+}
+
+/// A lint pass, which is linked statically into the driver.
+struct StaticLintPass {
+    /// The name of the crate declaring the lints of this pass. It's used in
+    /// timings and diagnostics, since static passes aren't loaded from a named
+    /// lint crate.
+    name: String,
+    pass: Box<dyn LintPass>,
+    /// The lints emitted by `check_expr`, declared with
+    /// [`LintPassInfoBuilder::expr_lint`](marker_api::LintPassInfoBuilder::expr_lint).
+    expr_lints: &'static [&'static Lint],
+}
+
+impl StaticLintPass {
+    /// Creates the static pass with the given index. Passes without lints are
+    /// named by their index.
+    fn new(index: usize, pass: Box<dyn LintPass>) -> Self {
+        let info = pass.info();
+        // Lint names have the form `marker::<crate>::<lint>`
+        let name = info
+            .lints()
+            .first()
+            .and_then(|lint| lint.name.split("::").nth(1))
+            .map_or_else(|| format!("#{index}"), ToString::to_string);
+        Self {
+            name,
+            pass,
+            expr_lints: info.expr_lints(),
+        }
+    }
+
+    /// The description of this lint pass, used in diagnostics.
+    fn description(&self) -> String {
+        format!("the static lint pass `{}`", self.name)
+    }
+}
+
+#[allow(clippy::missing_fields_in_debug)]
+impl std::fmt::Debug for StaticLintPass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StaticLintPass")
+            .field("name", &self.name)
+            .field("expr_lints", &self.expr_lints)
+            .finish()
     }
 }
 
@@ -216,3 +676,54 @@ unsafe fn get_symbol<T>(
         )
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lint_crates() {
+        assert!(parse_lint_crates("[]").unwrap().is_empty());
+
+        // The value might be formatted and use escapes
+        let env = r#"[
+            { "path": "C:\\Program Files\\lints.dll", "name": "win_lints" },
+            { "name": "lints", "path": "/home/user/\u00e4/liblints.so" }
+        ]"#;
+        let entries = parse_lint_crates(env).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "win_lints");
+        assert_eq!(entries[0].path, r"C:\Program Files\lints.dll");
+        assert_eq!(entries[1].name, "lints");
+        assert_eq!(entries[1].path, "/home/user/\u{e4}/liblints.so");
+
+        // The old `name:path` format, incomplete entries and unknown fields are rejected
+        assert!(parse_lint_crates("lints:/path/to/liblints.so").is_err());
+        assert!(parse_lint_crates(r#"[{"name": "lints"}]"#).is_err());
+        assert!(parse_lint_crates(r#"[{"name": "lints", "path": "liblints.so", "version": "0.1.0"}]"#).is_err());
+    }
+
+    marker_api::declare_lint! {
+        /// A lint used to test the names of static lint passes.
+        NAMED_LINT,
+        Warn,
+    }
+
+    struct TestPass(Vec<&'static Lint>);
+
+    impl LintPass for TestPass {
+        fn info(&self) -> LintPassInfo {
+            marker_api::LintPassInfoBuilder::new(self.0.clone().into_boxed_slice()).build()
+        }
+    }
+
+    #[test]
+    fn test_static_lint_pass_name() {
+        let named = StaticLintPass::new(0, Box::new(TestPass(vec![NAMED_LINT])));
+        assert_eq!(named.name, "marker_adapter");
+        assert_eq!(named.description(), "the static lint pass `marker_adapter`");
+
+        let unnamed = StaticLintPass::new(1, Box::new(TestPass(vec![])));
+        assert_eq!(unnamed.description(), "the static lint pass `#1`");
+    }
+}
@@ -0,0 +1,108 @@
+use marker_api::Lint;
+
+/// The marker of comments, which suppress lints on the commented line.
+const SUPPRESSION_MARKER: &str = "marker:ignore";
+
+/// Returns `true`, if the given lint is suppressed by a `// marker:ignore` comment.
+/// Drivers should not emit diagnostics of suppressed lints.
+///
+/// The comment has to name the lint, either by its full name, like
+/// `marker::lint_crate::lint_name`, or by the last segment, like `lint_name`.
+/// Multiple lints can be separated by commas. A reason can be added after `--`:
+///
+/// ```text
+/// let x = foo(); // marker:ignore lint_name, other_lint -- the reason
+/// ```
+///
+/// The comment suppresses the lint, if it's at the end of the first or the last
+/// line of the emission span, or if it's the only content of the line above
+/// the emission span.
+#[must_use]
+pub fn is_suppressed_by_comment(lint: &Lint, line_above: Option<&str>, first_line: &str, last_line: &str) -> bool {
+    let line_above = line_above.filter(|line| line.trim_start().starts_with("//"));
+    line_above
+        .into_iter()
+        .chain([first_line, last_line])
+        .any(|line| line_suppresses_lint(line, lint.name))
+}
+
+fn line_suppresses_lint(line: &str, lint_name: &str) -> bool {
+    // A `//` can also be part of a string literal or URL, all of them have to be checked
+    line.match_indices("//").any(|(index, _)| {
+        let comment = line[index + 2..].trim_start();
+        let Some(names) = comment.strip_prefix(SUPPRESSION_MARKER) else {
+            return false;
+        };
+        if !names.starts_with(char::is_whitespace) {
+            return false;
+        }
+
+        let names = names.split_once("--").map_or(names, |(names, _reason)| names);
+        names
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|name| !name.is_empty())
+            .any(|name| matches_lint_name(name, lint_name))
+    })
+}
+
+/// Lint names are compared case insensitive, with dashes normalized to underscores,
+/// like rustc does for lint names in command line arguments.
+fn matches_lint_name(name: &str, lint_name: &str) -> bool {
+    let name = name.replace('-', "_");
+    let short_name = lint_name.rsplit("::").next().unwrap_or(lint_name);
+    name.eq_ignore_ascii_case(lint_name) || name.eq_ignore_ascii_case(short_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    marker_api::declare_lint! {
+        /// A lint to test suppression comments.
+        TEST_LINT,
+        Warn,
+    }
+
+    #[test]
+    fn test_trailing_comment() {
+        let is_suppressed = |line| is_suppressed_by_comment(TEST_LINT, None, line, line);
+        assert!(is_suppressed("let x = 1; // marker:ignore test_lint"));
+        assert!(is_suppressed("let x = 1; //marker:ignore TEST_LINT"));
+        assert!(is_suppressed("let x = 1; // marker:ignore test-lint"));
+        assert!(is_suppressed(
+            "let x = 1; // marker:ignore other_lint, marker::marker_adapter::test_lint"
+        ));
+        assert!(is_suppressed("let x = 1; // marker:ignore test_lint -- the reason"));
+        assert!(is_suppressed(
+            "let x = \"https://example.com\"; // marker:ignore test_lint"
+        ));
+
+        assert!(!is_suppressed("let x = 1;"));
+        assert!(!is_suppressed("let x = 1; // marker:ignore other_lint"));
+        assert!(!is_suppressed("let x = 1; // marker:ignore other_lint -- test_lint"));
+        assert!(!is_suppressed("let x = 1; // marker:ignore-test_lint"));
+        assert!(!is_suppressed(
+            "let x = 1; // marker:ignore marker::other_crate::test_lint"
+        ));
+        assert!(!is_suppressed("let x = 1; // ignore test_lint"));
+    }
+
+    #[test]
+    fn test_line_above() {
+        let is_suppressed = |above| is_suppressed_by_comment(TEST_LINT, Some(above), "let x = 1;", "let x = 1;");
+        assert!(is_suppressed("// marker:ignore test_lint"));
+        assert!(is_suppressed("    // marker:ignore test_lint"));
+
+        // Trailing comments only apply to their own line
+        assert!(!is_suppressed("let y = 2; // marker:ignore test_lint"));
+        assert!(!is_suppressed("// marker:ignore other_lint"));
+    }
+
+    #[test]
+    fn test_multiline_span() {
+        let first_line = "let x = foo(";
+        let last_line = "); // marker:ignore test_lint";
+        assert!(is_suppressed_by_comment(TEST_LINT, None, first_line, last_line));
+        assert!(!is_suppressed_by_comment(TEST_LINT, None, first_line, ");"));
+    }
+}
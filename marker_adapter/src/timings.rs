@@ -0,0 +1,136 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use super::TIMINGS_ENV;
+
+/// This struct records the time spent in the `check_*` functions of the lint
+/// passes. The recording is opt-in via the [`TIMINGS_ENV`] environment value.
+/// The functions are called directly, if the recording is disabled.
+#[derive(Debug, Default)]
+pub(crate) struct Timings {
+    enabled: bool,
+    /// The recorded timings, grouped by lint pass and node kind.
+    passes: BTreeMap<String, BTreeMap<&'static str, Timing>>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Timing {
+    calls: u64,
+    time: Duration,
+}
+
+impl Timings {
+    /// Creates a new instance, which is enabled if [`TIMINGS_ENV`] is set to `1`.
+    pub(crate) fn from_env() -> Self {
+        Self::new(std::env::var(TIMINGS_ENV).is_ok_and(|value| value == "1"))
+    }
+
+    pub(crate) fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            passes: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Calls the given function and records the elapsed time for the given lint
+    /// pass and node kind, if the recording is enabled.
+    pub(crate) fn measure<R>(&mut self, pass: &str, node: &'static str, f: impl FnOnce() -> R) -> R {
+        if !self.enabled {
+            return f();
+        }
+
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+
+        let timing = self
+            .passes
+            .entry(pass.to_string())
+            .or_default()
+            .entry(node)
+            .or_default();
+        timing.calls += 1;
+        timing.time += elapsed;
+
+        result
+    }
+
+    /// Renders the recorded timings as a table, grouped by lint pass and node kind.
+    pub(crate) fn render(&self, crate_name: &str) -> String {
+        let mut out = format!("Marker timings for `{crate_name}`:\n");
+        let pass_width = self
+            .passes
+            .keys()
+            .map(String::len)
+            .max()
+            .unwrap_or(0)
+            .max("lint pass".len());
+
+        let _ = writeln!(
+            out,
+            "  {:pass_width$}  {:10}  {:>8}  {:>12}",
+            "lint pass", "node", "calls", "time"
+        );
+        for (pass, nodes) in &self.passes {
+            let mut total = Duration::ZERO;
+            for (node, timing) in nodes {
+                total += timing.time;
+                let _ = writeln!(
+                    out,
+                    "  {pass:pass_width$}  {node:10}  {:>8}  {:>12}",
+                    timing.calls,
+                    format!("{:.3?}", timing.time),
+                );
+            }
+            let _ = writeln!(
+                out,
+                "  {pass:pass_width$}  {:10}  {:>8}  {:>12}",
+                "total",
+                "",
+                format!("{total:.3?}"),
+            );
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabled_timings() {
+        let mut timings = Timings::new(false);
+        assert_eq!(timings.measure("lints", "item", || 7), 7);
+        assert!(timings.passes.is_empty());
+    }
+
+    #[test]
+    fn test_enabled_timings() {
+        let mut timings = Timings::new(true);
+        timings.measure("lints", "item", || {});
+        timings.measure("lints", "item", || {});
+        timings.measure("lints", "expr", || {});
+        timings.measure("other_lints", "item", || {});
+
+        assert_eq!(timings.passes["lints"]["item"].calls, 2);
+        assert_eq!(timings.passes["lints"]["expr"].calls, 1);
+        assert_eq!(timings.passes["other_lints"]["item"].calls, 1);
+
+        let table = timings.render("example");
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines[0], "Marker timings for `example`:");
+        assert!(lines[1].trim_start().starts_with("lint pass"));
+        // One line per node kind and one total per lint pass
+        assert_eq!(lines.len(), 2 + 3 + 2);
+        assert!(lines[2].contains("lints") && lines[2].contains("expr"));
+        assert!(lines[4].contains("total"));
+    }
+}
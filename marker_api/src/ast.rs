@@ -1,6 +1,7 @@
 //! A module containing the AST of Marker, which is the main syntactic
 //! representation of the written code.
 
+mod attr;
 mod common;
 mod expr;
 mod generic;
@@ -8,6 +9,7 @@ mod item;
 mod pat;
 mod stmt;
 mod ty;
+pub use attr::*;
 pub use common::*;
 pub use expr::*;
 pub use generic::*;
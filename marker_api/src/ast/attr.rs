@@ -0,0 +1,121 @@
+use crate::{
+    common::SpanId,
+    context::with_cx,
+    ffi::{FfiOption, FfiSlice},
+    span::{Ident, Span},
+};
+
+/// An attribute attached to a node, like an item, field or enum variant.
+///
+/// ```
+/// //  vvvvvvvvvvvv An attribute without arguments
+///     #[must_use]
+/// //  vvvvvvvvvvvvvvvvvvvvvvvvvvvvvvvvvvvvvvv An attribute with list arguments
+///     #[deprecated(since = "1.0", note = "..")]
+///     fn foo() -> u32 {
+///         // ...
+///         # 0
+///     }
+///
+/// //  vvvvvvvvvvvvvvvvvvvvvvvvv An attribute with name-value arguments
+///     #[doc = "Some documentation"]
+/// //  The following doc comment is also an attribute
+///     /// Some more documentation
+///     struct Bar;
+/// ```
+///
+/// Marker currently doesn't parse the arguments of attributes. Instead, they
+/// are provided as a [`Span`], which can be used to retrieve the raw token
+/// snippet. See [`Attribute::args_span`] and [`Attribute::args_snippet`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct Attribute<'ast> {
+    span: SpanId,
+    segments: FfiSlice<'ast, Ident<'ast>>,
+    args_kind: AttrArgsKind,
+    args_span: FfiOption<SpanId>,
+}
+
+impl<'ast> Attribute<'ast> {
+    /// The segments of the attribute path. For `#[inline]` this will be a single
+    /// segment `inline`, for tool attributes like `#[rustfmt::skip]` it would
+    /// contain the segments `rustfmt` and `skip`.
+    ///
+    /// Doc comments are represented with a single `doc` segment, like their
+    /// desugared form `#[doc = ".."]`.
+    pub fn segments(&self) -> &[Ident<'ast>] {
+        self.segments.get()
+    }
+
+    /// Returns `true`, if the path of this attribute consists of the given
+    /// segments. This can be used to check for specific attributes:
+    ///
+    /// ```ignore
+    /// if attr.is_path(&["rustfmt", "skip"]) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn is_path(&self, path: &[&str]) -> bool {
+        let segments = self.segments();
+        segments.len() == path.len() && segments.iter().zip(path).all(|(seg, name)| seg.name() == *name)
+    }
+
+    /// The kind of arguments provided to this attribute.
+    pub fn args_kind(&self) -> AttrArgsKind {
+        self.args_kind
+    }
+
+    /// The [`Span`] of the attribute arguments, if any were provided. For list
+    /// arguments, this includes the delimiters, for name-value arguments, this
+    /// is the span of the value after the `=`. For doc comments, this is the
+    /// span of the entire comment.
+    pub fn args_span(&self) -> Option<&Span<'ast>> {
+        self.args_span.copy().map(|span| with_cx(self, |cx| cx.span(span)))
+    }
+
+    /// The raw token snippet of the attribute arguments. This is a shorthand for
+    /// [`Attribute::args_span`] followed by [`Span::snippet`].
+    pub fn args_snippet(&self) -> Option<&'ast str> {
+        self.args_span().and_then(Span::snippet)
+    }
+
+    /// Returns `true`, if this attribute is a doc comment like `/// ..` or `/** .. */`.
+    /// Doc attributes written as `#[doc = ".."]` will return `false`.
+    pub fn is_doc_comment(&self) -> bool {
+        matches!(self.args_kind, AttrArgsKind::DocComment)
+    }
+}
+
+crate::span::impl_has_span_via_field!(Attribute<'ast>);
+
+#[cfg(feature = "driver-api")]
+impl<'ast> Attribute<'ast> {
+    pub fn new(
+        span: SpanId,
+        segments: &'ast [Ident<'ast>],
+        args_kind: AttrArgsKind,
+        args_span: Option<SpanId>,
+    ) -> Self {
+        Self {
+            span,
+            segments: segments.into(),
+            args_kind,
+            args_span: args_span.into(),
+        }
+    }
+}
+
+/// The kind of arguments provided to an [`Attribute`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AttrArgsKind {
+    /// No arguments, like `#[inline]`.
+    Empty,
+    /// Delimited arguments, like `#[inline(always)]` or `#[derive(Debug)]`.
+    List,
+    /// Name-value arguments, like `#[deprecated = "reason"]`.
+    NameValue,
+    /// A doc comment, like `/// ..` or `//! ..`.
+    DocComment,
+}
@@ -1,8 +1,9 @@
 use crate::{
     common::{ExprId, HasNodeId, SpanId},
+    context::with_cx,
     prelude::EmissionNode,
     private::Sealed,
-    sem::TyKind,
+    sem::{ConstValue, TyKind},
     span::{HasSpan, Span},
     CtorBlocker,
 };
@@ -36,9 +37,31 @@ pub trait ExprData<'ast>: Debug + EmissionNode<'ast> + HasSpan<'ast> + HasNodeId
     /// Returns the [`ExprId`] of this expression.
     fn id(&self) -> ExprId;
 
-    /// Returns the semantic type of this expression.
+    /// Returns the semantic type of this expression, before any adjustments
+    /// were applied. See [`adjusted_ty`](Self::adjusted_ty) for the type after
+    /// coercions.
     fn ty(&self) -> TyKind<'ast>;
 
+    /// Returns the semantic type of this expression, after all adjustments,
+    /// like auto-referencing, auto-dereferencing and unsizing coercions, were
+    /// applied. This is the type, which is actually used by the surrounding
+    /// expression.
+    ///
+    /// ```ignore
+    /// fn takes_str(_: &str) {}
+    ///
+    /// let text = String::new();
+    /// takes_str(&text);
+    /// //        ^^^^^ ty()          -> `&String`
+    /// //              adjusted_ty() -> `&str`
+    /// ```
+    ///
+    /// For expressions without adjustments, this is the same as [`ty`](Self::ty).
+    /// Both types are taken from the type checking results of the body, that
+    /// the expression belongs to. Expressions outside of bodies, like the length
+    /// of an array type, are part of an anonymous const body.
+    fn adjusted_ty(&self) -> TyKind<'ast>;
+
     /// Returns the [`ExprPrecedence`] of this expression.
     fn precedence(&self) -> ExprPrecedence;
 
@@ -85,6 +108,7 @@ pub enum ExprKind<'ast> {
     Loop(&'ast LoopExpr<'ast>),
     While(&'ast WhileExpr<'ast>),
     Await(&'ast AwaitExpr<'ast>),
+    Yield(&'ast YieldExpr<'ast>),
     Unstable(&'ast UnstableExpr<'ast>),
 }
 
@@ -92,6 +116,7 @@ impl<'ast> ExprKind<'ast> {
     impl_expr_kind_fn!(ExprKind: span() -> &Span<'ast>);
     impl_expr_kind_fn!(ExprKind: id() -> ExprId);
     impl_expr_kind_fn!(ExprKind: ty() -> TyKind<'ast>);
+    impl_expr_kind_fn!(ExprKind: adjusted_ty() -> TyKind<'ast>);
     impl_expr_kind_fn!(ExprKind: precedence() -> ExprPrecedence);
 }
 
@@ -117,6 +142,7 @@ impl<'ast> LitExprKind<'ast> {
     impl_expr_kind_fn!(LitExprKind: span() -> &Span<'ast>);
     impl_expr_kind_fn!(LitExprKind: id() -> ExprId);
     impl_expr_kind_fn!(LitExprKind: ty() -> TyKind<'ast>);
+    impl_expr_kind_fn!(LitExprKind: adjusted_ty() -> TyKind<'ast>);
     impl_expr_kind_fn!(LitExprKind: precedence() -> ExprPrecedence);
 }
 
@@ -245,6 +271,7 @@ pub enum ExprPrecedence {
     Break = 0x0100_0001,
     Return = 0x0100_0002,
     Continue = 0x0100_0003,
+    Yield = 0x0100_0004,
     /// The precedence originates from an unstable source. The stored value provides
     /// the current precedence of this expression. This might change in the future
     Unstable(i32),
@@ -260,7 +287,7 @@ macro_rules! impl_expr_kind_fn {
             Call, Method,
             Array, Tuple, Ctor, Range,
             If, Let, Match, Break, Return, Continue, For, Loop, While,
-            Await,
+            Await, Yield,
             Unstable
         );
     };
@@ -323,6 +350,10 @@ macro_rules! impl_expr_data {
                 $crate::context::with_cx(self, |cx| cx.expr_ty(self.data.id))
             }
 
+            fn adjusted_ty(&self) -> $crate::sem::TyKind<'ast> {
+                $crate::context::with_cx(self, |cx| cx.expr_adjusted_ty(self.data.id))
+            }
+
             $precedence_fn
 
             fn as_expr(&'ast self) -> crate::ast::ExprKind<'ast> {
@@ -348,8 +379,7 @@ use impl_expr_data;
 /// An expression that is evaluated at compile time. These show up in array
 /// indices and constant generics.
 ///
-/// Marker currently doesn't provide a way to calculate the result of a constant
-/// expression.
+/// The result of the evaluation can be retrieved with [`ConstExpr::value`].
 #[repr(C)]
 #[derive(Debug)]
 pub struct ConstExpr<'ast> {
@@ -361,6 +391,17 @@ impl<'ast> ConstExpr<'ast> {
     pub fn expr(&self) -> ExprKind<'ast> {
         self.expr
     }
+
+    /// The value of this constant expression, as computed by the const evaluation
+    /// of the driver. For `[0u8; 4 * 4]`, the length would have the integer value `16`.
+    ///
+    /// `None` is returned, if the expression can't be evaluated, for example,
+    /// because it depends on generic parameters. Values which aren't
+    /// represented in the API yet, will have the
+    /// [`ConstValueKind::Unstable`](crate::sem::ConstValueKind::Unstable) kind.
+    pub fn value(&self) -> Option<&'ast ConstValue<'ast>> {
+        with_cx(self, |cx| cx.const_expr_value(self.expr.id()))
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -387,10 +428,10 @@ mod test {
         assert_size_of::<CharLitExpr<'_>>(&expect!["24"]);
         assert_size_of::<BoolLitExpr<'_>>(&expect!["24"]);
         assert_size_of::<BlockExpr<'_>>(&expect!["96"]);
-        assert_size_of::<ClosureExpr<'_>>(&expect!["72"]);
+        assert_size_of::<ClosureExpr<'_>>(&expect!["96"]);
         assert_size_of::<UnaryOpExpr<'_>>(&expect!["40"]);
         assert_size_of::<RefExpr<'_>>(&expect!["40"]);
-        assert_size_of::<BinaryOpExpr<'_>>(&expect!["56"]);
+        assert_size_of::<BinaryOpExpr<'_>>(&expect!["72"]);
         assert_size_of::<TryExpr<'_>>(&expect!["32"]);
         assert_size_of::<AssignExpr<'_>>(&expect!["56"]);
         assert_size_of::<AsExpr<'_>>(&expect!["48"]);
@@ -405,9 +446,10 @@ mod test {
         assert_size_of::<FieldExpr<'_>>(&expect!["48"]);
         assert_size_of::<IfExpr<'_>>(&expect!["72"]);
         assert_size_of::<LetExpr<'_>>(&expect!["48"]);
-        assert_size_of::<MatchExpr<'_>>(&expect!["48"]);
+        assert_size_of::<MatchExpr<'_>>(&expect!["64"]);
         assert_size_of::<BreakExpr<'_>>(&expect!["72"]);
         assert_size_of::<ReturnExpr<'_>>(&expect!["40"]);
+        assert_size_of::<YieldExpr<'_>>(&expect!["32"]);
         assert_size_of::<ContinueExpr<'_>>(&expect!["48"]);
         assert_size_of::<ForExpr<'_>>(&expect!["88"]);
         assert_size_of::<LoopExpr<'_>>(&expect!["56"]);
@@ -1,7 +1,9 @@
 use crate::{
     ast::{pat::PatKind, stmt::StmtKind, ty::TyKind},
-    common::{BodyId, Safety, SpanId, Syncness},
+    common::{BodyId, Safety, SpanId, Syncness, VarId},
+    context::with_cx,
     ffi::{FfiOption, FfiSlice},
+    sem,
     span::Ident,
 };
 
@@ -142,6 +144,8 @@ pub struct ClosureExpr<'ast> {
     params: FfiSlice<'ast, ClosureParam<'ast>>,
     return_ty: FfiOption<TyKind<'ast>>,
     body_id: BodyId,
+    captures: FfiSlice<'ast, ClosureCapture<'ast>>,
+    is_coroutine: bool,
 }
 
 impl<'ast> ClosureExpr<'ast> {
@@ -149,17 +153,72 @@ impl<'ast> ClosureExpr<'ast> {
         self.capture_kind
     }
 
+    /// Returns `true`, if this closure is marked with the `move` keyword.
+    pub fn is_move(&self) -> bool {
+        matches!(self.capture_kind, CaptureKind::Move)
+    }
+
+    /// Returns `true`, if this closure is a coroutine. Coroutines are unstable
+    /// and written as closures, which contain `yield` expressions. They are
+    /// resumed instead of called and can't be treated like normal closures.
+    ///
+    /// ```ignore
+    /// #![feature(coroutines)]
+    /// //              vv A coroutine, since the body contains `yield`
+    /// let coroutine = || {
+    ///     yield 1;
+    /// };
+    /// ```
+    pub fn is_coroutine(&self) -> bool {
+        self.is_coroutine
+    }
+
+    /// The variables captured by this closure, as inferred by the type checker.
+    ///
+    /// ```
+    /// let name = String::from("Marker");
+    /// let mut count = 0;
+    /// let mut greet = || {
+    ///     // `name` is captured by reference and `count` by mutable reference
+    ///     println!("Hey {name}");
+    ///     count += 1;
+    /// };
+    /// # greet();
+    /// ```
+    ///
+    /// The capture information is only available, for closures whose body has
+    /// been type checked. For all other closures, the slice will be empty.
+    pub fn captures(&self) -> &'ast [ClosureCapture<'ast>] {
+        self.captures.get()
+    }
+
     pub fn params(&self) -> &'ast [ClosureParam<'ast>] {
         self.params.get()
     }
 
-    pub fn return_ty(&self) -> Option<TyKind<'_>> {
+    /// The explicitly declared return type of this closure, if any, like the
+    /// `u32` in `|| -> u32 { 1 }`.
+    pub fn return_ty(&self) -> Option<TyKind<'ast>> {
         self.return_ty.copy()
     }
 
     pub fn body_id(&self) -> BodyId {
         self.body_id
     }
+
+    /// The value expression of the closure body. This is a shorthand for
+    /// retrieving the [`Body`](crate::ast::Body) of [`ClosureExpr::body_id`].
+    ///
+    /// ```
+    /// //                     v The body expression of the closure
+    /// let five = (|| 5)();
+    /// //          ^^^^ A closure without parameters, which is called directly
+    /// ```
+    ///
+    /// For closures with a block, like `|| { 5 }`, this returns the [`BlockExpr`].
+    pub fn body_expr(&self) -> ExprKind<'ast> {
+        with_cx(self, |cx| cx.ast().body(self.body_id).expr())
+    }
 }
 
 super::impl_expr_data!(ClosureExpr<'ast>, Closure);
@@ -172,6 +231,8 @@ impl<'ast> ClosureExpr<'ast> {
         params: &'ast [ClosureParam<'ast>],
         return_ty: Option<TyKind<'ast>>,
         body_id: BodyId,
+        captures: &'ast [ClosureCapture<'ast>],
+        is_coroutine: bool,
     ) -> Self {
         Self {
             data,
@@ -179,6 +240,8 @@ impl<'ast> ClosureExpr<'ast> {
             params: params.into(),
             return_ty: return_ty.into(),
             body_id,
+            captures: captures.into(),
+            is_coroutine,
         }
     }
 }
@@ -191,6 +254,59 @@ pub enum CaptureKind {
     Move,
 }
 
+/// A variable captured by a [`ClosureExpr`].
+///
+/// Closures can capture disjoint fields of a variable, like `|| drop(a.field)`.
+/// In this case, the variable is only listed once, with the strongest
+/// [`ClosureCaptureKind`] of all captured places.
+#[repr(C)]
+#[derive(Debug)]
+pub struct ClosureCapture<'ast> {
+    var: VarId,
+    kind: ClosureCaptureKind,
+    ty: sem::TyKind<'ast>,
+}
+
+impl<'ast> ClosureCapture<'ast> {
+    /// The id of the captured variable.
+    pub fn var(&self) -> VarId {
+        self.var
+    }
+
+    pub fn kind(&self) -> ClosureCaptureKind {
+        self.kind
+    }
+
+    /// The semantic type of the captured variable. This is the type of the
+    /// variable itself, even if only some of its fields are captured.
+    pub fn ty(&self) -> sem::TyKind<'ast> {
+        self.ty
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> ClosureCapture<'ast> {
+    pub fn new(var: VarId, kind: ClosureCaptureKind, ty: sem::TyKind<'ast>) -> Self {
+        Self { var, kind, ty }
+    }
+}
+
+/// Describes how a variable is captured by a closure. The variants are
+/// ordered from the weakest to the strongest capture.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClosureCaptureKind {
+    /// The variable is captured by a shared reference.
+    Ref,
+    /// The variable is captured by a mutable or unique reference.
+    MutRef,
+    /// The variable is captured by value and its type implements [`Copy`].
+    Copy,
+    /// The variable is captured by value and moved into the closure.
+    Move,
+}
+
 /// A parameter for a [`ClosureExpr`], with a pattern and an optional type, like:
 ///
 /// ```
@@ -1,4 +1,4 @@
-use crate::{ast::AstPathSegment, ffi::FfiSlice};
+use crate::{ast::AstPathSegment, common::ItemId, context::with_cx, ffi::FfiSlice};
 
 use super::{CommonExprData, ExprKind};
 
@@ -37,10 +37,28 @@ impl<'ast> CallExpr<'ast> {
         self.func
     }
 
-    /// The arguments given to the operand.
+    /// The arguments given to the operand. The coercions applied to an argument,
+    /// like the unsizing of `&[u8; 3]` to `&[u8]`, are visible in the
+    /// [`adjusted_ty`](super::ExprData::adjusted_ty) of the argument.
     pub fn args(&self) -> &[ExprKind<'ast>] {
         self.args.get()
     }
+
+    /// Returns the [`ItemId`] of the called function, if the operand resolves to
+    /// a function item. This includes functions, associated functions and
+    /// locals, which hold a function item. For trait functions, this is the
+    /// function declared in the trait, like for
+    /// [`MarkerContext::resolve_method_target`](crate::context::MarkerContext::resolve_method_target).
+    ///
+    /// Calls of tuple struct and tuple variant constructors are usually represented
+    /// as [`CtorExpr`](super::CtorExpr)s. For the remaining constructor calls,
+    /// like `Self(1)`, the id of the constructed struct or enum is returned.
+    ///
+    /// `None` is returned for dynamic calls, like calls through function pointers,
+    /// closures or `dyn Fn` trait objects, and if the call couldn't be resolved.
+    pub fn resolved_fn(&self) -> Option<ItemId> {
+        with_cx(self, |cx| cx.resolve_call_target(self.data.id))
+    }
 }
 
 super::impl_expr_data!(CallExpr<'ast>, Call);
@@ -64,15 +82,6 @@ impl<'ast> MethodExpr<'ast> {
         &self.method
     }
 
-    // FIXME(xFrednet): Add this method again, once `resolve_method_target` is
-    // supported by rustc's driver
-    //
-    // /// This method resolves the [`ItemId`] of the method being called by this
-    // /// expression.
-    // pub fn resolve(&self) -> ItemId {
-    //     with_cx(self, |cx| cx.resolve_method_target(self.data.id))
-    // }
-
     /// The arguments given to the operand.
     pub fn args(&self) -> &[ExprKind<'ast>] {
         self.args.get()
@@ -6,7 +6,7 @@ use crate::{
     span::{Ident, Span},
 };
 
-use super::{CommonExprData, ExprKind};
+use super::{BinaryOpKind, CommonExprData, ExprKind};
 
 /// An if expression. If let expressions are expressed as an [`IfExpr`] with an
 /// [`LetExpr`] as the conditional expression.
@@ -59,6 +59,41 @@ impl<'ast> IfExpr<'ast> {
     pub fn els(&self) -> Option<ExprKind<'ast>> {
         self.els.copy()
     }
+
+    /// Returns the conditions of a let chain, in source order, if the
+    /// condition contains a [`LetExpr`]. Let chains are represented as
+    /// [`BinaryOpExpr`](super::BinaryOpExpr)s with the `&&` operator, this
+    /// function collects the operands of these expressions:
+    ///
+    /// ```ignore
+    /// // The let chain contains the expressions `let Some(a) = x`, `a > 2` and
+    /// // `let Some(b) = y`.
+    /// if let Some(a) = x && a > 2 && let Some(b) = y {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// A simple `if let` has a chain with a single [`LetExpr`]. `None` is
+    /// returned, if the condition doesn't contain a [`LetExpr`]. Conditions in
+    /// the chain, which are not [`LetExpr`]s, are regular boolean expressions.
+    pub fn let_chain(&self) -> Option<Vec<ExprKind<'ast>>> {
+        fn collect<'ast>(expr: ExprKind<'ast>, conditions: &mut Vec<ExprKind<'ast>>) {
+            match expr {
+                ExprKind::BinaryOp(op) if op.kind() == BinaryOpKind::And => {
+                    collect(op.left(), conditions);
+                    collect(op.right(), conditions);
+                },
+                _ => conditions.push(expr),
+            }
+        }
+
+        let mut conditions = vec![];
+        collect(self.condition, &mut conditions);
+        conditions
+            .iter()
+            .any(|cond| matches!(cond, ExprKind::Let(_)))
+            .then_some(conditions)
+    }
 }
 
 super::impl_expr_data!(IfExpr<'ast>, If);
@@ -149,6 +184,8 @@ pub struct MatchExpr<'ast> {
     data: CommonExprData<'ast>,
     scrutinee: ExprKind<'ast>,
     arms: FfiSlice<'ast, MatchArm<'ast>>,
+    source: MatchSource,
+    exhaustive_without_wildcard: FfiOption<bool>,
 }
 
 impl<'ast> MatchExpr<'ast> {
@@ -159,21 +196,80 @@ impl<'ast> MatchExpr<'ast> {
     pub fn arms(&self) -> &[MatchArm<'ast>] {
         self.arms.get()
     }
+
+    /// Describes where this `match` expression originates from. Lints, which
+    /// should only check `match` expressions written by the user, should
+    /// ignore all expressions with a source other than [`MatchSource::Normal`].
+    pub fn match_source(&self) -> MatchSource {
+        self.source
+    }
+
+    /// Returns `true`, if the arms of this `match` would still be exhaustive,
+    /// if all [wildcard arms](MatchArm::is_wildcard) were removed. Arms with a
+    /// guard are ignored, as they don't cover any value for certain.
+    ///
+    /// ```
+    /// # enum Animal { Duck, Goose }
+    /// # let animal = Animal::Duck;
+    /// match animal {
+    ///     Animal::Duck => println!("Quack"),
+    ///     Animal::Goose => println!("Honk"),
+    ///     // This arm is unreachable, the match is exhaustive without it.
+    ///     // `is_exhaustive_without_wildcard()` returns `Some(true)`
+    ///     #[allow(unreachable_patterns)]
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    ///
+    /// [`None`] is returned, if the `match` doesn't have a wildcard arm or if
+    /// the exhaustiveness can't be determined. Currently, this is only
+    /// determined for scrutinees of enum types and `bool`. For enums marked with
+    /// `#[non_exhaustive]` from other crates, `Some(false)` is returned, as the
+    /// wildcard arm is required.
+    pub fn is_exhaustive_without_wildcard(&self) -> Option<bool> {
+        self.exhaustive_without_wildcard.copy()
+    }
 }
 
 super::impl_expr_data!(MatchExpr<'ast>, Match);
 
 #[cfg(feature = "driver-api")]
 impl<'ast> MatchExpr<'ast> {
-    pub fn new(data: CommonExprData<'ast>, scrutinee: ExprKind<'ast>, arms: &'ast [MatchArm<'ast>]) -> Self {
+    pub fn new(
+        data: CommonExprData<'ast>,
+        scrutinee: ExprKind<'ast>,
+        arms: &'ast [MatchArm<'ast>],
+        source: MatchSource,
+        exhaustive_without_wildcard: Option<bool>,
+    ) -> Self {
         Self {
             data,
             scrutinee,
             arms: arms.into(),
+            source,
+            exhaustive_without_wildcard: exhaustive_without_wildcard.into(),
         }
     }
 }
 
+/// The source of a [`MatchExpr`].
+///
+/// Rustc desugars several constructs into `match` expressions. Marker
+/// represents most of them with dedicated expressions, like [`ForExpr`],
+/// [`TryExpr`](super::TryExpr) and [`AwaitExpr`](super::AwaitExpr).
+/// This enum describes the desugars, which are still represented as [`MatchExpr`]s.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchSource {
+    /// A `match` expression written by the user, like `match x { .. }`.
+    Normal,
+    /// A `match` expression created by the expansion of the `format_args!()`
+    /// macro. These are used, to evaluate arguments, which are used by multiple
+    /// placeholders, only once, like in `println!("{0} {0:?}", x)`.
+    FormatArgs,
+}
+
 /// An arm inside a [`MatchExpr`] with an optional guard.
 ///
 /// ```
@@ -200,6 +296,7 @@ pub struct MatchArm<'ast> {
     pat: PatKind<'ast>,
     guard: FfiOption<ExprKind<'ast>>,
     expr: ExprKind<'ast>,
+    is_irrefutable: bool,
 }
 
 impl<'ast> MatchArm<'ast> {
@@ -219,17 +316,46 @@ impl<'ast> MatchArm<'ast> {
         self.expr
     }
 
+    /// Returns `true`, if the pattern of this arm is a [`WildcardPat`](crate::ast::WildcardPat)
+    /// and the arm has no guard. A wildcard arm matches all values, which
+    /// haven't been matched by previous arms.
+    ///
+    /// Arms with a binding, like `x => {}`, also match all values, but are not
+    /// considered wildcard arms. These can be detected with
+    /// [`MatchArm::is_irrefutable`].
+    pub fn is_wildcard(&self) -> bool {
+        matches!(self.pat, PatKind::Wildcard(_)) && self.guard.get().is_none()
+    }
+
+    /// Returns `true`, if the pattern of this arm matches every value of the
+    /// scrutinee type, like `_`, `x` or `(_, Some(_) | None)`. The guard of the
+    /// arm is not considered.
+    ///
+    /// The check is based on the type of the scrutinee, but doesn't perform a
+    /// full exhaustiveness analysis. Some irrefutable patterns, like `0..=255`
+    /// for `u8` values, are considered refutable.
+    pub fn is_irrefutable(&self) -> bool {
+        self.is_irrefutable
+    }
+
     // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> MatchArm<'ast> {
-    pub fn new(span: SpanId, pat: PatKind<'ast>, guard: Option<ExprKind<'ast>>, expr: ExprKind<'ast>) -> Self {
+    pub fn new(
+        span: SpanId,
+        pat: PatKind<'ast>,
+        guard: Option<ExprKind<'ast>>,
+        expr: ExprKind<'ast>,
+        is_irrefutable: bool,
+    ) -> Self {
         Self {
             span,
             pat,
             guard: guard.into(),
             expr,
+            is_irrefutable,
         }
     }
 }
@@ -282,6 +408,43 @@ impl<'ast> ReturnExpr<'ast> {
     }
 }
 
+/// A yield expression, which suspends the execution of a coroutine and yields the
+/// given value. Coroutines are currently unstable, see
+/// [rust#43122](https://github.com/rust-lang/rust/issues/43122).
+///
+/// ```ignore
+/// # #![feature(coroutines)]
+/// let coroutine = || {
+///     //    v The yielded value
+///     yield 1;
+/// //  ^^^^^^^ A yield expression
+/// };
+/// ```
+///
+/// A `yield` without an explicit value, yields the unit value `()`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct YieldExpr<'ast> {
+    data: CommonExprData<'ast>,
+    expr: ExprKind<'ast>,
+}
+
+impl<'ast> YieldExpr<'ast> {
+    /// The yielded value.
+    pub fn expr(&self) -> ExprKind<'ast> {
+        self.expr
+    }
+}
+
+super::impl_expr_data!(YieldExpr<'ast>, Yield);
+
+#[cfg(feature = "driver-api")]
+impl<'ast> YieldExpr<'ast> {
+    pub fn new(data: CommonExprData<'ast>, expr: ExprKind<'ast>) -> Self {
+        Self { data, expr }
+    }
+}
+
 /// A break expression with an optional label and an optional value.
 ///
 /// ```
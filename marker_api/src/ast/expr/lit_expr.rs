@@ -1,6 +1,14 @@
-use crate::{common::SymbolId, context::with_cx, ffi::FfiOption, ffi::FfiSlice};
+use std::borrow::Cow;
 
-use super::{CommonExprData, ExprPrecedence};
+use crate::{
+    common::{NumKind, SymbolId},
+    context::with_cx,
+    ffi::FfiOption,
+    ffi::FfiSlice,
+    sem::TyKind,
+};
+
+use super::{CommonExprData, ExprData, ExprPrecedence};
 
 #[repr(C)]
 #[derive(Debug)]
@@ -147,6 +155,76 @@ impl<'ast> IntLitExpr<'ast> {
     pub fn suffix(&self) -> Option<IntSuffix> {
         self.suffix.copy()
     }
+
+    /// Returns the integer type of this literal. This is the type of the suffix,
+    /// if one has been specified, otherwise the type is determined by the semantic
+    /// type of the expression. `None` is returned, if the type is not an integer.
+    pub fn num_kind(&self) -> Option<NumKind> {
+        if let Some(suffix) = self.suffix() {
+            return Some(suffix.num_kind());
+        }
+        match self.ty() {
+            TyKind::Num(num) if num.is_integer() => Some(num.numeric_kind()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true`, if the value fits into the type of the literal, as
+    /// determined by [`num_kind`](Self::num_kind). The literal `300_u8` will return
+    /// `false`, since 300 is larger than [`u8::MAX`].
+    ///
+    /// `None` is returned, if the type is not an integer. For `isize` and `usize`,
+    /// `None` is also returned, if the result depends on the pointer width of
+    /// the compilation target. Values which fit on 32-bit targets fit on all
+    /// targets, values which don't fit on 64-bit targets don't fit on any.
+    ///
+    /// Negated literals, like `-128_i8`, are checked without the negation, since
+    /// the negation is a separate [`UnaryOpExpr`](super::UnaryOpExpr).
+    pub fn fits_suffix(&self) -> Option<bool> {
+        int_fits_kind(self.value, self.num_kind()?)
+    }
+
+    /// Returns the value converted to the integer type `T`, or `None`, if the
+    /// value doesn't fit into `T`. For example, the literal `300` can be read as a
+    /// `u16`, but not as an `u8`.
+    ///
+    /// This conversion is independent of the type of the literal.
+    pub fn value_as<T: TryFrom<u128>>(&self) -> Option<T> {
+        T::try_from(self.value).ok()
+    }
+}
+
+/// Returns `true`, if the value fits into the given integer type, see
+/// [`IntLitExpr::fits_suffix`].
+fn int_fits_kind(value: u128, kind: NumKind) -> Option<bool> {
+    let max = match kind {
+        NumKind::I8 => i8::MAX as u128,
+        NumKind::I16 => i16::MAX as u128,
+        NumKind::I32 => i32::MAX as u128,
+        NumKind::I64 => i64::MAX as u128,
+        NumKind::I128 => i128::MAX as u128,
+        NumKind::U8 => u128::from(u8::MAX),
+        NumKind::U16 => u128::from(u16::MAX),
+        NumKind::U32 => u128::from(u32::MAX),
+        NumKind::U64 => u128::from(u64::MAX),
+        NumKind::U128 => u128::MAX,
+        // The size depends on the target. Values which fit on 64-bit targets,
+        // but not on 32-bit targets, can't be decided here.
+        NumKind::Isize => return pointer_sized_fits(value, i32::MAX as u128, i64::MAX as u128),
+        NumKind::Usize => return pointer_sized_fits(value, u128::from(u32::MAX), u128::from(u64::MAX)),
+        NumKind::F32 | NumKind::F64 => return None,
+    };
+    Some(value <= max)
+}
+
+fn pointer_sized_fits(value: u128, max_32: u128, max_64: u128) -> Option<bool> {
+    if value <= max_32 {
+        Some(true)
+    } else if value > max_64 {
+        Some(false)
+    } else {
+        None
+    }
 }
 
 super::impl_expr_data!(
@@ -185,6 +263,26 @@ pub enum IntSuffix {
     U128,
 }
 
+impl IntSuffix {
+    /// Returns the [`NumKind`] of the type specified by this suffix.
+    pub fn num_kind(&self) -> NumKind {
+        match self {
+            IntSuffix::Isize => NumKind::Isize,
+            IntSuffix::I8 => NumKind::I8,
+            IntSuffix::I16 => NumKind::I16,
+            IntSuffix::I32 => NumKind::I32,
+            IntSuffix::I64 => NumKind::I64,
+            IntSuffix::I128 => NumKind::I128,
+            IntSuffix::Usize => NumKind::Usize,
+            IntSuffix::U8 => NumKind::U8,
+            IntSuffix::U16 => NumKind::U16,
+            IntSuffix::U32 => NumKind::U32,
+            IntSuffix::U64 => NumKind::U64,
+            IntSuffix::U128 => NumKind::U128,
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Debug)]
 pub struct StrLitExpr<'ast> {
@@ -194,7 +292,8 @@ pub struct StrLitExpr<'ast> {
 }
 
 impl<'ast> StrLitExpr<'ast> {
-    /// Returns `true`, if this is a raw string literal, like `r#"Hello World!"#`
+    /// Returns `true`, if this is a raw string literal, like `r#"Hello World!"#`.
+    /// Escape sequences in raw literals are not interpreted.
     pub fn is_raw_lit(&self) -> bool {
         self.is_raw
     }
@@ -214,6 +313,9 @@ impl<'ast> StrLitExpr<'ast> {
     /// This returns the UTF-8 string value of the string, if possible. Normal
     /// and raw strings in Rust are required to be UTF-8. Byte strings will be
     /// converted to UTF-8 if possible, otherwise `None` will be returned
+    ///
+    /// The value is decoded, escape sequences like `\n` or `\u{1F980}` have
+    /// already been replaced by the characters they represent.
     pub fn str_value(&self) -> Option<&str> {
         match &self.str_data {
             StrLitData::Sym(sym) => Some(with_cx(self, |cx| cx.symbol_str(*sym))),
@@ -221,7 +323,18 @@ impl<'ast> StrLitExpr<'ast> {
         }
     }
 
-    /// Returns the value of the string as bytes.
+    /// This returns the decoded value of the string. Unlike [`str_value`](Self::str_value),
+    /// this always returns a value. Byte strings, which are not valid UTF-8, are
+    /// converted lossily, with invalid sequences replaced by `U+FFFD`.
+    pub fn value(&self) -> Cow<'ast, str> {
+        match &self.str_data {
+            StrLitData::Sym(sym) => Cow::Borrowed(with_cx(self, |cx| cx.symbol_str(*sym))),
+            StrLitData::Bytes(bytes) => String::from_utf8_lossy(bytes.get()),
+        }
+    }
+
+    /// Returns the decoded value of the string as bytes. For byte strings, this
+    /// is the exact value, including bytes which are not valid UTF-8.
     pub fn byte_value(&self) -> &[u8] {
         match &self.str_data {
             StrLitData::Sym(sym) => with_cx(self, |cx| cx.symbol_str(*sym)).as_bytes(),
@@ -253,3 +366,44 @@ enum StrLitData<'ast> {
     /// A byte string might not be valid UTF-8
     Bytes(FfiSlice<'ast, u8>),
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_u8_range() {
+        // `255_u8`
+        assert_eq!(int_fits_kind(255, NumKind::U8), Some(true));
+        // `256_u8`
+        assert_eq!(int_fits_kind(256, NumKind::U8), Some(false));
+        // `0_u8`
+        assert_eq!(int_fits_kind(0, NumKind::U8), Some(true));
+    }
+
+    #[test]
+    fn test_signed_range() {
+        // `127_i8`
+        assert_eq!(int_fits_kind(127, NumKind::I8), Some(true));
+        // `128_i8`, only valid as `-128_i8`
+        assert_eq!(int_fits_kind(128, NumKind::I8), Some(false));
+        assert_eq!(int_fits_kind(u128::MAX, NumKind::I128), Some(false));
+        assert_eq!(int_fits_kind(u128::MAX, NumKind::U128), Some(true));
+    }
+
+    #[test]
+    fn test_pointer_sized_range() {
+        assert_eq!(int_fits_kind(255, NumKind::Usize), Some(true));
+        assert_eq!(int_fits_kind(u128::from(u32::MAX), NumKind::Usize), Some(true));
+        assert_eq!(int_fits_kind(1 << 40, NumKind::Usize), None);
+        assert_eq!(int_fits_kind(1 << 70, NumKind::Usize), Some(false));
+        assert_eq!(int_fits_kind(i32::MAX as u128, NumKind::Isize), Some(true));
+        assert_eq!(int_fits_kind(1 << 40, NumKind::Isize), None);
+        assert_eq!(int_fits_kind(1 << 63, NumKind::Isize), Some(false));
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(int_fits_kind(1, NumKind::F64), None);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     ast::{pat::PatKind, ty::TyKind},
-    common::Mutability,
+    common::{ItemId, Mutability},
     ffi::FfiOption,
 };
 
@@ -13,6 +13,7 @@ pub struct BinaryOpExpr<'ast> {
     left: ExprKind<'ast>,
     right: ExprKind<'ast>,
     kind: BinaryOpKind,
+    resolution: BinOpResolution,
 }
 
 impl<'ast> BinaryOpExpr<'ast> {
@@ -27,6 +28,27 @@ impl<'ast> BinaryOpExpr<'ast> {
     pub fn kind(&self) -> BinaryOpKind {
         self.kind
     }
+
+    /// Returns how the operator is resolved. Operators on primitive types are
+    /// usually built-in, while operators on other types call the method of an
+    /// operator trait, like [`Add::add`](std::ops::Add::add).
+    ///
+    /// ```
+    /// # use std::ops::Add;
+    /// // Built-in operator
+    /// let _ = 1 + 2;
+    ///
+    /// // Overloaded operator, implemented by the `Add<&str>` impl of `String`
+    /// let _ = String::from("Hello ") + "World";
+    ///
+    /// fn generic<T: Add<Output = T>>(a: T, b: T) -> T {
+    ///     // Overloaded operator, implemented by the `Add` bound of `T`
+    ///     a + b
+    /// }
+    /// ```
+    pub fn resolved_op(&self) -> BinOpResolution {
+        self.resolution
+    }
 }
 
 super::impl_expr_data!(
@@ -58,19 +80,40 @@ super::impl_expr_data!(
 
 #[cfg(feature = "driver-api")]
 impl<'ast> BinaryOpExpr<'ast> {
-    pub fn new(data: CommonExprData<'ast>, left: ExprKind<'ast>, right: ExprKind<'ast>, kind: BinaryOpKind) -> Self {
+    pub fn new(
+        data: CommonExprData<'ast>,
+        left: ExprKind<'ast>,
+        right: ExprKind<'ast>,
+        kind: BinaryOpKind,
+        resolution: BinOpResolution,
+    ) -> Self {
         Self {
             data,
             left,
             right,
             kind,
+            resolution,
         }
     }
 }
 
+/// The resolution of a binary operator, see [`BinaryOpExpr::resolved_op`].
 #[repr(C)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BinOpResolution {
+    /// The operator is built into the language, like `+` on integers or `&&`
+    /// on booleans.
+    Builtin,
+    /// The operator calls the method of an operator trait. The [`ItemId`]
+    /// identifies the trait method, like [`Add::add`](std::ops::Add::add) or
+    /// [`PartialEq::eq`].
+    Overloaded(ItemId),
+}
+
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum BinaryOpKind {
     /// The `*` operator
     Mul,
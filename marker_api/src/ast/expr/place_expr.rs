@@ -1,4 +1,4 @@
-use crate::span::Ident;
+use crate::{context::with_cx, span::Ident};
 
 use super::{CommonExprData, ExprKind};
 
@@ -79,6 +79,17 @@ impl<'ast> FieldExpr<'ast> {
     pub fn field(&self) -> &Ident<'ast> {
         &self.field
     }
+
+    /// Returns `true`, if this expression accesses a field of a union. Reading
+    /// a union field is `unsafe`, since the field might not be initialized.
+    /// The operand can also be a reference to a union, which is dereferenced
+    /// automatically.
+    ///
+    /// The fields of the union and their types are available from
+    /// [`UnionItem::fields`](crate::ast::UnionItem::fields).
+    pub fn is_union_access(&self) -> bool {
+        with_cx(self, |cx| cx.is_union_field_access(self.data.id))
+    }
 }
 
 super::impl_expr_data!(FieldExpr<'ast>, Field);
@@ -7,6 +7,7 @@ mod param;
 pub use param::*;
 
 use crate::{
+    ast::{ty::TyKind, AstPathTarget},
     common::{GenericId, SpanId, SymbolId},
     context::with_cx,
     ffi::{FfiOption, FfiSlice},
@@ -116,6 +117,27 @@ impl<'ast> Lifetime<'ast> {
 /// }
 /// ```
 ///
+/// The arguments can be accessed by kind with the [`type_args()`](Self::type_args),
+/// [`lifetime_args()`](Self::lifetime_args), [`binding_args()`](Self::binding_args)
+/// and [`const_args()`](Self::const_args) methods. This example retrieves the
+/// hasher of a `HashMap<K, V, S>` type, if it has been specified:
+///
+/// ```
+/// # use marker_api::prelude::*;
+/// # use marker_api::ast::TyKind;
+/// fn hasher_ty<'ast>(ty: TyKind<'ast>) -> Option<TyKind<'ast>> {
+///     let TyKind::Path(path_ty) = ty else {
+///         return None;
+///     };
+///     let segment = path_ty.path().segments().last()?;
+///     if segment.ident().name() != "HashMap" {
+///         return None;
+///     }
+///
+///     segment.generics().type_args().nth(2).map(|arg| arg.ty())
+/// }
+/// ```
+///
 /// See:
 /// * [`GenericParams`]
 #[repr(C)]
@@ -133,6 +155,39 @@ impl<'ast> GenericArgs<'ast> {
     pub fn is_empty(&self) -> bool {
         self.args.is_empty()
     }
+
+    /// Returns the type arguments, in the order they have been specified.
+    pub fn type_args(&self) -> impl Iterator<Item = &'ast TyArg<'ast>> {
+        self.args().iter().filter_map(|arg| match arg {
+            GenericArgKind::Ty(arg) => Some(*arg),
+            _ => None,
+        })
+    }
+
+    /// Returns the lifetime arguments, in the order they have been specified.
+    pub fn lifetime_args(&self) -> impl Iterator<Item = &'ast LifetimeArg<'ast>> {
+        self.args().iter().filter_map(|arg| match arg {
+            GenericArgKind::Lifetime(arg) => Some(*arg),
+            _ => None,
+        })
+    }
+
+    /// Returns the type bindings, like `Item = u32` in `Iterator<Item = u32>`, in
+    /// the order they have been specified.
+    pub fn binding_args(&self) -> impl Iterator<Item = &'ast BindingArg<'ast>> {
+        self.args().iter().filter_map(|arg| match arg {
+            GenericArgKind::Binding(arg) => Some(*arg),
+            _ => None,
+        })
+    }
+
+    /// Returns the constant arguments, in the order they have been specified.
+    pub fn const_args(&self) -> impl Iterator<Item = &'ast ConstArg<'ast>> {
+        self.args().iter().filter_map(|arg| match arg {
+            GenericArgKind::Const(arg) => Some(*arg),
+            _ => None,
+        })
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -223,6 +278,43 @@ impl<'ast> GenericParams<'ast> {
     pub fn clauses(&self) -> &'ast [WhereClauseKind<'ast>] {
         self.clauses.get()
     }
+
+    /// Returns the trait bounds applied to the generic type parameter with the
+    /// given [`GenericId`]. This includes bounds declared inline, with the
+    /// parameter, and bounds declared in the `where` clause:
+    ///
+    /// ```
+    /// # use std::fmt::Debug;
+    /// //                    For `T`, this returns `Copy` and `Debug`
+    /// //                       vvvv
+    /// pub fn generic_item<T: Copy>(t: T)
+    /// where
+    ///     T: Debug,
+    /// //     ^^^^^
+    /// {}
+    /// ```
+    ///
+    /// Relaxed bounds, like `?Sized`, are included as well. They can be identified
+    /// with [`TraitBound::is_relaxed`]. Bounds on types which only contain the
+    /// parameter, like `Vec<T>: Debug`, are not included.
+    pub fn bounds_for(&self, param: GenericId) -> impl Iterator<Item = &'ast TraitBound<'ast>> {
+        self.clauses()
+            .iter()
+            .filter_map(move |clause| match clause {
+                WhereClauseKind::Ty(clause) => {
+                    let TyKind::Path(path) = clause.ty() else {
+                        return None;
+                    };
+                    matches!(path.path().resolve(), AstPathTarget::Generic(id) if id == param).then(|| clause.bounds())
+                },
+                WhereClauseKind::Lifetime(_) => None,
+            })
+            .flatten()
+            .filter_map(|bound| match bound {
+                TyParamBound::TraitBound(bound) => Some(*bound),
+                TyParamBound::Lifetime(_) => None,
+            })
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -54,7 +54,7 @@ pub struct TyArg<'ast> {
 }
 
 impl<'ast> TyArg<'ast> {
-    pub fn ty(&self) -> TyKind<'_> {
+    pub fn ty(&self) -> TyKind<'ast> {
         self.ty
     }
 
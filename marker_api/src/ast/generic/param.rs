@@ -11,7 +11,7 @@ use crate::{
     context::with_cx,
     ffi::{FfiOption, FfiSlice},
     private::Sealed,
-    span::Span,
+    span::{HasSpan, Span},
 };
 
 use super::TyParamBound;
@@ -62,6 +62,16 @@ impl<'ast> GenericParamKind<'ast> {
     }
 }
 
+crate::common::impl_identifiable_for!(GenericParamKind<'ast>);
+
+impl<'ast> HasSpan<'ast> for GenericParamKind<'ast> {
+    fn span(&self) -> &Span<'ast> {
+        // Parameters are only missing a span, if they were generated by the
+        // compiler. These are not part of the AST, that is given to lint crates.
+        GenericParamKind::span(self).expect("generic parameters in the AST have a span")
+    }
+}
+
 /// This trait is a collection of common information that is provided by all
 /// generic parameters.
 ///
@@ -1,16 +1,17 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, marker::PhantomData};
 
 use crate::{
     common::{HasNodeId, ItemId, SpanId},
     context::with_cx,
     diagnostic::EmissionNode,
-    ffi::FfiOption,
+    ffi::{FfiOption, FfiSlice},
     private::Sealed,
+    sem,
     span::{HasSpan, Ident, Span},
     CtorBlocker,
 };
 
-use super::expr::ExprKind;
+use super::{expr::ExprKind, pat::PatKind, Attribute};
 
 // Item implementations
 mod extern_crate_item;
@@ -59,12 +60,8 @@ pub trait ItemData<'ast>: Debug + EmissionNode<'ast> + HasSpan<'ast> + HasNodeId
     /// as a bound to support all items and `ItemKind<'ast>` as parameters.
     fn as_item(&'ast self) -> ItemKind<'ast>;
 
-    /// The attributes attached to this item.
-    ///
-    /// Currently, it's only a placeholder until a proper representation is implemented.
-    /// rust-marker/marker#51 tracks the task of implementing this. You're welcome to
-    /// leave any comments in that issue.
-    fn attrs(&self); // FIXME: Add return type: -> &'ast [&'ast dyn Attribute<'ast>];
+    /// The [`Attribute`]s attached to this item.
+    fn attrs(&self) -> &[Attribute<'ast>];
 }
 
 #[repr(C)]
@@ -92,7 +89,17 @@ impl<'ast> ItemKind<'ast> {
     impl_item_type_fn!(ItemKind: span() -> &Span<'ast>);
     impl_item_type_fn!(ItemKind: visibility() -> &Visibility<'ast>);
     impl_item_type_fn!(ItemKind: ident() -> Option<&Ident<'ast>>);
-    impl_item_type_fn!(ItemKind: attrs() -> ());
+    impl_item_type_fn!(ItemKind: attrs() -> &[Attribute<'ast>]);
+
+    /// Returns the path of the file, which contains this item. For modules,
+    /// this is the file of the module content, see [`ModItem::source_file`].
+    /// For items from macro expansions, the file of the macro call is used.
+    pub fn source_file(&self) -> Option<&'ast str> {
+        match self {
+            ItemKind::Mod(item) => item.source_file(),
+            _ => self.span().source_file(),
+        }
+    }
 }
 
 crate::span::impl_spanned_for!(ItemKind<'ast>);
@@ -111,7 +118,7 @@ impl<'ast> AssocItemKind<'ast> {
     impl_item_type_fn!(AssocItemKind: span() -> &Span<'ast>);
     impl_item_type_fn!(AssocItemKind: visibility() -> &Visibility<'ast>);
     impl_item_type_fn!(AssocItemKind: ident() -> Option<&Ident<'ast>>);
-    impl_item_type_fn!(AssocItemKind: attrs() -> ());
+    impl_item_type_fn!(AssocItemKind: attrs() -> &[Attribute<'ast>]);
     impl_item_type_fn!(AssocItemKind: as_item() -> ItemKind<'ast>);
     // FIXME: Potentially add a field to the items to optionally store the owner id
 }
@@ -134,6 +141,9 @@ impl<'ast> From<AssocItemKind<'ast>> for ItemKind<'ast> {
 pub enum ExternItemKind<'ast> {
     Static(&'ast StaticItem<'ast>, CtorBlocker),
     Fn(&'ast FnItem<'ast>, CtorBlocker),
+    /// Foreign items, which are still unstable, like foreign types from the
+    /// `extern_types` feature.
+    Unstable(&'ast UnstableItem<'ast>, CtorBlocker),
 }
 
 impl<'ast> ExternItemKind<'ast> {
@@ -141,7 +151,7 @@ impl<'ast> ExternItemKind<'ast> {
     impl_item_type_fn!(ExternItemKind: span() -> &Span<'ast>);
     impl_item_type_fn!(ExternItemKind: visibility() -> &Visibility<'ast>);
     impl_item_type_fn!(ExternItemKind: ident() -> Option<&Ident<'ast>>);
-    impl_item_type_fn!(ExternItemKind: attrs() -> ());
+    impl_item_type_fn!(ExternItemKind: attrs() -> &[Attribute<'ast>]);
     impl_item_type_fn!(ExternItemKind: as_item() -> ItemKind<'ast>);
 }
 
@@ -153,6 +163,7 @@ impl<'ast> From<ExternItemKind<'ast>> for ItemKind<'ast> {
         match value {
             ExternItemKind::Static(item, ..) => ItemKind::Static(item),
             ExternItemKind::Fn(item, ..) => ItemKind::Fn(item),
+            ExternItemKind::Unstable(item, ..) => ItemKind::Unstable(item),
         }
     }
 }
@@ -173,7 +184,7 @@ macro_rules! impl_item_type_fn {
     };
     (ExternItemKind: $method:ident () -> $return_ty:ty) => {
         impl_item_type_fn!((ExternItemKind) $method() -> $return_ty,
-            Static, Fn
+            Static, Fn, Unstable
         );
     };
     (($self:ident) $method:ident () -> $return_ty:ty $(, $item:ident)+) => {
@@ -196,6 +207,8 @@ struct CommonItemData<'ast> {
     span: SpanId,
     vis: Visibility<'ast>,
     ident: Ident<'ast>,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 macro_rules! impl_item_data {
@@ -217,7 +230,9 @@ macro_rules! impl_item_data {
                 $crate::ast::item::ItemKind::$enum_name(self)
             }
 
-            fn attrs(&self) {}
+            fn attrs(&self) -> &[crate::ast::Attribute<'ast>] {
+                self.data.attrs.get()
+            }
         }
 
         impl<'ast> $crate::span::HasSpan<'ast> for $self_name<'ast> {
@@ -267,6 +282,8 @@ pub struct Visibility<'ast> {
     #[cfg_attr(feature = "driver-api", builder(setter(into), default))]
     span: FfiOption<SpanId>,
     sem: crate::sem::Visibility<'ast>,
+    #[cfg_attr(feature = "driver-api", builder(setter(into), default))]
+    restricted_path: FfiOption<&'ast RestrictedPath<'ast>>,
 }
 
 impl<'ast> Visibility<'ast> {
@@ -281,6 +298,117 @@ impl<'ast> Visibility<'ast> {
     pub fn semantics(&self) -> &crate::sem::Visibility<'ast> {
         &self.sem
     }
+
+    /// The path of a restricted visibility, like `crate::scope` in
+    /// `pub(in crate::scope)`. This also includes the shorthands `pub(crate)`,
+    /// `pub(super)` and `pub(self)`.
+    ///
+    /// ```
+    /// mod scope {
+    ///     //       vvvvvvvvvvvv The restricted path
+    ///     pub(in crate::scope) fn whale() {}
+    ///
+    ///     //  vvvvv The restricted path
+    ///     pub(super) fn seal() {}
+    ///
+    ///     // No restricted path, as the visibility is not restricted
+    ///     pub fn penguin() {}
+    /// }
+    /// # fn main() {}
+    /// ```
+    ///
+    /// `None` is returned if the visibility doesn't restrict the item to a
+    /// specific module.
+    pub fn restricted_path(&self) -> Option<&'ast RestrictedPath<'ast>> {
+        self.restricted_path.copy()
+    }
+
+    /// Returns the [`VisibilityScope`], describing how the visibility was declared.
+    ///
+    /// ```
+    /// mod scope {
+    ///     // `VisibilityScope::Public`
+    ///     pub fn tiger() {}
+    ///
+    ///     // `VisibilityScope::Crate`
+    ///     pub(crate) fn lion() {}
+    ///
+    ///     // `VisibilityScope::Restricted` with the path `self`
+    ///     pub(self) fn lynx() {}
+    ///
+    ///     // `VisibilityScope::Inherited`
+    ///     fn cheetah() {}
+    /// }
+    /// # fn main() {}
+    /// ```
+    pub fn kind(&self) -> VisibilityScope<'ast> {
+        if self.sem.is_default() {
+            return VisibilityScope::Inherited;
+        }
+        if self.sem.is_pub() {
+            return VisibilityScope::Public;
+        }
+        if self.sem.is_crate_scoped() {
+            return VisibilityScope::Crate;
+        }
+
+        // Declared restricted visibilities should always have a path. The
+        // visibility is treated as undeclared, if the driver couldn't provide one.
+        self.restricted_path
+            .copy()
+            .map_or(VisibilityScope::Inherited, VisibilityScope::Restricted)
+    }
+}
+
+/// The scope of a declared [`Visibility`], see [`Visibility::kind()`].
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone)]
+pub enum VisibilityScope<'ast> {
+    /// The visibility is declared as `pub`, without any restrictions.
+    Public,
+    /// The visibility is restricted to the entire crate, like `pub(crate)`.
+    /// This also includes restricted paths, like `pub(super)`, which resolve
+    /// to the root module of the crate.
+    Crate,
+    /// The visibility is restricted to a module, other than the root module
+    /// of the crate, like `pub(super)` or `pub(in crate::scope)`.
+    Restricted(&'ast RestrictedPath<'ast>),
+    /// The visibility has not been declared. The item has the default visibility
+    /// of its context, which is private for most items and public for trait
+    /// items and enum variants.
+    Inherited,
+}
+
+/// The path of a restricted [`Visibility`], like `pub(in crate::scope)`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct RestrictedPath<'ast> {
+    _lifetime: PhantomData<&'ast ()>,
+    span: SpanId,
+    module: ItemId,
+}
+
+impl<'ast> RestrictedPath<'ast> {
+    /// The [`Span`] of the written path, without the `in` keyword.
+    pub fn span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.span))
+    }
+
+    /// The [`ItemId`] of the module, that the path resolves to.
+    pub fn module(&self) -> ItemId {
+        self.module
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'ast> RestrictedPath<'ast> {
+    pub fn new(span: SpanId, module: ItemId) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            span,
+            module,
+        }
+    }
 }
 
 /// A body represents the expression of items.
@@ -294,14 +422,47 @@ impl<'ast> Visibility<'ast> {
 #[derive(Debug)]
 pub struct Body<'ast> {
     owner: ItemId,
+    params: FfiSlice<'ast, BodyParam<'ast>>,
     expr: ExprKind<'ast>,
+    is_const: bool,
 }
 
 impl<'ast> Body<'ast> {
+    /// The [`ItemId`] of the owner of this body. This is usually a function,
+    /// `const` or `static` item. Closures and anonymous constants, like array
+    /// lengths, are the owners of their own bodies. Their ids don't belong to an
+    /// item, see [`Body::owner_item`].
     pub fn owner(&self) -> ItemId {
         self.owner
     }
 
+    /// Returns the item owning this body, like the function or `const` item.
+    /// This can be used to only check function bodies, by matching on
+    /// [`ItemKind::Fn`].
+    ///
+    /// `None` is returned, if the owner isn't an item, like for the bodies of
+    /// closures and anonymous constants.
+    pub fn owner_item(&self) -> Option<ItemKind<'ast>> {
+        with_cx(self, |cx| cx.ast().item(self.owner))
+    }
+
+    /// Returns `true`, if this body is evaluated in a constant context. This is
+    /// the case for the initializers of `const` and `static` items, the bodies of
+    /// `const fn`s and anonymous constants, like array lengths.
+    ///
+    /// Lints can use this, to avoid suggesting operations, which are not allowed
+    /// in constant contexts. Closures have their own body, which is never constant.
+    pub fn is_const(&self) -> bool {
+        self.is_const
+    }
+
+    /// The parameters of this body. Bodies of functions and closures have one
+    /// parameter per declared function or closure parameter. All other
+    /// bodies, like the initializers of `const` items, have no parameters.
+    pub fn params(&self) -> &'ast [BodyParam<'ast>] {
+        self.params.get()
+    }
+
     /// The expression wrapped by this body. In most cases this will be a
     /// [block expression](`crate::ast::expr::BlockExpr`).
     pub fn expr(&self) -> ExprKind<'ast> {
@@ -311,8 +472,52 @@ impl<'ast> Body<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> Body<'ast> {
-    pub fn new(owner: ItemId, expr: ExprKind<'ast>) -> Self {
-        Self { owner, expr }
+    pub fn new(owner: ItemId, params: &'ast [BodyParam<'ast>], expr: ExprKind<'ast>, is_const: bool) -> Self {
+        Self {
+            owner,
+            params: params.into(),
+            expr,
+            is_const,
+        }
+    }
+}
+
+/// A parameter of a [`Body`]. In contrast to [`FnParam`] and
+/// [`ClosureParam`](crate::ast::expr::ClosureParam), this provides the
+/// semantic type of the parameter, which is also available for closure
+/// parameters without a type declaration.
+///
+/// ```
+/// //        vvvvvv A parameter with a tuple pattern and the type `(u8, u8)`
+/// let max = |(a, b)| u8::max(a, b);
+/// # let _ = max((1, 2));
+/// ```
+#[repr(C)]
+#[derive(Debug)]
+pub struct BodyParam<'ast> {
+    span: SpanId,
+    pat: PatKind<'ast>,
+    ty: sem::TyKind<'ast>,
+}
+
+impl<'ast> BodyParam<'ast> {
+    /// The pattern of this parameter.
+    pub fn pat(&self) -> PatKind<'ast> {
+        self.pat
+    }
+
+    /// The semantic type of this parameter, as inferred by the type checker.
+    pub fn ty(&self) -> sem::TyKind<'ast> {
+        self.ty
+    }
+}
+
+crate::span::impl_has_span_via_field!(BodyParam<'ast>);
+
+#[cfg(feature = "driver-api")]
+impl<'ast> BodyParam<'ast> {
+    pub fn new(span: SpanId, pat: PatKind<'ast>, ty: sem::TyKind<'ast>) -> Self {
+        Self { span, pat, ty }
     }
 }
 
@@ -327,19 +532,19 @@ mod test {
     fn test_item_struct_size() {
         // These sizes are allowed to change, this is just a check to have a
         // general overview and to prevent accidental changes
-        assert_size_of::<ModItem<'_>>(&expect!["80"]);
-        assert_size_of::<ExternCrateItem<'_>>(&expect!["72"]);
-        assert_size_of::<UseItem<'_>>(&expect!["88"]);
-        assert_size_of::<StaticItem<'_>>(&expect!["104"]);
-        assert_size_of::<ConstItem<'_>>(&expect!["96"]);
-        assert_size_of::<FnItem<'_>>(&expect!["168"]);
-        assert_size_of::<TyAliasItem<'_>>(&expect!["136"]);
-        assert_size_of::<StructItem<'_>>(&expect!["120"]);
-        assert_size_of::<EnumItem<'_>>(&expect!["112"]);
-        assert_size_of::<UnionItem<'_>>(&expect!["112"]);
-        assert_size_of::<TraitItem<'_>>(&expect!["136"]);
-        assert_size_of::<ImplItem<'_>>(&expect!["168"]);
-        assert_size_of::<ExternBlockItem<'_>>(&expect!["88"]);
-        assert_size_of::<UnstableItem<'_>>(&expect!["72"]);
+        assert_size_of::<ModItem<'_>>(&expect!["120"]);
+        assert_size_of::<ExternCrateItem<'_>>(&expect!["104"]);
+        assert_size_of::<UseItem<'_>>(&expect!["136"]);
+        assert_size_of::<StaticItem<'_>>(&expect!["136"]);
+        assert_size_of::<ConstItem<'_>>(&expect!["128"]);
+        assert_size_of::<FnItem<'_>>(&expect!["208"]);
+        assert_size_of::<TyAliasItem<'_>>(&expect!["168"]);
+        assert_size_of::<StructItem<'_>>(&expect!["152"]);
+        assert_size_of::<EnumItem<'_>>(&expect!["144"]);
+        assert_size_of::<UnionItem<'_>>(&expect!["144"]);
+        assert_size_of::<TraitItem<'_>>(&expect!["168"]);
+        assert_size_of::<ImplItem<'_>>(&expect!["200"]);
+        assert_size_of::<ExternBlockItem<'_>>(&expect!["120"]);
+        assert_size_of::<UnstableItem<'_>>(&expect!["104"]);
     }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    ast::{expr::ConstExpr, generic::GenericParams, ty::TyKind},
+    ast::{expr::ConstExpr, generic::GenericParams, ty::TyKind, Attribute},
     common::{FieldId, SpanId, SymbolId, VariantId},
     context::with_cx,
     ffi::{FfiOption, FfiSlice},
@@ -100,6 +100,7 @@ pub struct EnumVariant<'ast> {
     span: SpanId,
     kind: AdtKind<'ast>,
     discriminant: FfiOption<ConstExpr<'ast>>,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 impl<'ast> EnumVariant<'ast> {
@@ -111,7 +112,10 @@ impl<'ast> EnumVariant<'ast> {
         with_cx(self, |cx| cx.symbol_str(self.ident))
     }
 
-    // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
+    /// The [`Attribute`]s attached to this variant.
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        self.attrs.get()
+    }
 
     /// Returns `true` if this is a unit variant like:
     ///
@@ -160,6 +164,22 @@ impl<'ast> EnumVariant<'ast> {
     pub fn discriminant(&self) -> Option<&ConstExpr<'ast>> {
         self.discriminant.get()
     }
+
+    /// The evaluated value of the discriminant. Unlike [`discriminant()`](Self::discriminant),
+    /// this also returns a value for variants with an implicit discriminant:
+    ///
+    /// ```
+    /// enum Foo {
+    ///     A,      // value: 0
+    ///     B = 7,  // value: 7
+    ///     C,      // value: 8
+    /// }
+    /// ```
+    ///
+    /// This returns [`None`], if the value could not be evaluated.
+    pub fn discriminant_value(&self) -> Option<i128> {
+        with_cx(self, |cx| cx.variant_discriminant(self.id))
+    }
 }
 
 impl<'ast> HasSpan<'ast> for EnumVariant<'ast> {
@@ -178,6 +198,7 @@ impl<'ast> EnumVariant<'ast> {
         span: SpanId,
         kind: AdtKind<'ast>,
         discriminant: Option<ConstExpr<'ast>>,
+        attrs: &'ast [Attribute<'ast>],
     ) -> Self {
         Self {
             id,
@@ -185,6 +206,7 @@ impl<'ast> EnumVariant<'ast> {
             span,
             kind,
             discriminant: discriminant.into(),
+            attrs: attrs.into(),
         }
     }
 }
@@ -289,6 +311,7 @@ pub struct ItemField<'ast> {
     ident: SymbolId,
     ty: TyKind<'ast>,
     span: SpanId,
+    attrs: FfiSlice<'ast, Attribute<'ast>>,
 }
 
 impl<'ast> ItemField<'ast> {
@@ -309,7 +332,10 @@ impl<'ast> ItemField<'ast> {
         self.ty
     }
 
-    // FIXME(xFrednet): Add `fn attrs() -> ??? {}`, see rust-marker/marker#51
+    /// The [`Attribute`]s attached to this field.
+    pub fn attrs(&self) -> &[Attribute<'ast>] {
+        self.attrs.get()
+    }
 }
 
 impl<'ast> HasSpan<'ast> for ItemField<'ast> {
@@ -322,13 +348,21 @@ crate::common::impl_identifiable_for!(ItemField<'ast>);
 
 #[cfg(feature = "driver-api")]
 impl<'ast> ItemField<'ast> {
-    pub fn new(id: FieldId, vis: Visibility<'ast>, ident: SymbolId, ty: TyKind<'ast>, span: SpanId) -> Self {
+    pub fn new(
+        id: FieldId,
+        vis: Visibility<'ast>,
+        ident: SymbolId,
+        ty: TyKind<'ast>,
+        span: SpanId,
+        attrs: &'ast [Attribute<'ast>],
+    ) -> Self {
         Self {
             id,
             vis,
             ident,
             ty,
             span,
+            attrs: attrs.into(),
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::{ast::ty::TyKind, common::BodyId, ffi::FfiOption};
+use crate::{ast::ty::TyKind, common::BodyId, context::with_cx, ffi::FfiOption, sem::ConstValue};
 
 use super::CommonItemData;
 
@@ -25,6 +25,17 @@ impl<'ast> ConstItem<'ast> {
     pub fn body_id(&self) -> Option<BodyId> {
         self.body_id.copy()
     }
+
+    /// The value of this constant, as computed by the const evaluation of the
+    /// driver. For the example above, this would be the integer value `0xcafe`.
+    ///
+    /// `None` is returned, if the constant can't be evaluated, for example,
+    /// because it depends on generic parameters. Values which aren't
+    /// represented in the API yet, will have the
+    /// [`ConstValueKind::Unstable`](crate::sem::ConstValueKind::Unstable) kind.
+    pub fn value(&self) -> Option<&'ast ConstValue<'ast>> {
+        with_cx(self, |cx| cx.item_const_value(self.data.id))
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -1,5 +1,5 @@
 use crate::{
-    ast::{generic::GenericParams, pat::PatKind, ty::TyKind},
+    ast::{generic::GenericParams, pat::PatKind, ty::TyKind, AstPathTarget},
     common::{Abi, BodyId, Constness, Safety, SpanId, Syncness},
     ffi::{FfiOption, FfiSlice},
 };
@@ -48,6 +48,7 @@ pub struct FnItem<'ast> {
     is_extern: bool,
     has_self: bool,
     abi: Abi,
+    context: FnContext,
     params: FfiSlice<'ast, FnParam<'ast>>,
     return_ty: FfiOption<TyKind<'ast>>,
     body_id: FfiOption<BodyId>,
@@ -100,6 +101,13 @@ impl<'ast> FnItem<'ast> {
         self.abi
     }
 
+    /// Returns the [`FnContext`] of this function. This can be used to check
+    /// if the function is an associated item and in which kind of block it
+    /// was declared.
+    pub fn context(&self) -> FnContext {
+        self.context
+    }
+
     /// Returns `true`, if this callable has a specified `self` argument. The
     /// type of `self` can be retrieved from the first element of
     /// [`params()`](`Self::params`).
@@ -118,6 +126,23 @@ impl<'ast> FnItem<'ast> {
     pub fn return_ty(&self) -> Option<&TyKind<'ast>> {
         self.return_ty.get()
     }
+
+    /// Returns `true`, if the specified return type is an `impl Trait` type,
+    /// like `-> impl Iterator<Item = u32>`.
+    pub fn returns_impl_trait(&self) -> bool {
+        matches!(self.return_ty(), Some(TyKind::ImplTrait(_)))
+    }
+
+    /// Returns `true`, if the specified return type is `Self`. This can only be
+    /// the case for associated functions inside trait definitions and impl
+    /// blocks. Types named explicitly, even if they are the same as `Self`, will
+    /// return `false`.
+    pub fn returns_self(&self) -> bool {
+        matches!(
+            self.return_ty(),
+            Some(TyKind::Path(path)) if matches!(path.path().resolve(), AstPathTarget::SelfTy(_))
+        )
+    }
 }
 
 #[cfg(feature = "driver-api")]
@@ -132,6 +157,7 @@ impl<'ast> FnItem<'ast> {
         is_extern: bool,
         has_self: bool,
         abi: Abi,
+        context: FnContext,
         params: &'ast [FnParam<'ast>],
         return_ty: Option<TyKind<'ast>>,
         body: Option<BodyId>,
@@ -145,6 +171,7 @@ impl<'ast> FnItem<'ast> {
             is_extern,
             has_self,
             abi,
+            context,
             params: params.into(),
             return_ty: return_ty.into(),
             body_id: body.into(),
@@ -152,6 +179,62 @@ impl<'ast> FnItem<'ast> {
     }
 }
 
+/// The context in which a [`FnItem`] was declared, like:
+///
+/// ```
+/// // A free function
+/// fn free() {}
+///
+/// # pub struct SomeItem;
+/// impl SomeItem {
+///     // An associated function in an inherent impl block
+///     fn inherent() {}
+/// }
+///
+/// pub trait SomeTrait {
+///     // Associated functions in a trait declaration, with and
+///     // without a default implementation
+///     fn required();
+///     fn provided() {}
+/// }
+///
+/// impl SomeTrait for SomeItem {
+///     // An associated function in a trait impl block
+///     fn required() {}
+/// }
+///
+/// extern "C" {
+///     // A function in an extern block
+///     fn external();
+/// }
+/// ```
+///
+/// Functions in trait declarations can be distinguished by their body. Default
+/// implementations have a [`BodyId`], which can be retrieved with [`FnItem::body_id`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FnContext {
+    /// A free function, declared in a module or a block.
+    Free,
+    /// A function declared in an `extern` block.
+    Extern,
+    /// An associated function, declared in an inherent impl block, like `impl Foo {}`.
+    InherentImpl,
+    /// An associated function, declared in a trait, like `trait Foo {}`.
+    TraitDecl,
+    /// An associated function, declared in a trait impl block, like `impl Foo for Bar {}`.
+    TraitImpl,
+}
+
+impl FnContext {
+    /// Returns `true`, if the function is an associated item of a trait or
+    /// an impl block.
+    pub fn is_assoc(self) -> bool {
+        matches!(self, Self::InherentImpl | Self::TraitDecl | Self::TraitImpl)
+    }
+}
+
 /// A parameter for a [`FnItem`], like:
 ///
 /// ```
@@ -1,4 +1,4 @@
-use crate::ffi::FfiSlice;
+use crate::{common::SpanId, context::with_cx, ffi::FfiSlice, span::Span};
 
 use super::{CommonItemData, ItemKind};
 
@@ -19,6 +19,7 @@ use super::{CommonItemData, ItemKind};
 #[cfg_attr(feature = "driver-api", derive(typed_builder::TypedBuilder))]
 pub struct ModItem<'ast> {
     data: CommonItemData<'ast>,
+    inner_span: SpanId,
     #[cfg_attr(feature = "driver-api", builder(setter(into)))]
     items: FfiSlice<'ast, ItemKind<'ast>>,
 }
@@ -29,4 +30,19 @@ impl<'ast> ModItem<'ast> {
     pub fn items(&self) -> &[ItemKind<'ast>] {
         self.items.get()
     }
+
+    /// The [`Span`] of the module content, without the `mod` keyword and name.
+    /// For modules declared as `mod name;`, this span belongs to the file of
+    /// the module, while [`HasSpan::span`](crate::span::HasSpan::span) belongs
+    /// to the declaration in the parent module.
+    pub fn inner_span(&self) -> &Span<'ast> {
+        with_cx(self, |cx| cx.span(self.inner_span))
+    }
+
+    /// Returns the path of the file, which contains the items of this module.
+    /// For inline modules, like `mod name { ... }`, this is the file of the
+    /// parent module. See [`Span::source_file`] for more information.
+    pub fn source_file(&self) -> Option<&'ast str> {
+        self.inner_span().source_file()
+    }
 }
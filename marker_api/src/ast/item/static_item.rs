@@ -1,7 +1,9 @@
 use crate::{
     ast::ty::TyKind,
     common::{BodyId, Mutability},
+    context::with_cx,
     ffi::FfiOption,
+    sem::ConstValue,
 };
 
 use super::CommonItemData;
@@ -12,6 +14,7 @@ use super::CommonItemData;
 /// static mut LEVELS: u32 = 0;
 /// // `name()` -> `LEVELS`
 /// // `mutability()` -> `Mutability::Mut`
+/// // `is_mut()` -> `true`
 /// // `ty()` -> _Ty of u32_
 /// // `body_id()` -> _BodyId of `0`_
 /// ```
@@ -34,6 +37,12 @@ impl<'ast> StaticItem<'ast> {
         self.mutability
     }
 
+    /// Returns `true`, if this is a `static mut` item. This is a shorthand for
+    /// `item.mutability().is_mut()`.
+    pub fn is_mut(&self) -> bool {
+        self.mutability.is_mut()
+    }
+
     /// The defined type of this static item
     pub fn ty(&self) -> TyKind<'ast> {
         self.ty
@@ -43,6 +52,13 @@ impl<'ast> StaticItem<'ast> {
     pub fn body_id(&self) -> Option<BodyId> {
         self.body_id.copy()
     }
+
+    /// The initial value of this static item, as computed by the const evaluation
+    /// of the driver. See [`ConstItem::value`](crate::ast::ConstItem::value) for
+    /// more information.
+    pub fn value(&self) -> Option<&'ast ConstValue<'ast>> {
+        with_cx(self, |cx| cx.item_const_value(self.data.id))
+    }
 }
 
 #[cfg(feature = "driver-api")]
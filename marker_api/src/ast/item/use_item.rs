@@ -1,4 +1,4 @@
-use crate::ast::AstPath;
+use crate::ast::{AstPath, AstPathTarget};
 
 use super::CommonItemData;
 
@@ -30,6 +30,7 @@ use super::CommonItemData;
 pub struct UseItem<'ast> {
     data: CommonItemData<'ast>,
     use_path: AstPath<'ast>,
+    target: AstPathTarget,
     use_kind: UseKind,
 }
 
@@ -54,6 +55,19 @@ impl<'ast> UseItem<'ast> {
         &self.use_path
     }
 
+    /// This function resolves the target of the [`use_path()`](Self::use_path).
+    /// A path can import nodes from multiple namespaces at once, like a tuple
+    /// struct and its constructor. In these cases, the first resolvable target
+    /// is returned. For glob imports, this is the module or enum that the nodes
+    /// are imported from.
+    ///
+    /// Modules and functions are resolved to [`AstPathTarget::Item`]. Targets,
+    /// like macros, which are not represented in Marker's AST, are returned as
+    /// [`AstPathTarget::Unresolved`].
+    pub fn resolve(&self) -> AstPathTarget {
+        self.target
+    }
+
     pub fn is_glob(&self) -> bool {
         matches!(self.use_kind, UseKind::Glob)
     }
@@ -61,10 +75,11 @@ impl<'ast> UseItem<'ast> {
 
 #[cfg(feature = "driver-api")]
 impl<'ast> UseItem<'ast> {
-    pub fn new(data: CommonItemData<'ast>, use_path: AstPath<'ast>, use_kind: UseKind) -> Self {
+    pub fn new(data: CommonItemData<'ast>, use_path: AstPath<'ast>, target: AstPathTarget, use_kind: UseKind) -> Self {
         Self {
             data,
             use_path,
+            target,
             use_kind,
         }
     }
@@ -1,4 +1,9 @@
-use crate::{common::SpanId, private::Sealed, span::Span};
+use crate::{
+    common::{PatId, SpanId},
+    private::Sealed,
+    sem,
+    span::Span,
+};
 
 use std::{fmt::Debug, marker::PhantomData};
 
@@ -36,6 +41,16 @@ pub use wildcard_pat::*;
 pub trait PatData<'ast>: Debug + Sealed {
     /// Returns the [`Span`] of this pattern.
     fn span(&self) -> &Span<'ast>;
+
+    /// Returns the semantic type of this pattern, as determined by the type
+    /// checking of the surrounding body. For an [`IdentPat`], this is the type
+    /// of the bound variable. A binding by reference, like `ref x`, or through
+    /// default binding modes, will therefore have a reference type.
+    ///
+    /// Parameters of trait functions without a default body, are resolved
+    /// to the type in the function signature. An unstable type is returned for
+    /// patterns without a type, like the [`RestPat`] in `(a, .., b)`.
+    fn ty(&self) -> sem::TyKind<'ast>;
 }
 
 #[repr(C)]
@@ -90,6 +105,7 @@ pub enum PatKind<'ast> {
 
 impl<'ast> PatKind<'ast> {
     impl_pat_data_fn!(span() -> &Span<'ast>);
+    impl_pat_data_fn!(ty() -> sem::TyKind<'ast>);
 }
 
 macro_rules! impl_pat_data_fn {
@@ -120,14 +136,16 @@ struct CommonPatData<'ast> {
     /// don't need it. Otherwise, we might need to declare this field in each
     /// pattern.
     _lifetime: PhantomData<&'ast ()>,
+    id: PatId,
     span: SpanId,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> CommonPatData<'ast> {
-    pub fn new(span: SpanId) -> Self {
+    pub fn new(id: PatId, span: SpanId) -> Self {
         Self {
             _lifetime: PhantomData,
+            id,
             span,
         }
     }
@@ -139,6 +157,10 @@ macro_rules! impl_pat_data {
             fn span(&self) -> &crate::span::Span<'ast> {
                 $crate::context::with_cx(self, |cx| cx.span(self.data.span))
             }
+
+            fn ty(&self) -> $crate::sem::TyKind<'ast> {
+                $crate::context::with_cx(self, |cx| cx.pat_ty(self.data.id))
+            }
         }
 
         impl<'ast> From<&'ast $self_ty> for $crate::ast::pat::PatKind<'ast> {
@@ -34,6 +34,24 @@ impl<'ast> IdentPat<'ast> {
         self.is_ref
     }
 
+    /// Returns the binding mode, declared by the `ref` and `mut` keywords of this
+    /// pattern. This is a combination of [`is_ref`](Self::is_ref) and
+    /// [`mutability`](Self::mutability).
+    ///
+    /// The binding mode is the one written in the source code. Bindings which
+    /// are references due to [default binding modes], like `x` in
+    /// `let Some(x) = &Some(1)`, have the [`BindingMode::Value`] mode.
+    ///
+    /// [default binding modes]: https://doc.rust-lang.org/reference/patterns.html#binding-modes
+    pub fn binding_mode(&self) -> BindingMode {
+        match (self.is_ref, self.mutability) {
+            (false, Mutability::Unmut) => BindingMode::Value,
+            (false, Mutability::Mut) => BindingMode::MutValue,
+            (true, Mutability::Unmut) => BindingMode::Ref,
+            (true, Mutability::Mut) => BindingMode::RefMut,
+        }
+    }
+
     /// The pattern, if the variable originates from a binding to a pattern.
     /// ```
     /// # let expr = 10;
@@ -53,6 +71,21 @@ impl<'ast> IdentPat<'ast> {
 
 super::impl_pat_data!(IdentPat<'ast>, Ident);
 
+/// The binding mode of an [`IdentPat`], declared by the `ref` and `mut` keywords.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BindingMode {
+    /// The value is bound to the variable, like `x`.
+    Value,
+    /// The value is bound to a mutable variable, like `mut x`.
+    MutValue,
+    /// A reference to the value is bound, like `ref x`.
+    Ref,
+    /// A mutable reference to the value is bound, like `ref mut x`.
+    RefMut,
+}
+
 #[cfg(feature = "driver-api")]
 impl<'ast> IdentPat<'ast> {
     pub fn new(
@@ -126,7 +126,7 @@ impl<'ast> LetStmt<'ast> {
     /// This returns the optional `else` expression of the let statement.
     ///
     /// `els` is an abbreviation for `else`, which is a reserved keyword in Rust.
-    pub fn els(&self) -> Option<ExprKind> {
+    pub fn els(&self) -> Option<ExprKind<'ast>> {
         self.els.copy()
     }
 }
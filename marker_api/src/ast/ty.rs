@@ -1,8 +1,12 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
+use crate::context::with_cx;
 use crate::span::{HasSpan, Span};
-use crate::{common::SpanId, private::Sealed};
+use crate::{
+    common::{SpanId, SynTyId},
+    private::Sealed,
+};
 
 mod other_ty;
 mod prim_ty;
@@ -159,16 +163,33 @@ impl<'ast> TyKind<'ast> {
 }
 
 impl<'ast> TyKind<'ast> {
-    impl_syn_ty_data_fn!(span() -> &Span<'ast>);
+    /// Resolves this syntactic type to its semantic representation. This can
+    /// be used to see through type aliases or to check which type an
+    /// [`impl Trait`](ImplTraitTy) parameter stands for.
+    ///
+    /// Only types in item signatures, like the types of struct fields or
+    /// function parameters, can be resolved. This returns `None` for types
+    /// inside bodies, like the type of a `let` statement, and for types which
+    /// contain inferred types, like `Vec<_>`.
+    #[must_use]
+    pub fn resolve(&self) -> Option<crate::sem::TyKind<'ast>> {
+        with_cx(self, |cx| cx.resolve_syn_ty(self.id()))
+    }
+}
+
+impl<'ast> TyKind<'ast> {
+    impl_syn_ty_data_fn!(pub span() -> &Span<'ast>);
+    impl_syn_ty_data_fn!(pub id() -> SynTyId);
 }
 
 crate::span::impl_spanned_for!(TyKind<'ast>);
+crate::common::impl_identifiable_for!(TyKind<'ast>);
 
 /// Until [trait upcasting](https://github.com/rust-lang/rust/issues/65991) has been implemented
 /// and stabilized we need this to call [`SynTyData`] functions for every [`SynTyKind`].
 macro_rules! impl_syn_ty_data_fn {
-    ($method:ident () -> $return_ty:ty) => {
-        impl_syn_ty_data_fn!($method() -> $return_ty,
+    ($vis:vis $method:ident () -> $return_ty:ty) => {
+        impl_syn_ty_data_fn!($vis $method() -> $return_ty,
             Bool, Num, Text, Never,
             Tuple, Array, Slice,
             Ref, RawPtr, FnPtr,
@@ -176,8 +197,8 @@ macro_rules! impl_syn_ty_data_fn {
             Inferred, Path
         );
     };
-    ($method:ident () -> $return_ty:ty $(, $item:ident)+) => {
-        pub fn $method(&self) -> $return_ty {
+    ($vis:vis $method:ident () -> $return_ty:ty $(, $item:ident)+) => {
+        $vis fn $method(&self) -> $return_ty {
             match self {
                 $(TyKind::$item(data) => data.$method(),)*
             }
@@ -192,14 +213,16 @@ use impl_syn_ty_data_fn;
 #[cfg_attr(feature = "driver-api", visibility::make(pub))]
 pub(crate) struct CommonSynTyData<'ast> {
     _lifetime: PhantomData<&'ast ()>,
+    id: SynTyId,
     span: SpanId,
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> CommonSynTyData<'ast> {
-    pub fn new_syntactic(span: SpanId) -> Self {
+    pub fn new_syntactic(id: SynTyId, span: SpanId) -> Self {
         Self {
             _lifetime: PhantomData,
+            id,
             span,
         }
     }
@@ -215,6 +238,12 @@ macro_rules! impl_ty_data {
 
         $crate::span::impl_has_span_via_field!($self_ty, data.span);
 
+        impl<'ast> $self_ty {
+            pub(crate) fn id(&self) -> $crate::common::SynTyId {
+                self.data.id
+            }
+        }
+
         impl<'ast> $crate::private::Sealed for $self_ty {}
 
         impl<'ast> From<&'ast $self_ty> for $crate::ast::ty::TyKind<'ast> {
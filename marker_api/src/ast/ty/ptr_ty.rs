@@ -24,6 +24,16 @@ impl<'ast> RefTy<'ast> {
         self.lifetime.get().is_some()
     }
 
+    /// Returns the lifetime of this reference, if it has been specified.
+    ///
+    /// Elided lifetimes, like in `&T`, return [`None`]. An explicit anonymous
+    /// lifetime, like in `&'_ T`, is distinguishable from an elided one. It
+    /// returns a [`Lifetime`] for which [`Lifetime::is_infer`] is `true`.
+    /// The `'static` lifetime can be checked with [`Lifetime::is_static`].
+    pub fn lifetime(&self) -> Option<&Lifetime<'ast>> {
+        self.lifetime.get()
+    }
+
     pub fn mutability(&self) -> Mutability {
         self.mutability
     }
@@ -208,6 +208,28 @@ pub enum Level {
     Forbid,
 }
 
+/// The category of a lint. It describes, what kind of problems the lint detects.
+/// The category is optional, it can be used by tools to group lints.
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum LintCategory {
+    /// Lints for code that is outright wrong or useless.
+    Correctness,
+    /// Lints for code that is most likely wrong or useless.
+    Suspicious,
+    /// Lints for code that should be written in a more idiomatic way.
+    Style,
+    /// Lints for code that does something simple in a complex way.
+    Complexity,
+    /// Lints for code that can be written to run faster.
+    Perf,
+    /// Lints which are rather strict or might have false positives.
+    Pedantic,
+    /// Lints which restrict the usage of language and library features.
+    Restriction,
+}
+
 /// FIXME(xFrednet): These settings should be working now, but are still limited
 /// due to the limited [`Span`](crate::span::Span) implementation. Ideally, I would
 /// also like more options, like a `Local` variant that only lints in local marcos.
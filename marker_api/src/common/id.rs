@@ -106,6 +106,15 @@ new_id! {
     pub ExprId: u64
 }
 
+new_id! {
+    /// **Unstable**
+    ///
+    /// This id is used to identify patterns. This type is only intended for internal
+    /// use. Lint crates get the pattern nodes directly.
+    #[cfg_attr(feature = "driver-api", visibility::make(pub))]
+    pub(crate) PatId: u64
+}
+
 new_id! {
     /// **Unstable**
     ///
@@ -151,6 +160,13 @@ new_id! {
     pub(crate) DriverTyId: u64
 }
 
+new_id! {
+    /// This ID uniquely identifies a syntactic type during linting. It allows lints to be
+    /// emitted on syntactic types and the driver to resolve them to their semantic
+    /// representation.
+    pub SynTyId: u64
+}
+
 new_id! {
     /// This ID uniquely identifies a statement during linting.
     pub StmtId: u64
@@ -166,6 +182,8 @@ pub enum NodeId {
     Body(BodyId),
     Field(FieldId),
     Variant(VariantId),
+    Ty(SynTyId),
+    Generic(GenericId),
 }
 
 macro_rules! impl_into_node_id_for {
@@ -190,6 +208,8 @@ impl_into_node_id_for!(Stmt, StmtId);
 impl_into_node_id_for!(Body, BodyId);
 impl_into_node_id_for!(Field, FieldId);
 impl_into_node_id_for!(Variant, VariantId);
+impl_into_node_id_for!(Ty, SynTyId);
+impl_into_node_id_for!(Generic, GenericId);
 
 pub trait HasNodeId: Sealed {
     /// Returns the [`NodeId`] of the identifiable node
@@ -8,11 +8,14 @@
 use std::{cell::RefCell, mem::transmute};
 
 use crate::{
-    common::{ExpnId, ExprId, ItemId, Level, MacroReport, SpanId, SymbolId, TyDefId},
+    ast::{AstPathTarget, CtorExpr, ExprData, ExprKind, MethodExpr},
+    common::{
+        DriverTyId, ExpnId, ExprId, ItemId, Level, MacroReport, PatId, SpanId, SymbolId, SynTyId, TyDefId, VariantId,
+    },
     diagnostic::{Diagnostic, DiagnosticBuilder, EmissionNode},
     ffi,
-    sem::TyKind,
-    span::{ExpnInfo, FileInfo, FilePos, Span, SpanPos, SpanSource},
+    sem::{AdtField, AdtVariant, ConstValue, TyKind},
+    span::{ExpnInfo, FileInfo, FilePos, MacroFrame, Span, SpanPos, SpanSource},
     Lint,
 };
 
@@ -105,6 +108,35 @@ impl<'ast> MarkerContext<'ast> {
         &self.ast
     }
 
+    /// Returns the effective [`Level`] of the given lint at the given node.
+    ///
+    /// The returned level already accounts for lint attributes, like `#[allow]`
+    /// and `#[deny]`, on the node and its parents, as well as for command-line
+    /// flags. Lints which require expensive analysis can use this, to return
+    /// early if the lint is allowed at the current node:
+    ///
+    /// ```
+    /// # use marker_api::prelude::*;
+    /// # use marker_api::common::Level;
+    /// # marker_api::declare_lint!{
+    /// #     /// Dummy
+    /// #     EXPENSIVE_LINT,
+    /// #     Warn,
+    /// # }
+    /// fn check_expr<'ast>(cx: &MarkerContext<'ast>, expr: ExprKind<'ast>) {
+    ///     if cx.lint_level(EXPENSIVE_LINT, expr) == Level::Allow {
+    ///         return;
+    ///     }
+    ///
+    ///     // Heavy analysis...
+    /// }
+    /// ```
+    ///
+    /// [`MarkerContext::emit_lint`] performs this check automatically.
+    pub fn lint_level(&self, lint: &'static Lint, node: impl EmissionNode<'ast>) -> Level {
+        self.ast().lint_level_at(lint, node)
+    }
+
     /// This function is used to emit a lint.
     ///
     /// Every lint emission, is bound to one specific node in the AST. This
@@ -213,16 +245,62 @@ impl<'ast> MarkerContext<'ast> {
         node: impl EmissionNode<'ast>,
         msg: impl Into<String>,
     ) -> DiagnosticBuilder<'ast> {
-        let id = node.node_id();
-        let span = node.span();
-        if matches!(lint.report_in_macro, MacroReport::No) && span.is_from_expansion() {
+        let Some(level) = self.emission_level(lint, &node) else {
             return DiagnosticBuilder::dummy();
-        }
-        if self.ast().lint_level_at(lint, &node) == Level::Allow {
+        };
+
+        DiagnosticBuilder::new(lint, level, node.node_id(), msg.into(), node.span().clone())
+    }
+
+    /// This function works like [`MarkerContext::emit_lint`], but only emits the
+    /// first diagnostic for every combination of `lint` and `key`. Later calls with
+    /// the same combination return a dummy [`DiagnosticBuilder`], which does nothing.
+    ///
+    /// This is useful for lints, which can find the same problem multiple times,
+    /// for example, once for every usage of a type. Keys are compared by value.
+    /// A key is only recorded, once the diagnostic has actually been emitted.
+    /// Emissions for allowed nodes, excluded files or nodes suppressed by a
+    /// `// marker:ignore` comment therefore don't prevent later emissions. Keys
+    /// are tracked during the linting of one crate.
+    ///
+    /// ```
+    /// # use marker_api::prelude::*;
+    /// # marker_api::declare_lint!{
+    /// #     /// Dummy
+    /// #     LINT,
+    /// #     Warn,
+    /// # }
+    /// # fn value_provider<'ast>(cx: &MarkerContext<'ast>, node: ExprKind<'ast>, ty_name: &str) {
+    ///     // Only the first usage of each type is reported
+    ///     cx.emit_lint_once(LINT, node, ty_name, format!("usage of `{ty_name}`"));
+    /// # }
+    /// ```
+    pub fn emit_lint_once(
+        &self,
+        lint: &'static Lint,
+        node: impl EmissionNode<'ast>,
+        key: impl Into<String>,
+        msg: impl Into<String>,
+    ) -> DiagnosticBuilder<'ast> {
+        let Some(level) = self.emission_level(lint, &node) else {
+            return DiagnosticBuilder::dummy();
+        };
+
+        let key = key.into();
+        if (self.callbacks.is_key_emitted)(self.callbacks.data, lint, key.as_str().into()) {
             return DiagnosticBuilder::dummy();
         }
 
-        DiagnosticBuilder::new(lint, id, msg.into(), span.clone())
+        DiagnosticBuilder::new(lint, level, node.node_id(), msg.into(), node.span().clone()).with_key(key)
+    }
+
+    /// Returns the level of the lint at the given node, if a diagnostic would be
+    /// emitted, based on the lint level and the [`MacroReport`] setting.
+    fn emission_level(&self, lint: &'static Lint, node: &impl EmissionNode<'ast>) -> Option<Level> {
+        if matches!(lint.report_in_macro, MacroReport::No) && node.span().is_from_expansion() {
+            return None;
+        }
+        Some(self.lint_level(lint, node)).filter(|level| *level != Level::Allow)
     }
 
     pub(crate) fn emit_diagnostic<'a>(&self, diag: &'a Diagnostic<'a, 'ast>) {
@@ -253,6 +331,266 @@ impl<'ast> MarkerContext<'ast> {
     pub fn resolve_ty_ids(&self, path: &str) -> &[TyDefId] {
         (self.callbacks.resolve_ty_ids)(self.callbacks.data, path.into()).get()
     }
+
+    /// This function tries to resolve the given path to the [`ItemId`]s of the
+    /// corresponding functions. Free functions are resolved by their module
+    /// path, like `std::mem::transmute`. Associated functions are resolved
+    /// by the path of their trait or type, like `std::vec::Vec::new`, or
+    /// `core::clone::Clone::clone`. Associated functions of types are taken
+    /// from inherent impl blocks only.
+    ///
+    /// The slice might be empty if the path could not be resolved, or if the
+    /// path doesn't point to a function. Like for [`MarkerContext::resolve_ty_ids`],
+    /// multiple ids can be returned, if there are multiple crates with different
+    /// versions in the dependency tree. The slice should not be stored across
+    /// `check_*` calls.
+    ///
+    /// The ids can be compared with the targets of paths and method calls:
+    /// ```ignore
+    /// if let ExprKind::Call(call) = expr
+    ///     && let ExprKind::Path(path) = call.func()
+    ///     && let AstPathTarget::Item(id) = path.path().resolve()
+    ///     && cx.resolve_fn_ids("std::mem::transmute").contains(&id)
+    /// {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn resolve_fn_ids(&self, path: &str) -> &[ItemId] {
+        (self.callbacks.resolve_fn_ids)(self.callbacks.data, path.into()).get()
+    }
+
+    /// Returns the [`ItemId`]s of all impls of the trait, identified by the
+    /// given [`ItemId`]. The trait id can be retrieved from a [`TraitItem`] or
+    /// from the [`TraitRef`] of an [`ImplItem`]:
+    ///
+    /// ```ignore
+    /// if let ItemKind::Trait(trait_item) = item {
+    ///     for impl_id in cx.impls_of_trait(trait_item.id()) {
+    ///         if let Some(ItemKind::Impl(impl_item)) = cx.ast().item(*impl_id) {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Impls from other crates are included, if the driver has access to them.
+    /// Their nodes can't be retrieved with [`AstMap::item`], since the AST
+    /// is only available for the current crate. An empty slice is returned, if
+    /// the id doesn't belong to a trait.
+    ///
+    /// [`TraitItem`]: crate::ast::TraitItem
+    /// [`TraitRef`]: crate::ast::TraitRef
+    /// [`ImplItem`]: crate::ast::ImplItem
+    /// [`AstMap::item`]: crate::context::AstMap::item
+    pub fn impls_of_trait(&self, trait_id: ItemId) -> &[ItemId] {
+        (self.callbacks.impls_of_trait)(self.callbacks.data, trait_id).get()
+    }
+
+    /// Returns the [`ItemId`]s of all inherent and trait impls, which have the
+    /// type, identified by the given [`TyDefId`], as their `Self` type. Blanket
+    /// impls, like `impl<T> Trait for T`, and impls for references to the type
+    /// are not included.
+    ///
+    /// Like for [`MarkerContext::impls_of_trait`], impls from other crates
+    /// are included, if the driver has access to them. An empty slice is
+    /// returned, if the id doesn't belong to a struct, enum or union.
+    pub fn impls_for_type(&self, ty_id: TyDefId) -> &[ItemId] {
+        (self.callbacks.impls_for_type)(self.callbacks.data, ty_id).get()
+    }
+
+    /// This function checks if the given semantic type implements the trait,
+    /// identified by the given [`TyDefId`]. The `generics` are the generic
+    /// arguments of the trait, without the `Self` type. The trait id can be
+    /// retrieved with [`MarkerContext::resolve_ty_ids`]:
+    ///
+    /// ```ignore
+    /// let ty = expr.ty();
+    /// let implements_debug = cx
+    ///     .resolve_ty_ids("core::fmt::Debug")
+    ///     .iter()
+    ///     .any(|trait_id| cx.implements_trait(ty, *trait_id, &[]));
+    /// ```
+    ///
+    /// The check is conservative, `false` is returned if:
+    /// * The id doesn't belong to a trait
+    /// * The number of `generics` doesn't match the generic parameters of the trait
+    /// * The answer depends on generic parameters, like `T: Clone`, even if the bound is present on
+    ///   the surrounding item
+    pub fn implements_trait(&self, ty: TyKind<'ast>, trait_id: TyDefId, generics: &[TyKind<'ast>]) -> bool {
+        let generics: Vec<_> = generics.iter().map(|ty| ty.data().driver_id()).collect();
+        (self.callbacks.implements_trait)(
+            self.callbacks.data,
+            ty.data().driver_id(),
+            trait_id,
+            generics.as_slice().into(),
+        )
+    }
+
+    /// Returns `true`, if the given type implements [`Copy`]. This is a shorthand
+    /// for [`MarkerContext::implements_trait`] with the `Copy` trait and follows
+    /// the same conservative rules for generic parameters.
+    pub fn ty_is_copy(&self, ty: TyKind<'ast>) -> bool {
+        (self.callbacks.ty_is_copy)(self.callbacks.data, ty.data().driver_id())
+    }
+
+    /// Returns `true`, if the given type implements [`Sized`]. This is a shorthand
+    /// for [`MarkerContext::implements_trait`] with the `Sized` trait and follows
+    /// the same conservative rules for generic parameters.
+    pub fn ty_is_sized(&self, ty: TyKind<'ast>) -> bool {
+        (self.callbacks.ty_is_sized)(self.callbacks.data, ty.data().driver_id())
+    }
+
+    /// Returns `true`, if the given type is uninhabited, meaning that no value of
+    /// this type can exist. Examples are the never type `!`, enums without variants,
+    /// like [`Infallible`](std::convert::Infallible), and structs or tuples with an
+    /// uninhabited field, like `(u32, !)`.
+    ///
+    /// ```ignore
+    /// // Check if the error type of a `Result<T, E>` is uninhabited
+    /// if let sem::TyKind::Adt(adt) = ty {
+    ///     if let Some(sem::GenericArgKind::Ty(err_ty)) = adt.generics().args().get(1) {
+    ///         if cx.ty_is_uninhabited(*err_ty) {
+    ///             // The `Err` variant can never be constructed
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// An enum is only uninhabited, if all of its variants are, meaning that
+    /// `Result<T, !>` is inhabited, unless `T` is uninhabited. The check ignores
+    /// the visibility of fields. Types depending on generic parameters, unions and
+    /// `#[non_exhaustive]` enums from other crates are considered inhabited.
+    pub fn ty_is_uninhabited(&self, ty: TyKind<'ast>) -> bool {
+        (self.callbacks.ty_is_uninhabited)(self.callbacks.data, ty.data().driver_id())
+    }
+
+    /// Returns `true`, if the given expression diverges, meaning that its type is
+    /// the never type `!`. Control flow never continues after such an expression.
+    /// Examples are `return`, `break`, `loop {}` without a `break` and calls to
+    /// functions returning `!`, like [`panic!`] or [`std::process::exit`].
+    ///
+    /// Expressions with other uninhabited types, like
+    /// [`Infallible`](std::convert::Infallible), are not considered to diverge.
+    /// [`MarkerContext::ty_is_uninhabited`] can be used to check for those.
+    pub fn expr_diverges(&self, expr: ExprKind<'ast>) -> bool {
+        matches!(self.expr_ty(expr.id()), TyKind::Never(_))
+    }
+
+    /// Returns the size of the given type in bytes, as it's laid out in memory.
+    ///
+    /// ```ignore
+    /// if let ExprKind::Ctor(ctor) = expr {
+    ///     if cx.ty_size(expr.ty()).is_some_and(|size| size > 1024) {
+    ///         // The created value is quite large, maybe it should be boxed
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`None`] is returned, if the layout can't be computed. This is the case
+    /// for unsized types, like [`str`], and types depending on generic
+    /// parameters, like `Vec<T>`. The layout of Rust types is not stable and
+    /// can change between compiler versions.
+    pub fn ty_size(&self, ty: TyKind<'ast>) -> Option<u64> {
+        (self.callbacks.ty_size)(self.callbacks.data, ty.data().driver_id()).copy()
+    }
+
+    /// Returns the alignment of the given type in bytes. The same rules as for
+    /// [`MarkerContext::ty_size`] apply.
+    pub fn ty_align(&self, ty: TyKind<'ast>) -> Option<u64> {
+        (self.callbacks.ty_align)(self.callbacks.data, ty.data().driver_id()).copy()
+    }
+
+    /// This function resolves the method, called by the given [`MethodExpr`].
+    /// The returned [`ItemId`] identifies the function item of the called method.
+    /// For trait methods, this is the method declared in the trait, and not the
+    /// implementation of it.
+    ///
+    /// The returned id can be compared with the ids of other items, or be used
+    /// to request the item from the [`AstMap`], if it's part of the current crate:
+    ///
+    /// ```ignore
+    /// if let ExprKind::Method(method) = expr {
+    ///     if let Some(target) = cx.resolve_method_target(method) {
+    ///         if let Some(ItemKind::Fn(item)) = cx.ast().item(target) {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// `None` is returned, if the method call couldn't be resolved, for example,
+    /// due to a type error in the linted code. The resolution depends on the type
+    /// checking of the surrounding body, this function can therefore only be called
+    /// for method expressions inside bodies.
+    pub fn resolve_method_target(&self, expr: &MethodExpr<'_>) -> Option<ItemId> {
+        self.callbacks.call_resolve_method_target(expr.id())
+    }
+
+    /// This function returns the target, that will be constructed by the given
+    /// [`CtorExpr`]. The target is either an item, like a struct or union, an
+    /// enum variant, or the `Self` type of the surrounding item.
+    ///
+    /// Tuple structs and tuple variants can also be used as functions. Paths to
+    /// these constructor functions are already resolved to the constructed item
+    /// or variant, when the [`CtorExpr`] is created. This function only filters
+    /// out path targets, which can't be constructed. The following two
+    /// expressions will both return the same target:
+    ///
+    /// ```
+    /// //                             vvvv A tuple variant used as a function
+    /// let _ = [1, 2, 3].map(Option::Some);
+    /// //      vvvvvvvvvvvvvvv A constructor expression
+    /// let _ = Option::Some(4);
+    /// ```
+    ///
+    /// `None` is returned, if the target can't be resolved.
+    pub fn ctor_target(&self, expr: &CtorExpr<'_>) -> Option<AstPathTarget> {
+        match expr.path().resolve() {
+            target @ (AstPathTarget::Item(_) | AstPathTarget::Variant(_) | AstPathTarget::SelfTy(_)) => Some(target),
+            _ => None,
+        }
+    }
+
+    /// Returns the chain of macro expansions, which produced the given [`Span`].
+    /// The frames are ordered from the innermost expansion to the macro invoked
+    /// from the source file. The returned vector is empty, if the span doesn't
+    /// come from a macro expansion.
+    ///
+    /// ```
+    /// macro_rules! inner {
+    ///     () => {
+    ///         1 + 1
+    ///     };
+    /// }
+    /// macro_rules! outer {
+    ///     () => {
+    ///         inner!()
+    ///     };
+    /// }
+    ///
+    /// // The backtrace of the binary expression in `inner` is `[inner, outer]`
+    /// let _ = outer!();
+    /// ```
+    ///
+    /// This can be used to check if a node comes from a specific macro, at
+    /// any depth:
+    ///
+    /// ```ignore
+    /// let from_vec = cx.macro_backtrace(expr.span()).iter().any(|frame| frame.name() == "vec");
+    /// ```
+    pub fn macro_backtrace(&self, span: &Span<'ast>) -> Vec<MacroFrame<'ast>> {
+        let mut expn = match span.source() {
+            SpanSource::Macro(expn) => Some(expn),
+            _ => None,
+        };
+
+        let mut frames = vec![];
+        while let Some(info) = expn {
+            frames.push(MacroFrame::new(info));
+            expn = info.parent();
+        }
+        frames
+    }
 }
 
 impl<'ast> MarkerContext<'ast> {
@@ -260,6 +598,22 @@ impl<'ast> MarkerContext<'ast> {
         self.callbacks.call_expr_ty(expr)
     }
 
+    pub(crate) fn expr_adjusted_ty(&self, expr: ExprId) -> TyKind<'ast> {
+        (self.callbacks.expr_adjusted_ty)(self.callbacks.data, expr)
+    }
+
+    pub(crate) fn pat_ty(&self, pat: PatId) -> TyKind<'ast> {
+        (self.callbacks.pat_ty)(self.callbacks.data, pat)
+    }
+
+    pub(crate) fn resolve_call_target(&self, expr: ExprId) -> Option<ItemId> {
+        (self.callbacks.resolve_call_target)(self.callbacks.data, expr).copy()
+    }
+
+    pub(crate) fn is_union_field_access(&self, expr: ExprId) -> bool {
+        (self.callbacks.is_union_field_access)(self.callbacks.data, expr)
+    }
+
     // FIXME: This function should probably be removed in favor of a better
     // system to deal with spans. See rust-marker/marker#175
     pub(crate) fn span_snipped(&self, span: &Span<'ast>) -> Option<&'ast str> {
@@ -286,9 +640,28 @@ impl<'ast> MarkerContext<'ast> {
         self.callbacks.call_symbol_str(sym)
     }
 
-    #[allow(unused)] // Will be used later(or removed)
-    pub(crate) fn resolve_method_target(&self, expr: ExprId) -> ItemId {
-        self.callbacks.resolve_method_target(expr)
+    pub(crate) fn item_const_value(&self, item: ItemId) -> Option<&'ast ConstValue<'ast>> {
+        (self.callbacks.item_const_value)(self.callbacks.data, item).copy()
+    }
+
+    pub(crate) fn const_expr_value(&self, expr: ExprId) -> Option<&'ast ConstValue<'ast>> {
+        (self.callbacks.const_expr_value)(self.callbacks.data, expr).copy()
+    }
+
+    pub(crate) fn resolve_syn_ty(&self, ty: SynTyId) -> Option<TyKind<'ast>> {
+        (self.callbacks.resolve_syn_ty)(self.callbacks.data, ty).into()
+    }
+
+    pub(crate) fn variant_discriminant(&self, variant: VariantId) -> Option<i128> {
+        (self.callbacks.variant_discriminant)(self.callbacks.data, variant).copy()
+    }
+
+    pub(crate) fn adt_fields(&self, ty: DriverTyId) -> &'ast [AdtField<'ast>] {
+        (self.callbacks.adt_fields)(self.callbacks.data, ty).get()
+    }
+
+    pub(crate) fn adt_variants(&self, ty: DriverTyId) -> &'ast [AdtVariant<'ast>] {
+        (self.callbacks.adt_variants)(self.callbacks.data, ty).get()
     }
 }
 
@@ -316,12 +689,25 @@ struct MarkerContextCallbacks<'ast> {
 
     // Lint emission and information
     pub emit_diag: for<'a> extern "C" fn(&'ast MarkerContextData, &'a Diagnostic<'a, 'ast>),
+    pub is_key_emitted: extern "C" fn(&'ast MarkerContextData, &'static Lint, ffi::FfiStr<'_>) -> bool,
 
     // Public utility
     pub resolve_ty_ids: extern "C" fn(&'ast MarkerContextData, path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, TyDefId>,
+    pub resolve_fn_ids: extern "C" fn(&'ast MarkerContextData, path: ffi::FfiStr<'_>) -> ffi::FfiSlice<'ast, ItemId>,
+    pub impls_of_trait: extern "C" fn(&'ast MarkerContextData, ItemId) -> ffi::FfiSlice<'ast, ItemId>,
+    pub impls_for_type: extern "C" fn(&'ast MarkerContextData, TyDefId) -> ffi::FfiSlice<'ast, ItemId>,
+    pub implements_trait:
+        extern "C" fn(&'ast MarkerContextData, DriverTyId, TyDefId, ffi::FfiSlice<'_, DriverTyId>) -> bool,
+    pub ty_is_copy: extern "C" fn(&'ast MarkerContextData, DriverTyId) -> bool,
+    pub ty_is_sized: extern "C" fn(&'ast MarkerContextData, DriverTyId) -> bool,
+    pub ty_is_uninhabited: extern "C" fn(&'ast MarkerContextData, DriverTyId) -> bool,
+    pub ty_size: extern "C" fn(&'ast MarkerContextData, DriverTyId) -> ffi::FfiOption<u64>,
+    pub ty_align: extern "C" fn(&'ast MarkerContextData, DriverTyId) -> ffi::FfiOption<u64>,
 
     // Internal utility
     pub expr_ty: extern "C" fn(&'ast MarkerContextData, ExprId) -> TyKind<'ast>,
+    pub expr_adjusted_ty: extern "C" fn(&'ast MarkerContextData, ExprId) -> TyKind<'ast>,
+    pub pat_ty: extern "C" fn(&'ast MarkerContextData, PatId) -> TyKind<'ast>,
     pub span: extern "C" fn(&'ast MarkerContextData, SpanId) -> &'ast Span<'ast>,
     pub span_snippet: extern "C" fn(&'ast MarkerContextData, &Span<'ast>) -> ffi::FfiOption<ffi::FfiStr<'ast>>,
     pub span_source: extern "C" fn(&'ast MarkerContextData, &Span<'_>) -> SpanSource<'ast>,
@@ -329,7 +715,18 @@ struct MarkerContextCallbacks<'ast> {
         extern "C" fn(&'ast MarkerContextData, &FileInfo<'ast>, SpanPos) -> ffi::FfiOption<FilePos<'ast>>,
     pub span_expn_info: extern "C" fn(&'ast MarkerContextData, ExpnId) -> ffi::FfiOption<&'ast ExpnInfo<'ast>>,
     pub symbol_str: extern "C" fn(&'ast MarkerContextData, SymbolId) -> ffi::FfiStr<'ast>,
-    pub resolve_method_target: extern "C" fn(&'ast MarkerContextData, ExprId) -> ItemId,
+    pub resolve_method_target: extern "C" fn(&'ast MarkerContextData, ExprId) -> ffi::FfiOption<ItemId>,
+    pub resolve_call_target: extern "C" fn(&'ast MarkerContextData, ExprId) -> ffi::FfiOption<ItemId>,
+    pub is_union_field_access: extern "C" fn(&'ast MarkerContextData, ExprId) -> bool,
+    pub item_const_value: extern "C" fn(&'ast MarkerContextData, ItemId) -> ffi::FfiOption<&'ast ConstValue<'ast>>,
+    pub const_expr_value: extern "C" fn(&'ast MarkerContextData, ExprId) -> ffi::FfiOption<&'ast ConstValue<'ast>>,
+    pub resolve_syn_ty: extern "C" fn(&'ast MarkerContextData, SynTyId) -> ffi::FfiOption<TyKind<'ast>>,
+    // `i128` has no stable ABI, but the API and driver are always compiled
+    // with the same toolchain
+    #[allow(improper_ctypes_definitions)]
+    pub variant_discriminant: extern "C" fn(&'ast MarkerContextData, VariantId) -> ffi::FfiOption<i128>,
+    pub adt_fields: extern "C" fn(&'ast MarkerContextData, DriverTyId) -> ffi::FfiSlice<'ast, AdtField<'ast>>,
+    pub adt_variants: extern "C" fn(&'ast MarkerContextData, DriverTyId) -> ffi::FfiSlice<'ast, AdtVariant<'ast>>,
 }
 
 impl<'ast> MarkerContextCallbacks<'ast> {
@@ -346,8 +743,8 @@ impl<'ast> MarkerContextCallbacks<'ast> {
     fn call_symbol_str(&self, sym: SymbolId) -> &'ast str {
         (self.symbol_str)(self.data, sym).get()
     }
-    pub fn resolve_method_target(&self, expr: ExprId) -> ItemId {
-        (self.resolve_method_target)(self.data, expr)
+    fn call_resolve_method_target(&self, expr: ExprId) -> Option<ItemId> {
+        (self.resolve_method_target)(self.data, expr).copy()
     }
 }
 
@@ -65,6 +65,33 @@ impl<'ast> AstMap<'ast> {
             .unwrap_or_else(|| panic!("The requested item is unavailable (id = {id:?})"))
     }
 
+    /// Returns the child items of the module, identified by the given [`ItemId`].
+    /// This can be used to navigate the module tree, starting from any module.
+    ///
+    /// ```
+    /// # use marker_api::prelude::*;
+    /// # use marker_api::common::ItemId;
+    /// // Counts the public items of a module
+    /// fn count_pub_items<'ast>(cx: &'ast MarkerContext<'ast>, module: ItemId) -> usize {
+    ///     cx.ast()
+    ///         .item_children(module)
+    ///         .iter()
+    ///         .filter(|item| item.visibility().semantics().is_pub())
+    ///         .count()
+    /// }
+    /// ```
+    ///
+    /// An empty slice is returned, if the item is not a module or unavailable.
+    /// Checkout the documentation of [`AstMap`] for more information, when a node
+    /// might be unavailable, even if the given ID is valid. The items of traits,
+    /// impl and extern blocks can be accessed from the items themselves.
+    pub fn item_children(&self, id: ItemId) -> &'ast [ItemKind<'ast>] {
+        match self.item(id) {
+            Some(ItemKind::Mod(module)) => module.items(),
+            _ => &[],
+        }
+    }
+
     /// Returns the [`EnumVariant`] belonging to the given [`VariantId`], if available.
     ///
     /// Checkout the documentation of [`AstMap`] for more information, when a node
@@ -119,6 +146,30 @@ impl<'ast> AstMap<'ast> {
     pub fn expr(&self, id: ExprId) -> ExprKind<'ast> {
         (self.callbacks.expr)(self.callbacks.data, id)
     }
+
+    /// Returns the closest expression, which contains the expression with the
+    /// given [`ExprId`]. Statements and other nodes in between are skipped. For
+    /// the `1` in `{ let x = 1; }` this would return the block expression.
+    ///
+    /// The search stops at the body boundary. This returns `None` for the root
+    /// expression of a function or constant body. The body expression of a
+    /// closure is contained by the [`ClosureExpr`](crate::ast::ClosureExpr)
+    /// itself, which will be returned in that case.
+    ///
+    /// The returned expression has the same `'ast` lifetime as all other nodes.
+    pub fn parent_expr(&self, id: ExprId) -> Option<ExprKind<'ast>> {
+        (self.callbacks.parent_expr)(self.callbacks.data, id).copy()
+    }
+
+    /// Returns the [`ItemId`] of the item, which contains the expression with
+    /// the given [`ExprId`]. This is usually the function, constant or static,
+    /// which owns the body of the expression. Expressions inside closures belong
+    /// to the item, which contains the closure.
+    ///
+    /// Associated items, like methods, are also considered to be items.
+    pub fn parent_item(&self, id: ExprId) -> Option<ItemId> {
+        (self.callbacks.parent_item)(self.callbacks.data, id).copy()
+    }
 }
 
 #[repr(C)]
@@ -136,6 +187,8 @@ struct AstMapCallbacks<'ast> {
     pub body: extern "C" fn(data: &'ast AstMapData, id: BodyId) -> &'ast Body<'ast>,
     pub stmt: extern "C" fn(data: &'ast AstMapData, id: StmtId) -> StmtKind<'ast>,
     pub expr: extern "C" fn(data: &'ast AstMapData, id: ExprId) -> ExprKind<'ast>,
+    pub parent_expr: extern "C" fn(data: &'ast AstMapData, id: ExprId) -> ffi::FfiOption<ExprKind<'ast>>,
+    pub parent_item: extern "C" fn(data: &'ast AstMapData, id: ExprId) -> ffi::FfiOption<ItemId>,
 
     pub lint_level_at: extern "C" fn(data: &'ast AstMapData, lint: &'static Lint, node: NodeId) -> Level,
 }
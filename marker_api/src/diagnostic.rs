@@ -4,9 +4,9 @@
 use std::fmt::Debug;
 
 use crate::{
-    common::{HasNodeId, NodeId},
+    common::{HasNodeId, Level, NodeId},
     context::{with_cx, MarkerContext},
-    ffi::{FfiSlice, FfiStr},
+    ffi::{FfiOption, FfiSlice, FfiStr},
     lint::Lint,
     prelude::{HasSpan, Span},
 };
@@ -23,10 +23,24 @@ pub struct DiagnosticBuilder<'ast> {
 
 struct DiagnosticBuilderInner<'ast> {
     lint: &'static Lint,
+    level: Level,
     node: NodeId,
     msg: String,
     span: Span<'ast>,
-    parts: Vec<DiagnosticPart<String, Span<'ast>>>,
+    parts: Vec<OwnedDiagnosticPart<'ast>>,
+    /// The key of [`MarkerContext::emit_lint_once`], if this diagnostic
+    /// should only be emitted once per key.
+    key: Option<String>,
+}
+
+impl<'ast> DiagnosticBuilderInner<'ast> {
+    /// Adds a note, which helps lint authors to find misuses of the
+    /// [`DiagnosticBuilder`]. The note is only added in debug builds.
+    fn debug_note(&mut self, msg: String) {
+        if cfg!(debug_assertions) {
+            self.parts.push(DiagnosticPart::Note { msg });
+        }
+    }
 }
 
 impl<'ast> DiagnosticBuilder<'ast> {
@@ -35,18 +49,27 @@ impl<'ast> DiagnosticBuilder<'ast> {
         Self { inner: None }
     }
 
-    pub(crate) fn new(lint: &'static Lint, node: NodeId, msg: String, span: Span<'ast>) -> Self {
+    pub(crate) fn new(lint: &'static Lint, level: Level, node: NodeId, msg: String, span: Span<'ast>) -> Self {
         Self {
             inner: Some(DiagnosticBuilderInner {
                 lint,
+                level,
                 msg,
                 node,
                 span,
                 parts: vec![],
+                key: None,
             }),
         }
     }
 
+    pub(crate) fn with_key(mut self, key: String) -> Self {
+        if let Some(inner) = &mut self.inner {
+            inner.key = Some(key);
+        }
+        self
+    }
+
     /// This function sets the main [`Span`] of the created diagnostic.
     /// [`MarkerContext::emit_lint`] will by default use the [`Span`] of the given
     /// [`EmissionNode`].
@@ -122,6 +145,37 @@ impl<'ast> DiagnosticBuilder<'ast> {
         self
     }
 
+    /// This function adds a label to a secondary [`Span`] of the diagnostic.
+    /// Labels are intended to point at related code, like the declaration of
+    /// an item, which conflicts with its use. The [`Span`] can be in a different
+    /// file than the main [`Span`] of the diagnostic.
+    ///
+    /// From rustc a labeled span would be displayed like this:
+    /// ```text
+    ///  warning: <lint message>
+    ///  --> path/file.rs:1:1
+    ///   |
+    /// 1 | expression
+    ///   | ^^^^^^^^^^
+    ///   |
+    ///  ::: path/other.rs:4:1
+    ///   |
+    /// 4 | declaration
+    ///   | ----------- <text>          <-- The label added by this function
+    /// ```
+    ///
+    /// [`Self::span_note`] can be used to add a separate note with a [`Span`].
+    pub fn span_label(&mut self, msg: impl Into<String>, span: impl HasSpan<'ast>) -> &mut Self {
+        if let Some(inner) = self.inner.as_mut() {
+            inner.parts.push(DiagnosticPart::Label {
+                msg: msg.into(),
+                span: span.span().clone(),
+            });
+        }
+
+        self
+    }
+
     /// This function adds a help message. Help messages are intended to provide
     /// additional information about how the issue can be solved.
     ///
@@ -213,6 +267,69 @@ impl<'ast> DiagnosticBuilder<'ast> {
         self
     }
 
+    /// This function adds a help message with a suggestion, which consists of
+    /// multiple edits. Each edit replaces the given [`Span`] with the given string.
+    /// The edits are applied together, this makes it possible to suggest a single
+    /// fix, which affects disjoint parts of the code. The confidence of the
+    /// suggestion is expressed with the [`Applicability`].
+    ///
+    /// From rustc a multipart suggestion would be displayed like this:
+    /// ```text
+    ///  warning: <lint message>
+    ///  --> path/file.rs:1:1
+    ///   |
+    /// 1 | first + second
+    ///   | ^^^^^^^^^^^^^^
+    ///   |
+    /// help: <msg>                                 <-- The suggestion added by this function
+    ///   |
+    /// 1 | second + first
+    ///   | ~~~~~~   ~~~~~
+    /// ```
+    ///
+    /// Edits, which replace an empty span with an empty string, don't change
+    /// anything and are ignored. The suggestion is dropped, if no edits remain
+    /// or if any of the edits overlap. Debug builds of the lint crate add a
+    /// note to the diagnostic in these cases.
+    pub fn multipart_suggestion(
+        &mut self,
+        msg: impl Into<String>,
+        edits: &[(Span<'ast>, String)],
+        app: Applicability,
+    ) -> &mut Self {
+        if let Some(inner) = self.inner.as_mut() {
+            let msg = msg.into();
+            let edits: Vec<_> = edits
+                .iter()
+                .filter(|(span, sugg)| !(span.is_empty() && sugg.is_empty()))
+                .map(|(span, sugg)| SuggestionEdit {
+                    span: span.clone(),
+                    sugg: sugg.clone(),
+                })
+                .collect();
+
+            if edits.is_empty() {
+                inner.debug_note(format!(
+                    "the multipart suggestion `{msg}` was dropped, since it has no edits"
+                ));
+            } else if edits
+                .iter()
+                .enumerate()
+                .any(|(index, edit)| edits[index + 1..].iter().any(|other| edit.span.overlaps(&other.span)))
+            {
+                inner.debug_note(format!(
+                    "the multipart suggestion `{msg}` was dropped, since its edits overlap"
+                ));
+            } else {
+                inner
+                    .parts
+                    .push(DiagnosticPart::MultipartSuggestion { msg, edits, app });
+            }
+        }
+
+        self
+    }
+
     /// The `decorate` parameter accepts a closure, that is only executed, when the
     /// lint will actually be emitted in the end. Having them in a conditional closure
     /// will speedup the linting process if the lint is suppressed.
@@ -275,13 +392,32 @@ impl<'ast> DiagnosticBuilder<'ast> {
 
     pub(crate) fn emit<'builder>(&'builder self, cx: &MarkerContext<'ast>) {
         if let Some(inner) = &self.inner {
-            let parts: Vec<_> = inner.parts.iter().map(DiagnosticPart::to_ffi_part).collect();
+            // The edits of multipart suggestions have to be converted first, since
+            // the FFI parts only borrow them.
+            let edits: Vec<Vec<_>> = inner
+                .parts
+                .iter()
+                .map(|part| match part {
+                    DiagnosticPart::MultipartSuggestion { edits, .. } => {
+                        edits.iter().map(SuggestionEdit::to_ffi_edit).collect()
+                    },
+                    _ => vec![],
+                })
+                .collect();
+            let parts: Vec<_> = inner
+                .parts
+                .iter()
+                .zip(&edits)
+                .map(|(part, edits)| part.to_ffi_part(edits))
+                .collect();
             let diag = Diagnostic {
                 lint: inner.lint,
+                level: inner.level,
                 msg: inner.msg.as_str().into(),
                 node: inner.node,
                 span: &inner.span,
                 parts: parts.as_slice().into(),
+                key: inner.key.as_deref().map(Into::into).into(),
             };
             cx.emit_diagnostic(&diag);
         }
@@ -305,7 +441,7 @@ impl<'ast, N: Debug + HasSpan<'ast> + HasNodeId> EmissionNode<'ast> for N {}
 #[non_exhaustive]
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "driver-api", visibility::make(pub))]
-pub(crate) enum DiagnosticPart<St, Sp> {
+pub(crate) enum DiagnosticPart<St, Sp, Ed> {
     Help {
         msg: St,
     },
@@ -326,10 +462,22 @@ pub(crate) enum DiagnosticPart<St, Sp> {
         sugg: St,
         app: Applicability,
     },
+    MultipartSuggestion {
+        msg: St,
+        edits: Ed,
+        app: Applicability,
+    },
+    Label {
+        msg: St,
+        span: Sp,
+    },
 }
 
-impl<'ast> DiagnosticPart<String, Span<'ast>> {
-    fn to_ffi_part<'part>(&'part self) -> DiagnosticPart<FfiStr<'part>, &'part Span<'ast>> {
+impl<'ast> OwnedDiagnosticPart<'ast> {
+    fn to_ffi_part<'part>(
+        &'part self,
+        ffi_edits: &'part [SuggestionEdit<FfiStr<'part>, &'part Span<'ast>>],
+    ) -> FfiDiagnosticPart<'part, 'ast> {
         match self {
             DiagnosticPart::Help { msg } => DiagnosticPart::Help { msg: msg.into() },
             DiagnosticPart::HelpSpan { msg, span } => DiagnosticPart::HelpSpan { msg: msg.into(), span },
@@ -341,6 +489,38 @@ impl<'ast> DiagnosticPart<String, Span<'ast>> {
                 sugg: sugg.into(),
                 app: *app,
             },
+            DiagnosticPart::MultipartSuggestion { msg, edits: _, app } => DiagnosticPart::MultipartSuggestion {
+                msg: msg.into(),
+                edits: ffi_edits.into(),
+                app: *app,
+            },
+            DiagnosticPart::Label { msg, span } => DiagnosticPart::Label { msg: msg.into(), span },
+        }
+    }
+}
+
+/// The [`DiagnosticPart`] representation, which is stored by the [`DiagnosticBuilder`].
+type OwnedDiagnosticPart<'ast> = DiagnosticPart<String, Span<'ast>, Vec<SuggestionEdit<String, Span<'ast>>>>;
+
+/// The [`DiagnosticPart`] representation, which is passed to the driver.
+type FfiDiagnosticPart<'part, 'ast> =
+    DiagnosticPart<FfiStr<'part>, &'part Span<'ast>, FfiSlice<'part, SuggestionEdit<FfiStr<'part>, &'part Span<'ast>>>>;
+
+/// A single edit of a [`DiagnosticPart::MultipartSuggestion`], which replaces
+/// the [`Span`] with the suggestion.
+#[repr(C)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "driver-api", visibility::make(pub))]
+pub(crate) struct SuggestionEdit<St, Sp> {
+    pub span: Sp,
+    pub sugg: St,
+}
+
+impl<'ast> SuggestionEdit<String, Span<'ast>> {
+    fn to_ffi_edit(&self) -> SuggestionEdit<FfiStr<'_>, &Span<'ast>> {
+        SuggestionEdit {
+            span: &self.span,
+            sugg: self.sugg.as_str().into(),
         }
     }
 }
@@ -383,10 +563,15 @@ pub enum Applicability {
 #[cfg_attr(feature = "driver-api", visibility::make(pub))]
 pub(crate) struct Diagnostic<'builder, 'ast> {
     pub lint: &'static Lint,
+    /// The level of the lint at the emission node. This can differ from the
+    /// default level of the lint, for example, due to `#[warn]` attributes.
+    pub level: Level,
     pub msg: FfiStr<'builder>,
     pub node: NodeId,
     pub span: &'builder Span<'ast>,
-    pub parts: FfiSlice<'builder, DiagnosticPart<FfiStr<'builder>, &'builder Span<'ast>>>,
+    pub parts: FfiSlice<'builder, FfiDiagnosticPart<'builder, 'ast>>,
+    /// The key given to [`MarkerContext::emit_lint_once`], if any.
+    pub key: FfiOption<FfiStr<'builder>>,
 }
 
 impl<'builder, 'ast> Diagnostic<'builder, 'ast> {
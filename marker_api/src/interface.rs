@@ -1,7 +1,11 @@
 //! A module responsible for generating and exposing an interface from lint crates.
 //! [`export_lint_pass`](crate::export_lint_pass) is the main macro, from this module.
 
-use crate::{context::MarkerContext, ffi::FfiSlice, lint::Lint};
+use crate::{
+    context::MarkerContext,
+    ffi::{FfiOption, FfiSlice, FfiStr},
+    lint::Lint,
+};
 
 /// **!Unstable!**
 /// This struct is used to connect lint crates to drivers.
@@ -11,14 +15,37 @@ pub struct LintCrateBindings {
     pub set_ast_context: for<'ast> extern "C" fn(cx: &'ast MarkerContext<'ast>),
 
     // lint pass functions
-    pub info: for<'ast> extern "C" fn() -> LintPassInfo,
-    pub check_crate: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::Crate<'ast>),
-    pub check_item: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::ItemKind<'ast>),
-    pub check_field: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::ItemField<'ast>),
-    pub check_variant: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::EnumVariant<'ast>),
-    pub check_body: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::Body<'ast>),
-    pub check_stmt: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::StmtKind<'ast>),
-    pub check_expr: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::ExprKind<'ast>),
+    //
+    // The `check_*` functions and `setup` return `false` and `info` returns
+    // `None`, if the lint pass panicked. Panics can't unwind across the FFI
+    // boundary, they're therefore caught inside the lint crate.
+    pub info: for<'ast> extern "C" fn() -> FfiOption<LintPassInfo>,
+    pub setup: for<'a> extern "C" fn(ConfigValue<'a>) -> bool,
+    pub check_crate: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::Crate<'ast>) -> bool,
+    pub check_crate_post: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::Crate<'ast>) -> bool,
+    pub check_item: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::ItemKind<'ast>) -> bool,
+    pub check_field: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::ItemField<'ast>) -> bool,
+    pub check_variant: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::EnumVariant<'ast>) -> bool,
+    pub check_body: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, &'ast crate::ast::Body<'ast>) -> bool,
+    pub check_stmt: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::StmtKind<'ast>) -> bool,
+    pub check_expr: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::ExprKind<'ast>) -> bool,
+    pub check_pat: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::PatKind<'ast>) -> bool,
+    pub check_ty: for<'ast> extern "C" fn(&'ast MarkerContext<'ast>, crate::ast::TyKind<'ast>) -> bool,
+    pub check_generic_param: for<'ast> extern "C" fn(
+        &'ast MarkerContext<'ast>,
+        &'ast crate::ast::GenericParamKind<'ast>,
+        &'ast crate::ast::GenericParams<'ast>,
+    ) -> bool,
+}
+
+/// **!Unstable!**
+/// Calls the given function and catches panics, which occur during the call.
+/// Returns `false`, if the function panicked. This is used by
+/// [`export_lint_pass`](crate::export_lint_pass) to prevent panics from unwinding
+/// across the FFI boundary.
+#[doc(hidden)]
+pub fn catch_lint_pass_panic(f: impl FnOnce()) -> bool {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).is_ok()
 }
 
 /// This macro marks the given struct as the main [`LintPass`](`crate::LintPass`)
@@ -70,68 +97,129 @@ macro_rules! export_lint_pass {
             /// This magic function fills the `LintCrateBindings` struct to allow easy
             /// communication between marker's driver and lint crates.
             #[no_mangle]
-            extern "C" fn marker_lint_crate_bindings() -> $crate::LintCrateBindings {
+            pub extern "C" fn marker_lint_crate_bindings() -> $crate::LintCrateBindings {
                 pub use $crate::LintPass;
 
                 extern "C" fn set_ast_context<'ast>(cx: &'ast $crate::MarkerContext<'ast>) {
                     $crate::context::set_ast_cx(cx);
                 }
-                extern "C" fn info() -> $crate::LintPassInfo {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().info())
+                extern "C" fn info() -> $crate::ffi::FfiOption<$crate::LintPassInfo> {
+                    let mut info = None;
+                    $crate::catch_lint_pass_panic(|| {
+                        info = Some(super::__MARKER_STATE.with(|state| state.borrow_mut().info()));
+                    });
+                    info.into()
+                }
+                extern "C" fn setup(config: $crate::ConfigValue<'_>) -> bool {
+                    $crate::catch_lint_pass_panic(|| {
+                        super::__MARKER_STATE.with(|state| state.borrow_mut().setup(config));
+                    })
                 }
                 extern "C" fn check_crate<'ast>(
                     cx: &'ast $crate::MarkerContext<'ast>,
                     krate: &'ast $crate::ast::Crate<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_crate(cx, krate));
+                ) -> bool {
+                    $crate::catch_lint_pass_panic(|| {
+                        super::__MARKER_STATE.with(|state| state.borrow_mut().check_crate(cx, krate));
+                    })
+                }
+                extern "C" fn check_crate_post<'ast>(
+                    cx: &'ast $crate::MarkerContext<'ast>,
+                    krate: &'ast $crate::ast::Crate<'ast>,
+                ) -> bool {
+                    $crate::catch_lint_pass_panic(|| {
+                        super::__MARKER_STATE.with(|state| state.borrow_mut().check_crate_post(cx, krate));
+                    })
                 }
                 extern "C" fn check_item<'ast>(
                     cx: &'ast $crate::MarkerContext<'ast>,
                     item: $crate::ast::ItemKind<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_item(cx, item));
+                ) -> bool {
+                    $crate::catch_lint_pass_panic(|| {
+                        super::__MARKER_STATE.with(|state| state.borrow_mut().check_item(cx, item));
+                    })
                 }
                 extern "C" fn check_field<'ast>(
                     cx: &'ast $crate::MarkerContext<'ast>,
                     field: &'ast $crate::ast::ItemField<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_field(cx, field));
+                ) -> bool {
+                    $crate::catch_lint_pass_panic(|| {
+                        super::__MARKER_STATE.with(|state| state.borrow_mut().check_field(cx, field));
+                    })
                 }
                 extern "C" fn check_variant<'ast>(
                     cx: &'ast $crate::MarkerContext<'ast>,
                     variant: &'ast $crate::ast::EnumVariant<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_variant(cx, variant));
+                ) -> bool {
+                    $crate::catch_lint_pass_panic(|| {
+                        super::__MARKER_STATE.with(|state| state.borrow_mut().check_variant(cx, variant));
+                    })
                 }
                 extern "C" fn check_body<'ast>(
                     cx: &'ast $crate::MarkerContext<'ast>,
                     body: &'ast $crate::ast::Body<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_body(cx, body));
+                ) -> bool {
+                    $crate::catch_lint_pass_panic(|| {
+                        super::__MARKER_STATE.with(|state| state.borrow_mut().check_body(cx, body));
+                    })
                 }
                 extern "C" fn check_stmt<'ast>(
                     cx: &'ast $crate::MarkerContext<'ast>,
                     stmt: $crate::ast::StmtKind<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_stmt(cx, stmt));
+                ) -> bool {
+                    $crate::catch_lint_pass_panic(|| {
+                        super::__MARKER_STATE.with(|state| state.borrow_mut().check_stmt(cx, stmt));
+                    })
                 }
                 extern "C" fn check_expr<'ast>(
                     cx: &'ast $crate::MarkerContext<'ast>,
                     expr: $crate::ast::ExprKind<'ast>,
-                ) {
-                    super::__MARKER_STATE.with(|state| state.borrow_mut().check_expr(cx, expr));
+                ) -> bool {
+                    $crate::catch_lint_pass_panic(|| {
+                        super::__MARKER_STATE.with(|state| state.borrow_mut().check_expr(cx, expr));
+                    })
+                }
+                extern "C" fn check_pat<'ast>(
+                    cx: &'ast $crate::MarkerContext<'ast>,
+                    pat: $crate::ast::PatKind<'ast>,
+                ) -> bool {
+                    $crate::catch_lint_pass_panic(|| {
+                        super::__MARKER_STATE.with(|state| state.borrow_mut().check_pat(cx, pat));
+                    })
+                }
+                extern "C" fn check_ty<'ast>(
+                    cx: &'ast $crate::MarkerContext<'ast>,
+                    ty: $crate::ast::TyKind<'ast>,
+                ) -> bool {
+                    $crate::catch_lint_pass_panic(|| {
+                        super::__MARKER_STATE.with(|state| state.borrow_mut().check_ty(cx, ty));
+                    })
+                }
+                extern "C" fn check_generic_param<'ast>(
+                    cx: &'ast $crate::MarkerContext<'ast>,
+                    param: &'ast $crate::ast::GenericParamKind<'ast>,
+                    generics: &'ast $crate::ast::GenericParams<'ast>,
+                ) -> bool {
+                    $crate::catch_lint_pass_panic(|| {
+                        super::__MARKER_STATE.with(|state| state.borrow_mut().check_generic_param(cx, param, generics));
+                    })
                 }
 
                 $crate::LintCrateBindings {
                     set_ast_context,
                     info,
+                    setup,
                     check_crate,
+                    check_crate_post,
                     check_item,
                     check_field,
                     check_variant,
                     check_body,
                     check_stmt,
                     check_expr,
+                    check_pat,
+                    check_ty,
+                    check_generic_param,
                 }
             }
         }
@@ -141,6 +229,8 @@ macro_rules! export_lint_pass {
 #[derive(Debug)]
 pub struct LintPassInfoBuilder {
     lints: &'static [&'static Lint],
+    expr_lints: Vec<&'static Lint>,
+    config_validator: Option<ConfigValidator>,
 }
 
 impl LintPassInfoBuilder {
@@ -154,17 +244,78 @@ impl LintPassInfoBuilder {
             // is called. Ideally, it would be cool to just store the `Box` directly but
             // that is sadly not possible due to ABI constraints
             lints: Box::leak(lints),
+            expr_lints: vec![],
+            config_validator: None,
         }
     }
 
+    /// This method declares that [`LintPass::check_expr`](crate::LintPass::check_expr)
+    /// can emit the given lint. It can be called multiple times, if `check_expr`
+    /// emits several lints. The driver will then skip calling `check_expr` for
+    /// expressions, where all declared lints are allowed. `check_expr` is always
+    /// called, if no lint was declared.
+    ///
+    /// `check_expr` is called for every expression and is therefore one of the
+    /// most frequently called functions of a lint pass. Lint passes can use this
+    /// to avoid the call overhead and repeated lint level checks in their own
+    /// code. The lint levels are still checked by the driver for every expression.
+    #[must_use]
+    pub fn expr_lint(mut self, lint: &'static Lint) -> Self {
+        self.expr_lints.push(lint);
+        self
+    }
+
+    /// This method declares the schema of the configuration, accepted by this
+    /// lint crate. The driver will validate the configuration provided by the
+    /// user against this schema and report an error, if it is malformed. The
+    /// configuration is then passed to [`LintPass::setup`](crate::LintPass::setup),
+    /// where it can be deserialized with [`ConfigValue::deserialize`].
+    ///
+    /// ```ignore
+    /// #[derive(Default, serde::Deserialize)]
+    /// #[serde(deny_unknown_fields)]
+    /// struct Config {
+    ///     allowed_prefixes: Vec<String>,
+    /// }
+    ///
+    /// LintPassInfoBuilder::new(Box::new([NAMING_CONVENTION]))
+    ///     .config_schema::<Config>()
+    ///     .build()
+    /// ```
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn config_schema<T: serde::de::DeserializeOwned>(mut self) -> Self {
+        self.config_validator = Some(validate_config::<T>);
+        self
+    }
+
     /// This method builds the [`LintPassInfo`], ready for consumption.
     pub fn build(self) -> LintPassInfo {
         LintPassInfo {
             lints: self.lints.into(),
+            expr_lints: (&*self.expr_lints.leak()).into(),
+            config_validator: self.config_validator.into(),
         }
     }
 }
 
+/// A function, which validates the given configuration. It returns an error
+/// message, if the configuration is malformed.
+type ConfigValidator = for<'a> extern "C" fn(ConfigValue<'a>) -> FfiOption<FfiStr<'static>>;
+
+#[cfg(feature = "serde")]
+extern "C" fn validate_config<T: serde::de::DeserializeOwned>(config: ConfigValue<'_>) -> FfiOption<FfiStr<'static>> {
+    let Some(json) = config.as_json() else {
+        return FfiOption::None;
+    };
+    match serde_json::from_str::<T>(json) {
+        Ok(_) => FfiOption::None,
+        // The message is leaked, since it has to outlive this function call. This
+        // is fine, as the driver terminates after encountering a malformed config.
+        Err(err) => FfiOption::Some(FfiStr::from(&*Box::leak(err.to_string().into_boxed_str()))),
+    }
+}
+
 /// This struct provides basic information required by the driver. It can also
 /// be used to provide additional information. The struct is constructed using
 /// the [`LintPassInfoBuilder`].
@@ -172,10 +323,12 @@ impl LintPassInfoBuilder {
 /// All references and pointers in this struct have to have the `'static` lifetime
 /// due to ABI constraints.
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub struct LintPassInfo {
     lints: FfiSlice<'static, &'static Lint>,
+    expr_lints: FfiSlice<'static, &'static Lint>,
+    config_validator: FfiOption<ConfigValidator>,
 }
 
 #[cfg(feature = "driver-api")]
@@ -183,4 +336,136 @@ impl LintPassInfo {
     pub fn lints(&self) -> &[&'static Lint] {
         self.lints.get()
     }
+
+    /// The lints emitted by `check_expr`, declared by the lint pass. The slice
+    /// is empty, if the lint pass didn't declare any lints.
+    pub fn expr_lints(&self) -> &'static [&'static Lint] {
+        self.expr_lints.get()
+    }
+
+    /// Validates the given configuration against the schema declared by the
+    /// lint pass. Configurations are always valid, if no schema was declared.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message describing the problem, if the configuration is malformed.
+    pub fn validate_config(&self, config: ConfigValue<'_>) -> Result<(), &'static str> {
+        match self.config_validator.copy().map(|validator| validator(config)) {
+            Some(FfiOption::Some(msg)) => Err(msg.get()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The configuration of a lint crate, provided by the user. Users can specify
+/// it in the `[workspace.metadata.marker.lints.<crate>.config]` table of their
+/// `Cargo.toml` file:
+///
+/// ```toml
+/// [workspace.metadata.marker.lints.naming_lints]
+/// path = "./naming_lints"
+///
+/// [workspace.metadata.marker.lints.naming_lints.config]
+/// allowed_prefixes = ["get_", "set_"]
+/// ```
+///
+/// The configuration is passed to [`LintPass::setup`](crate::LintPass::setup).
+/// Lint crates can enable the `serde` feature of `marker_api`, to deserialize it
+/// into a custom type, with `ConfigValue::deserialize`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ConfigValue<'a> {
+    json: FfiOption<FfiStr<'a>>,
+}
+
+impl<'a> ConfigValue<'a> {
+    /// Returns `true`, if the user provided a configuration for this lint crate.
+    pub fn is_set(&self) -> bool {
+        self.json.get().is_some()
+    }
+
+    /// The raw configuration, serialized as JSON.
+    pub fn as_json(&self) -> Option<&'a str> {
+        self.json.get().map(FfiStr::get)
+    }
+
+    /// Deserializes the configuration into the given type. [`Default::default`]
+    /// is returned, if the user didn't provide a configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, if the configuration doesn't match the given type.
+    #[cfg(feature = "serde")]
+    pub fn deserialize<T: serde::de::DeserializeOwned + Default>(&self) -> Result<T, ConfigError> {
+        let Some(json) = self.as_json() else {
+            return Ok(T::default());
+        };
+        serde_json::from_str(json).map_err(|err| ConfigError {
+            message: err.to_string(),
+        })
+    }
+}
+
+#[cfg(feature = "driver-api")]
+impl<'a> ConfigValue<'a> {
+    pub fn new(json: Option<&'a str>) -> Self {
+        Self {
+            json: json.map(FfiStr::from).into(),
+        }
+    }
+}
+
+/// An error, describing why a [`ConfigValue`] couldn't be deserialized.
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub struct ConfigError {
+    message: String,
+}
+
+#[cfg(feature = "serde")]
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "malformed lint crate configuration: {}", self.message)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for ConfigError {}
+
+#[cfg(all(test, feature = "driver-api"))]
+mod tests {
+    use crate::{ConfigValue, LintPass, LintPassInfo};
+
+    struct PanickingPass;
+
+    impl LintPass for PanickingPass {
+        fn info(&self) -> LintPassInfo {
+            panic!("info panicked");
+        }
+
+        fn setup(&mut self, _config: ConfigValue<'_>) {
+            panic!("setup panicked");
+        }
+    }
+
+    // The lints are silenced for expansions in lint crates, since the macro
+    // comes from an external crate.
+    #[allow(unused_imports, improper_ctypes_definitions, clippy::too_many_lines)]
+    mod exported {
+        use super::PanickingPass;
+
+        crate::export_lint_pass!(PanickingPass, PanickingPass);
+
+        pub(super) fn bindings() -> crate::LintCrateBindings {
+            __marker_todo::marker_lint_crate_bindings()
+        }
+    }
+
+    #[test]
+    fn test_exported_info_and_setup_catch_panics() {
+        let bindings = exported::bindings();
+
+        assert!((bindings.info)().get().is_none());
+        assert!(!(bindings.setup)(ConfigValue::new(None)));
+    }
 }
@@ -27,13 +27,15 @@ pub mod context;
 pub mod diagnostic;
 pub mod prelude;
 pub mod sem;
+#[cfg(feature = "serialize")]
+pub mod serialize;
 pub mod span;
 
 #[doc(hidden)]
 pub mod ffi;
 
 pub use context::MarkerContext;
-pub use interface::{LintPassInfo, LintPassInfoBuilder};
+pub use interface::{ConfigValue, LintPassInfo, LintPassInfoBuilder};
 
 /// A [`LintPass`] visits every node like a `Visitor`. The difference is that a
 /// [`LintPass`] provides some additional information about the implemented lints.
@@ -42,13 +44,41 @@ pub use interface::{LintPassInfo, LintPassInfoBuilder};
 pub trait LintPass {
     fn info(&self) -> LintPassInfo;
 
+    /// This function is called once, before any `check_*` function, with the
+    /// configuration provided by the user. Users can configure lint crates in the
+    /// `[workspace.metadata.marker.lints.<crate>.config]` table of their `Cargo.toml`.
+    ///
+    /// The configuration is validated by the driver, if a schema was declared with
+    /// `LintPassInfoBuilder::config_schema`. See [`ConfigValue`] for more
+    /// information.
+    fn setup(&mut self, _config: ConfigValue<'_>) {}
+
     fn check_crate<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _krate: &'ast ast::Crate<'ast>) {}
+    /// This function is called after all other `check_*` functions, once the
+    /// entire crate has been traversed. It can be used to emit lints, which
+    /// depend on information collected from the whole crate.
+    fn check_crate_post<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _krate: &'ast ast::Crate<'ast>) {}
     fn check_item<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _item: ast::ItemKind<'ast>) {}
     fn check_field<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _field: &'ast ast::ItemField<'ast>) {}
     fn check_variant<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _variant: &'ast ast::EnumVariant<'ast>) {}
     fn check_body<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _body: &'ast ast::Body<'ast>) {}
     fn check_stmt<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _stmt: ast::StmtKind<'ast>) {}
     fn check_expr<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _expr: ast::ExprKind<'ast>) {}
+    fn check_pat<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _pat: ast::PatKind<'ast>) {}
+    /// This function is called for every syntactic type, including nested types,
+    /// like the generic arguments of a path or the element type of a slice.
+    fn check_ty<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _ty: ast::TyKind<'ast>) {}
+    /// This function is called for every generic parameter declared by an item,
+    /// like functions, ADTs, traits, impls and type aliases. The `generics` are
+    /// the parameters of the declaring item. They can be used to get the bounds
+    /// of a type parameter, with [`GenericParams::bounds_for`](ast::GenericParams::bounds_for).
+    fn check_generic_param<'ast>(
+        &mut self,
+        _cx: &'ast MarkerContext<'ast>,
+        _param: &'ast ast::GenericParamKind<'ast>,
+        _generics: &'ast ast::GenericParams<'ast>,
+    ) {
+    }
 }
 
 /// This struct blocks the construction of enum variants, similar to the `#[non_exhaustive]`
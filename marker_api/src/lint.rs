@@ -1,4 +1,4 @@
-use crate::common::{Level, MacroReport};
+use crate::common::{Level, LintCategory, MacroReport};
 
 /// This struct defines a lint.
 ///
@@ -61,6 +61,16 @@ pub struct Lint {
     /// See [`MacroReport`] for the possible levels.
     pub report_in_macro: MacroReport,
 
+    /// The category of the lint, if specified. It's added as a note to all
+    /// diagnostics emitted by this lint.
+    ///
+    /// See [`LintCategory`] for the possible categories.
+    pub category: Option<LintCategory>,
+
+    /// A URL to the documentation of the lint, if specified. It's added as a
+    /// note to all diagnostics emitted by this lint.
+    pub url: Option<&'static str>,
+
     /// Fully qualiefied name of the static variable that defines the lint.
     /// It includes the module and the name of the variable.
     pub fqn: &'static str,
@@ -97,25 +107,33 @@ pub struct Lint {
 ///     Warn,
 /// }
 /// ```
+///
+/// The macro report, the category and a URL to the documentation of the lint
+/// can optionally be specified after the level. The order of the fields is fixed,
+/// and each field has to be followed by a comma:
+///
+/// ```
+/// marker_api::declare_lint!{
+///     /// # What it does
+///     /// Here you can describe what your lint does.
+///     ITEM_WITH_TEST_NAME,
+///     Warn,
+///     report_in_macro: marker_api::common::MacroReport::All,
+///     category: Style,
+///     url: "https://example.com/lints/item_with_test_name",
+/// }
+/// ```
 #[macro_export]
 macro_rules! declare_lint {
-    (
-        $(#[doc = $doc:literal])+
-        $NAME: ident,
-        $LEVEL: ident $(,)?
-    ) => {
-        $crate::declare_lint!{
-            $(#[doc = $doc])+
-            $NAME,
-            $LEVEL,
-            $crate::common::MacroReport::No,
-        }
-    };
+    // This arm has to be first. Otherwise, the named fields might be parsed
+    // as the expression of the macro report.
     (
         $(#[doc = $doc:literal])+
         $NAME: ident,
         $LEVEL: ident,
-        $REPORT_IN_MACRO: expr $(,)?
+        $(report_in_macro: $REPORT_IN_MACRO: expr,)?
+        $(category: $CATEGORY: ident,)?
+        $(url: $URL: literal,)?
     ) => {
         $(#[doc = $doc])+
         pub static $NAME: &$crate::Lint = &$crate::Lint {
@@ -138,9 +156,47 @@ macro_rules! declare_lint {
             name: concat!("marker::", std::env!("CARGO_CRATE_NAME"), "::", stringify!($NAME)),
             default_level: $crate::common::Level::$LEVEL,
             explanation: concat!($($doc, '\n',)*),
-            report_in_macro: $REPORT_IN_MACRO,
+            report_in_macro: $crate::declare_lint!(@or $crate::common::MacroReport::No $(, $REPORT_IN_MACRO)?),
+            category: $crate::declare_lint!(@opt $($crate::common::LintCategory::$CATEGORY)?),
+            url: $crate::declare_lint!(@opt $($URL)?),
             fqn: concat!(module_path!(), "::", stringify!($NAME)),
             _unstable_i_accept_the_risk_of_instability: (),
         };
     };
+    (
+        $(#[doc = $doc:literal])+
+        $NAME: ident,
+        $LEVEL: ident $(,)?
+    ) => {
+        $crate::declare_lint!{
+            $(#[doc = $doc])+
+            $NAME,
+            $LEVEL,
+        }
+    };
+    (
+        $(#[doc = $doc:literal])+
+        $NAME: ident,
+        $LEVEL: ident,
+        $REPORT_IN_MACRO: expr $(,)?
+    ) => {
+        $crate::declare_lint!{
+            $(#[doc = $doc])+
+            $NAME,
+            $LEVEL,
+            report_in_macro: $REPORT_IN_MACRO,
+        }
+    };
+    (@or $default: expr) => {
+        $default
+    };
+    (@or $default: expr, $value: expr) => {
+        $value
+    };
+    (@opt) => {
+        None
+    };
+    (@opt $value: expr) => {
+        Some($value)
+    };
 }
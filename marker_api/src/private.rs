@@ -9,6 +9,8 @@ pub trait Sealed {}
 impl<N: Sealed> Sealed for &N {}
 
 impl Sealed for ast::AssocItemKind<'_> {}
+impl Sealed for ast::Attribute<'_> {}
+impl Sealed for ast::BodyParam<'_> {}
 impl Sealed for ast::ClosureParam<'_> {}
 impl Sealed for ast::ConstParam<'_> {}
 impl Sealed for ast::EnumVariant<'_> {}
@@ -16,6 +18,7 @@ impl Sealed for ast::ExprKind<'_> {}
 impl Sealed for ast::ExternItemKind<'_> {}
 impl Sealed for ast::FnTyParameter<'_> {}
 impl Sealed for ast::FnParam<'_> {}
+impl Sealed for ast::GenericParamKind<'_> {}
 impl Sealed for ast::ItemField<'_> {}
 impl Sealed for ast::ItemKind<'_> {}
 impl Sealed for ast::LifetimeParam<'_> {}
@@ -1,26 +1,51 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
-/// The semantic equivalent of a [`ConstExpr`][crate::ast::ConstExpr], at
-/// least theoretically. This part of the API is sadly not done yet, so this is
-/// just a placeholder.
+/// The semantic equivalent of a [`ConstExpr`][crate::ast::ConstExpr], as
+/// computed by the const evaluation of the driver.
+///
+/// Only scalar values, like numbers, `bool`s and `char`s are represented right
+/// now. Other values will have the [`ConstValueKind::Unstable`] kind.
 ///
 /// See: rust-marker/marker#179
 #[repr(C)]
+#[derive(Debug)]
 pub struct ConstValue<'ast> {
     _lifetime: PhantomData<&'ast ()>,
+    kind: ConstValueKind,
 }
 
-impl<'ast> Debug for ConstValue<'ast> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ConstValue {{ /* WIP: See rust-marker/marker#179 */}}")
-            .finish()
+impl<'ast> ConstValue<'ast> {
+    pub fn kind(&self) -> ConstValueKind {
+        self.kind
     }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> ConstValue<'ast> {
-    pub fn new() -> Self {
-        Self { _lifetime: PhantomData }
+    pub fn new(kind: ConstValueKind) -> Self {
+        Self {
+            _lifetime: PhantomData,
+            kind,
+        }
     }
 }
+
+/// The kind of a [`ConstValue`].
+#[repr(C)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConstValueKind {
+    /// A `bool` value
+    Bool(bool),
+    /// A `char` value
+    Char(char),
+    /// The value of a signed integer type, like `i32`
+    Int(i128),
+    /// The value of an unsigned integer type, like `u32`
+    Uint(u128),
+    /// The value of a float type. `f32` values are converted to `f64`.
+    Float(f64),
+    /// The placeholder kind, for values that are not yet represented in the API.
+    Unstable,
+}
@@ -70,6 +70,9 @@ pub enum TyKind<'ast> {
     // ================================
     /// A user defined data type, identified by an [`TyDefId`](crate::common::TyDefId)
     Adt(&'ast AdtTy<'ast>),
+    /// A foreign type, declared in an `extern` block, identified by an
+    /// [`TyDefId`](crate::common::TyDefId)
+    Foreign(&'ast ForeignTy<'ast>),
     /// A generic type defined by a generic parameter
     Generic(&'ast GenericTy<'ast>),
     /// A type alias. Note that simple type aliases will already be replaced in
@@ -115,6 +118,34 @@ impl<'ast> TyKind<'ast> {
     }
 }
 
+impl<'ast> TyKind<'ast> {
+    impl_ty_kind_data_fn!(data() -> &CommonTyData<'ast>);
+}
+
+/// Until [trait upcasting](https://github.com/rust-lang/rust/issues/65991) has been implemented
+/// and stabilized we need this to call [`CommonTyData`] functions for every [`TyKind`].
+macro_rules! impl_ty_kind_data_fn {
+    ($method:ident () -> $return_ty:ty) => {
+        impl_ty_kind_data_fn!($method() -> $return_ty,
+            Bool, Num, Text, Never,
+            Tuple, Array, Slice,
+            Fn, Closure,
+            Ref, RawPtr, FnPtr,
+            TraitObj, Adt, Foreign, Generic, Alias,
+            Unstable
+        );
+    };
+    ($method:ident () -> $return_ty:ty $(, $item:ident)+) => {
+        pub(crate) fn $method(&self) -> $return_ty {
+            match self {
+                $(TyKind::$item(data) => data.$method(),)*
+            }
+        }
+    };
+}
+
+use impl_ty_kind_data_fn;
+
 #[repr(C)]
 #[cfg_attr(feature = "driver-api", visibility::make(pub))]
 #[cfg_attr(feature = "driver-api", derive(typed_builder::TypedBuilder))]
@@ -130,18 +161,16 @@ impl<'ast> Debug for CommonTyData<'ast> {
     }
 }
 
-#[cfg(feature = "driver-api")]
 impl<'ast> CommonTyData<'ast> {
-    pub fn driver_id(&self) -> DriverTyId {
+    pub(crate) fn driver_id(&self) -> DriverTyId {
         self.driver_id
     }
 }
 
 macro_rules! impl_ty_data {
     ($self_ty:ty, $enum_name:ident) => {
-        #[cfg(feature = "driver_api")]
         impl<'ast> $self_ty {
-            pub fn data(&self) -> &$crate::sem::ty::CommonTyData<'ast> {
+            pub(crate) fn data(&self) -> &$crate::sem::ty::CommonTyData<'ast> {
                 &self.data
             }
         }
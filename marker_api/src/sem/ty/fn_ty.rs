@@ -1,9 +1,10 @@
 use crate::{
     common::{ItemId, TyDefId},
+    ffi::FfiSlice,
     sem::generic::GenericArgs,
 };
 
-use super::CommonTyData;
+use super::{CommonTyData, TyKind};
 
 /// A [function item type](https://doc.rust-lang.org/reference/types/function-item.html)
 /// identifying a specific function and potentualy additional generics.
@@ -34,7 +35,15 @@ super::impl_ty_data!(FnTy<'ast>, Fn);
 /// [closure type](https://doc.rust-lang.org/reference/types/closure.html).
 ///
 /// Closure expressions create anonymous types, which implement traits to call the
-/// closure. This type on it's own therefore only identifies the type of the closure.
+/// closure. This type identifies the type of the closure and provides the
+/// signature, as inferred by the type checker.
+///
+/// ```
+/// //      v The parameter type is inferred as `u32`
+/// let f = |x| u32::max(x, 1);
+/// ```
+///
+/// In this example, the closure type has a single `u32` parameter and returns `u32`.
 #[repr(C)]
 #[derive(Debug)]
 #[cfg_attr(feature = "driver-api", derive(typed_builder::TypedBuilder))]
@@ -42,6 +51,9 @@ pub struct ClosureTy<'ast> {
     data: CommonTyData<'ast>,
     def_id: TyDefId,
     generics: GenericArgs<'ast>,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    params: FfiSlice<'ast, TyKind<'ast>>,
+    return_ty: TyKind<'ast>,
 }
 
 impl<'ast> ClosureTy<'ast> {
@@ -55,8 +67,15 @@ impl<'ast> ClosureTy<'ast> {
         &self.generics
     }
 
-    // FIXME: Add a method to get a different representation, which includes the
-    // parameters and return type.
+    /// The types of the closure parameters, in declaration order.
+    pub fn params(&self) -> &[TyKind<'ast>] {
+        self.params.get()
+    }
+
+    /// The return type of the closure.
+    pub fn return_ty(&self) -> TyKind<'ast> {
+        self.return_ty
+    }
 }
 
 super::impl_ty_data!(ClosureTy<'ast>, Closure);
@@ -1,9 +1,11 @@
 use crate::{
-    common::{GenericId, ItemId, TyDefId},
+    common::{GenericId, ItemId, SymbolId, TyDefId, VariantId},
+    context::with_cx,
+    ffi::FfiSlice,
     sem::generic::GenericArgs,
 };
 
-use super::CommonTyData;
+use super::{CommonTyData, TyKind};
 
 /// The semantic representation of an abstract data type. This can be an
 /// [`Enum`], [`Struct`], or [`Union`].
@@ -30,10 +32,121 @@ impl<'ast> AdtTy<'ast> {
     pub fn generics(&self) -> &GenericArgs<'ast> {
         &self.generics
     }
+
+    /// Returns the fields of a struct or union, in the order of their declaration.
+    /// An empty slice is returned for enums, the fields of enums are available
+    /// via [`AdtTy::variants`].
+    ///
+    /// ```ignore
+    /// if let sem::TyKind::Adt(adt) = expr.ty() {
+    ///     if adt.fields().len() > 10 {
+    ///         // The struct has a lot of fields
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// This also works for types from external crates. Private fields are
+    /// included as well.
+    pub fn fields(&self) -> &'ast [AdtField<'ast>] {
+        with_cx(self, |cx| cx.adt_fields(self.data.driver_id()))
+    }
+
+    /// Returns the variants of an enum, in the order of their declaration. An
+    /// empty slice is returned for structs and unions.
+    pub fn variants(&self) -> &'ast [AdtVariant<'ast>] {
+        with_cx(self, |cx| cx.adt_variants(self.data.driver_id()))
+    }
 }
 
 super::impl_ty_data!(AdtTy<'ast>, Adt);
 
+/// A variant of an enum, returned by [`AdtTy::variants`].
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "driver-api", derive(typed_builder::TypedBuilder))]
+pub struct AdtVariant<'ast> {
+    id: VariantId,
+    name: SymbolId,
+    #[cfg_attr(feature = "driver-api", builder(setter(into)))]
+    fields: FfiSlice<'ast, AdtField<'ast>>,
+}
+
+impl<'ast> AdtVariant<'ast> {
+    /// The [`VariantId`] of the variant. For enums of the current crate, it can
+    /// be used to retrieve the [`EnumVariant`](crate::ast::EnumVariant) from
+    /// the [`AstMap`](crate::context::AstMap).
+    pub fn id(&self) -> VariantId {
+        self.id
+    }
+
+    pub fn name(&self) -> &'ast str {
+        with_cx(self, |cx| cx.symbol_str(self.name))
+    }
+
+    /// The fields of this variant, in the order of their declaration.
+    pub fn fields(&self) -> &'ast [AdtField<'ast>] {
+        self.fields.get()
+    }
+}
+
+/// A field of a struct, union or enum variant, returned by [`AdtTy::fields`]
+/// and [`AdtVariant::fields`].
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "driver-api", derive(typed_builder::TypedBuilder))]
+pub struct AdtField<'ast> {
+    name: SymbolId,
+    ty: TyKind<'ast>,
+}
+
+impl<'ast> AdtField<'ast> {
+    /// The name of the field. Fields of tuple structs and tuple variants are
+    /// named by their index, like `0` and `1`.
+    pub fn name(&self) -> &'ast str {
+        with_cx(self, |cx| cx.symbol_str(self.name))
+    }
+
+    /// The type of the field, with the generic arguments of the [`AdtTy`]
+    /// applied. For `Option<u32>`, the field of the `Some` variant has the
+    /// type `u32`.
+    ///
+    /// Field types, which still depend on generic parameters, like `T` for
+    /// `Option<T>` inside a generic function, are currently returned as
+    /// [`TyKind::Unstable`].
+    pub fn ty(&self) -> TyKind<'ast> {
+        self.ty
+    }
+}
+
+/// The semantic representation of a foreign type, declared in an `extern` block.
+///
+/// ```ignore
+/// #![feature(extern_types)]
+///
+/// extern "C" {
+///     type Opaque;
+///     //   ^^^^^^ A foreign type
+/// }
+/// ```
+///
+/// Foreign types have an unknown size and can only be used behind pointers.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(feature = "driver-api", derive(typed_builder::TypedBuilder))]
+pub struct ForeignTy<'ast> {
+    data: CommonTyData<'ast>,
+    def_id: TyDefId,
+}
+
+impl<'ast> ForeignTy<'ast> {
+    /// This returns the [`TyDefId`] of the foreign type.
+    pub fn def_id(&self) -> TyDefId {
+        self.def_id
+    }
+}
+
+super::impl_ty_data!(ForeignTy<'ast>, Foreign);
+
 /// The semantic representation of a generic type. For example
 ///
 /// ```
@@ -0,0 +1,228 @@
+//! A serializable representation of the AST, available with the `serialize`
+//! feature. It allows external tools and golden tests to inspect the AST as
+//! JSON, without implementing a [`LintPass`](crate::LintPass).
+//!
+//! ```ignore
+//! let node = ItemNode::new(item);
+//! let json = serde_json::to_string_pretty(&node).unwrap();
+//! ```
+//!
+//! Currently, only items and expressions are represented. Every node stores its
+//! kind, the location of its span and its child nodes. Node IDs are not stable
+//! between sessions (See [`ItemId`](crate::common::ItemId)) and are therefore
+//! not part of the representation. The location of the span is used instead.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ast::{ConstExpr, CtorField, ExprKind, ItemKind, StmtKind},
+    context::with_cx,
+    span::{Span, SpanSource},
+};
+
+/// The serializable representation of an [`ItemKind`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemNode {
+    kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    span: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    items: Vec<ItemNode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<ExprNode>,
+}
+
+impl ItemNode {
+    /// Creates the representation of the given item and all its child nodes.
+    pub fn new(item: ItemKind<'_>) -> Self {
+        let (kind, items, body_id) = match item {
+            ItemKind::Mod(item) => ("Mod", item.items().iter().map(|item| Self::new(*item)).collect(), None),
+            ItemKind::ExternCrate(_) => ("ExternCrate", vec![], None),
+            ItemKind::Use(_) => ("Use", vec![], None),
+            ItemKind::Static(item) => ("Static", vec![], item.body_id()),
+            ItemKind::Const(item) => ("Const", vec![], item.body_id()),
+            ItemKind::Fn(item) => ("Fn", vec![], item.body_id()),
+            ItemKind::TyAlias(_) => ("TyAlias", vec![], None),
+            ItemKind::Struct(_) => ("Struct", vec![], None),
+            ItemKind::Enum(_) => ("Enum", vec![], None),
+            ItemKind::Union(_) => ("Union", vec![], None),
+            ItemKind::Trait(item) => (
+                "Trait",
+                item.items().iter().map(|item| Self::new(item.as_item())).collect(),
+                None,
+            ),
+            ItemKind::Impl(item) => (
+                "Impl",
+                item.items().iter().map(|item| Self::new(item.as_item())).collect(),
+                None,
+            ),
+            ItemKind::ExternBlock(item) => (
+                "ExternBlock",
+                item.items().iter().map(|item| Self::new(item.as_item())).collect(),
+                None,
+            ),
+            ItemKind::Unstable(_) => ("Unstable", vec![], None),
+        };
+        let body = body_id.map(|id| ExprNode::new(with_cx(&item, |cx| cx.ast().body(id)).expr()));
+
+        Self {
+            kind: kind.to_string(),
+            name: item
+                .ident()
+                .map(|ident| ident.name().to_string())
+                .filter(|name| !name.is_empty()),
+            span: span_location(item.span()),
+            items,
+            body,
+        }
+    }
+
+    /// The name of the [`ItemKind`] variant, like `Fn` or `Mod`.
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// The name of the item, if it has one.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The location of the item as `file:line:column`. See [`span_location`].
+    pub fn span(&self) -> Option<&str> {
+        self.span.as_deref()
+    }
+
+    /// The items nested in this item, like the items of a module or the
+    /// associated items of an impl block.
+    pub fn items(&self) -> &[ItemNode] {
+        &self.items
+    }
+
+    /// The root expression of the item's body, if it has one.
+    pub fn body(&self) -> Option<&ExprNode> {
+        self.body.as_ref()
+    }
+}
+
+/// The serializable representation of an [`ExprKind`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExprNode {
+    kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    span: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    exprs: Vec<ExprNode>,
+}
+
+impl ExprNode {
+    /// Creates the representation of the given expression and all its child
+    /// expressions.
+    #[allow(clippy::too_many_lines)]
+    pub fn new(expr: ExprKind<'_>) -> Self {
+        let (kind, exprs): (&str, Vec<ExprKind<'_>>) = match expr {
+            ExprKind::IntLit(_) => ("IntLit", vec![]),
+            ExprKind::FloatLit(_) => ("FloatLit", vec![]),
+            ExprKind::StrLit(_) => ("StrLit", vec![]),
+            ExprKind::CharLit(_) => ("CharLit", vec![]),
+            ExprKind::BoolLit(_) => ("BoolLit", vec![]),
+            ExprKind::Block(e) => {
+                let mut exprs = vec![];
+                for stmt in e.stmts() {
+                    match stmt {
+                        StmtKind::Let(stmt) => exprs.extend(stmt.init().into_iter().chain(stmt.els())),
+                        StmtKind::Expr(stmt) => exprs.push(stmt.expr()),
+                        // Items in statements are represented by `ItemNode`s
+                        _ => {},
+                    }
+                }
+                exprs.extend(e.expr());
+                ("Block", exprs)
+            },
+            ExprKind::Closure(e) => ("Closure", vec![e.body_expr()]),
+            ExprKind::UnaryOp(e) => ("UnaryOp", vec![e.expr()]),
+            ExprKind::Ref(e) => ("Ref", vec![e.expr()]),
+            ExprKind::BinaryOp(e) => ("BinaryOp", vec![e.left(), e.right()]),
+            ExprKind::Try(e) => ("Try", vec![e.expr()]),
+            ExprKind::Assign(e) => ("Assign", vec![e.value()]),
+            ExprKind::As(e) => ("As", vec![e.expr()]),
+            ExprKind::Path(_) => ("Path", vec![]),
+            ExprKind::Call(e) => (
+                "Call",
+                std::iter::once(e.func()).chain(e.args().iter().copied()).collect(),
+            ),
+            ExprKind::Method(e) => (
+                "Method",
+                std::iter::once(e.receiver()).chain(e.args().iter().copied()).collect(),
+            ),
+            ExprKind::Array(e) => (
+                "Array",
+                e.elements()
+                    .iter()
+                    .copied()
+                    .chain(e.len().map(ConstExpr::expr))
+                    .collect(),
+            ),
+            ExprKind::Tuple(e) => ("Tuple", e.elements().to_vec()),
+            ExprKind::Ctor(e) => ("Ctor", e.fields().iter().map(CtorField::expr).chain(e.base()).collect()),
+            ExprKind::Range(e) => ("Range", e.start().into_iter().chain(e.end()).collect()),
+            ExprKind::Index(e) => ("Index", vec![e.operand(), e.index()]),
+            ExprKind::Field(e) => ("Field", vec![e.operand()]),
+            ExprKind::If(e) => ("If", [e.condition(), e.then()].into_iter().chain(e.els()).collect()),
+            ExprKind::Let(e) => ("Let", vec![e.scrutinee()]),
+            ExprKind::Match(e) => {
+                let mut exprs = vec![e.scrutinee()];
+                for arm in e.arms() {
+                    exprs.extend(arm.guard());
+                    exprs.push(arm.expr());
+                }
+                ("Match", exprs)
+            },
+            ExprKind::Break(e) => ("Break", e.expr().into_iter().collect()),
+            ExprKind::Return(e) => ("Return", e.expr().into_iter().collect()),
+            ExprKind::Continue(_) => ("Continue", vec![]),
+            ExprKind::For(e) => ("For", vec![e.iterable(), e.block()]),
+            ExprKind::Loop(e) => ("Loop", vec![e.block()]),
+            ExprKind::While(e) => ("While", vec![e.condition(), e.block()]),
+            ExprKind::Await(e) => ("Await", vec![e.expr()]),
+            ExprKind::Yield(e) => ("Yield", vec![e.expr()]),
+            ExprKind::Unstable(_) => ("Unstable", vec![]),
+        };
+
+        Self {
+            kind: kind.to_string(),
+            span: span_location(expr.span()),
+            exprs: exprs.into_iter().map(Self::new).collect(),
+        }
+    }
+
+    /// The name of the [`ExprKind`] variant, like `Call` or `IntLit`.
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// The location of the expression as `file:line:column`. See [`span_location`].
+    pub fn span(&self) -> Option<&str> {
+        self.span.as_deref()
+    }
+
+    /// The direct child expressions of this expression, in source order.
+    pub fn exprs(&self) -> &[ExprNode] {
+        &self.exprs
+    }
+}
+
+/// Returns the start location of the given [`Span`] as `file:line:column`.
+///
+/// Spans from macro expansions are mapped to the location of the outermost
+/// macro invocation. [`None`] is returned for spans, which don't belong to a
+/// file, like [`SpanSource::Builtin`] spans.
+pub fn span_location(span: &Span<'_>) -> Option<String> {
+    let span = span.outermost_call_site();
+    let SpanSource::File(file) = span.source() else {
+        return None;
+    };
+    let pos = file.try_to_file_pos(span.start())?;
+    Some(format!("{}:{}:{}", file.file(), pos.line(), pos.column()))
+}
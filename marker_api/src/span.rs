@@ -1,4 +1,4 @@
-use std::marker::PhantomData;
+use std::{marker::PhantomData, ops::Range};
 
 use crate::{
     common::{ExpnId, MacroId, SpanId, SpanSrcId, SymbolId},
@@ -142,40 +142,95 @@ pub struct ExpnInfo<'ast> {
     _lifetime: PhantomData<&'ast ()>,
     parent: ExpnId,
     call_site: SpanId,
+    def_site: ffi::FfiOption<SpanId>,
     macro_id: MacroId,
+    name: SymbolId,
 }
 
 impl<'ast> ExpnInfo<'ast> {
     /// This returns [`Some`] if this expansion comes from another expansion.
     #[must_use]
-    pub fn parent(&self) -> Option<&ExpnInfo<'ast>> {
+    pub fn parent(&self) -> Option<&'ast ExpnInfo<'ast>> {
         with_cx(self, |cx| cx.span_expn_info(self.parent))
     }
 
     /// The [`Span`] that invoked the macro, that this expansion belongs to.
     #[must_use]
-    pub fn call_site(&self) -> &Span<'ast> {
+    pub fn call_site(&self) -> &'ast Span<'ast> {
         with_cx(self, |cx| cx.span(self.call_site))
     }
 
+    /// The [`Span`] of the macro definition, that this expansion belongs to. For
+    /// `macro_rules!` macros, this is the span of the entire definition. Lints,
+    /// which target macro authors, can use this to report at the definition,
+    /// instead of every expansion.
+    ///
+    /// This returns [`None`], if the driver doesn't know the definition, which
+    /// is usually the case for proc macros and built-in macros.
+    #[must_use]
+    pub fn def_site(&self) -> Option<&'ast Span<'ast>> {
+        self.def_site.copy().map(|span| with_cx(self, |cx| cx.span(span)))
+    }
+
     pub fn macro_id(&self) -> MacroId {
         self.macro_id
     }
+
+    /// The name of the macro, that this expansion belongs to. For derive macros,
+    /// this is the name of the derived trait, like `Debug` for `#[derive(Debug)]`.
+    pub fn name(&self) -> &'ast str {
+        with_cx(self, |cx| cx.symbol_str(self.name))
+    }
 }
 
 #[cfg(feature = "driver-api")]
 impl<'ast> ExpnInfo<'ast> {
     #[must_use]
-    pub fn new(parent: ExpnId, call_site: SpanId, macro_id: MacroId) -> Self {
+    pub fn new(parent: ExpnId, call_site: SpanId, def_site: Option<SpanId>, macro_id: MacroId, name: SymbolId) -> Self {
         Self {
             _lifetime: PhantomData,
             parent,
             call_site,
+            def_site: def_site.into(),
             macro_id,
+            name,
         }
     }
 }
 
+/// A single macro expansion in a macro backtrace. The frames for a [`Span`] can be
+/// retrieved via [`MarkerContext::macro_backtrace`](crate::MarkerContext::macro_backtrace).
+#[derive(Debug, Clone, Copy)]
+pub struct MacroFrame<'ast> {
+    expn: &'ast ExpnInfo<'ast>,
+}
+
+impl<'ast> MacroFrame<'ast> {
+    pub(crate) fn new(expn: &'ast ExpnInfo<'ast>) -> Self {
+        Self { expn }
+    }
+
+    /// The name of the expanded macro, see [`ExpnInfo::name`].
+    pub fn name(&self) -> &'ast str {
+        self.expn.name()
+    }
+
+    /// The [`Span`] of the macro invocation, see [`ExpnInfo::call_site`].
+    pub fn call_site(&self) -> &'ast Span<'ast> {
+        self.expn.call_site()
+    }
+
+    /// The [`Span`] of the macro definition, see [`ExpnInfo::def_site`].
+    pub fn def_site(&self) -> Option<&'ast Span<'ast>> {
+        self.expn.def_site()
+    }
+
+    /// The [`ExpnInfo`] of this expansion.
+    pub fn expn_info(&self) -> &'ast ExpnInfo<'ast> {
+        self.expn
+    }
+}
+
 /// A region of code, used for snipping, lint emission, and the retrieval of
 /// context information.
 ///
@@ -268,6 +323,63 @@ impl<'ast> Span<'ast> {
         with_cx(self, |cx| cx.span_snipped(self))
     }
 
+    /// Returns the code snippet of the macro invocation, that this [`Span`] was
+    /// expanded from. For spans, which don't come from a macro expansion, this
+    /// is the same as [`snippet`](Self::snippet).
+    ///
+    /// ```ignore
+    /// let data = vec![1, 2, 3];
+    /// //         ^^^^^^^^^^^^^
+    /// //         The span of the expanded expression
+    ///
+    /// expr_span.snippet();           // -> None
+    /// expr_span.call_site_snippet(); // -> Some("vec![1, 2, 3]")
+    /// ```
+    ///
+    /// For nested macros, the call site of the outermost macro is used, as it's
+    /// the only one written by the user.
+    #[must_use]
+    pub fn call_site_snippet(&self) -> Option<&'ast str> {
+        let span = self.outermost_call_site();
+        match span.source() {
+            SpanSource::File(_) => span.snippet(),
+            SpanSource::Macro(_) | SpanSource::Builtin(_) => None,
+        }
+    }
+
+    /// Returns the [`Span`] of the outermost macro invocation, that this [`Span`]
+    /// was expanded from. Spans, which don't come from a macro expansion, are
+    /// returned as is. The returned span therefore never has a
+    /// [`SpanSource::Macro`] source.
+    ///
+    /// ```ignore
+    /// let data = vec![1, 2, 3];
+    /// //         ^^^^^^^^^^^^^
+    /// //         The outermost call site of the expanded expression
+    /// ```
+    #[must_use]
+    pub fn outermost_call_site(&self) -> &Span<'ast> {
+        let mut span = self;
+        while let SpanSource::Macro(expn) = span.source() {
+            span = expn.call_site();
+        }
+        span
+    }
+
+    /// Returns the path of the file, that this [`Span`] belongs to. For spans
+    /// from macro expansions, the file of the outermost macro call is used.
+    /// [`None`] is returned for builtin spans, which don't belong to a file.
+    ///
+    /// The path is the one used by the driver, it's usually relative to the
+    /// package root, like `src/lib.rs`.
+    #[must_use]
+    pub fn source_file(&self) -> Option<&'ast str> {
+        match self.outermost_call_site().source() {
+            SpanSource::File(file) => Some(file.file.get()),
+            SpanSource::Macro(_) | SpanSource::Builtin(_) => None,
+        }
+    }
+
     /// Returns the code snippet that this [`Span`] refers to or the given default
     /// if the snippet is unavailable.
     ///
@@ -385,10 +497,73 @@ impl<'ast> Span<'ast> {
         new_span
     }
 
+    /// Returns a [`Span`] for the given byte range, relative to the start of
+    /// this span. This can be used to point at a part of a node, like the `mut`
+    /// keyword of a `let` statement:
+    ///
+    /// ```ignore
+    /// // The span of this statement is `stmt_span`
+    /// let mut counter = 0;
+    ///
+    /// let mut_span = stmt_span.subspan(4..7); // -> Some(..), with the snippet `mut`
+    /// ```
+    ///
+    /// `None` is returned, if the snippet of this span is unavailable, if the
+    /// range is out of bounds or if it doesn't start and end at UTF-8 character
+    /// boundaries of the snippet.
+    #[must_use]
+    pub fn subspan(&self, range: Range<usize>) -> Option<Span<'ast>> {
+        let snippet = self.snippet()?;
+        if snippet.len() != self.len() {
+            return None;
+        }
+        // This also validates the range bounds and character boundaries
+        snippet.get(range.clone())?;
+
+        let mut new_span = self.clone();
+        new_span.start = SpanPos(self.start.0 + u32::try_from(range.start).ok()?);
+        new_span.end = SpanPos(self.start.0 + u32::try_from(range.end).ok()?);
+        Some(new_span)
+    }
+
     #[must_use]
     pub fn source(&self) -> SpanSource<'ast> {
         with_cx(self, |cx| cx.span_source(self))
     }
+
+    /// Returns a [`Span`] covering all given spans, from the smallest start
+    /// position to the largest end position. This can be used to emit a single
+    /// diagnostic for a list of nodes, like all variants of an enum.
+    ///
+    /// ```ignore
+    /// let span = Span::join_all(item.variants().iter().map(|var| var.span()));
+    /// ```
+    ///
+    /// `None` is returned, if the iterator is empty, or if the spans come from
+    /// different sources. This can for example happen, if some nodes originate
+    /// from a macro expansion.
+    #[must_use]
+    pub fn join_all<'a>(spans: impl IntoIterator<Item = &'a Span<'ast>>) -> Option<Span<'ast>>
+    where
+        'ast: 'a,
+    {
+        let mut spans = spans.into_iter();
+        let mut joined = spans.next()?.clone();
+        for span in spans {
+            if span.source_id != joined.source_id {
+                return None;
+            }
+            joined.start = SpanPos(joined.start.0.min(span.start.0));
+            joined.end = SpanPos(joined.end.0.max(span.end.0));
+        }
+        Some(joined)
+    }
+
+    /// Returns `true`, if both spans come from the same source and share at
+    /// least one byte.
+    pub(crate) fn overlaps(&self, other: &Span<'ast>) -> bool {
+        self.source_id == other.source_id && self.start.0 < other.end.0 && other.start.0 < self.end.0
+    }
 }
 
 impl<'ast> HasSpan<'ast> for Span<'ast> {
@@ -623,6 +798,23 @@ impl_ident_eq_for!(
 pub trait HasSpan<'ast>: Sealed {
     /// This returns the [`Span`] of the implementing AST node.
     fn span(&self) -> &Span<'ast>;
+
+    /// This returns the [`Span`] of the implementing AST node, if it refers to
+    /// user code. Nodes, which were generated by the compiler, like the imports
+    /// of the prelude, have a span with a [`SpanSource::Builtin`] source. These
+    /// spans have no snippet and shouldn't be used for diagnostics. In that case,
+    /// `None` is returned.
+    ///
+    /// ```
+    /// # use marker_api::prelude::*;
+    /// fn span_or_fallback<'ast>(node: impl HasSpan<'ast>, fallback: &'ast Span<'ast>) -> Span<'ast> {
+    ///     node.try_span().unwrap_or(fallback).clone()
+    /// }
+    /// ```
+    fn try_span(&self) -> Option<&Span<'ast>> {
+        let span = self.span();
+        (!matches!(span.source(), SpanSource::Builtin(_))).then_some(span)
+    }
 }
 
 macro_rules! impl_has_span_via_field {
@@ -656,4 +848,49 @@ impl<'ast, N: HasSpan<'ast>> HasSpan<'ast> for &N {
     fn span(&self) -> &Span<'ast> {
         (*self).span()
     }
+
+    fn try_span(&self) -> Option<&Span<'ast>> {
+        (*self).try_span()
+    }
+}
+
+#[cfg(all(test, feature = "driver-api"))]
+mod test {
+    use super::*;
+
+    fn span(src: u32, start: u32, end: u32) -> Span<'static> {
+        Span::new(SpanSrcId::new(src), false, SpanPos::new(start), SpanPos::new(end))
+    }
+
+    #[test]
+    fn test_len() {
+        let non_empty = span(1, 10, 24);
+        assert_eq!(non_empty.len(), 14);
+        assert!(!non_empty.is_empty());
+
+        let empty = span(1, 7, 7);
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_join_all_same_source() {
+        let spans = [span(1, 10, 20), span(1, 5, 8), span(1, 30, 42), span(1, 12, 15)];
+        let joined = Span::join_all(&spans).unwrap();
+        assert_eq!(joined.start().index(), 5);
+        assert_eq!(joined.end().index(), 42);
+        assert_eq!(joined.source_id(), SpanSrcId::new(1));
+
+        let joined = Span::join_all(&spans[..1]).unwrap();
+        assert_eq!(joined.start().index(), 10);
+        assert_eq!(joined.end().index(), 20);
+    }
+
+    #[test]
+    fn test_join_all_invalid_input() {
+        assert!(Span::join_all(&[]).is_none());
+
+        let spans = [span(1, 10, 20), span(2, 5, 8)];
+        assert!(Span::join_all(&spans).is_none());
+    }
 }
@@ -1,15 +1,23 @@
 use std::cell::{OnceCell, RefCell};
 
-use marker_adapter::context::{AstMapWrapper, MarkerContextDriver, MarkerContextWrapper};
+use marker_adapter::{
+    context::{AstMapWrapper, DiagnosticSink, MarkerContextDriver, MarkerContextWrapper},
+    is_suppressed_by_comment, FileFilter,
+};
 use marker_api::{
-    common::{SpanId, SymbolId},
+    common::{DriverTyId, PatId, SpanId, SymbolId, SynTyId},
     diagnostic::Diagnostic,
     prelude::*,
+    sem::ConstValue,
+    span::SpanSource,
 };
 use rustc_hash::FxHashMap;
 use rustc_hir as hir;
+use rustc_infer::infer::TyCtxtInferExt;
 use rustc_lint::LintStore;
-use rustc_middle::ty::TyCtxt;
+use rustc_middle as mid;
+use rustc_middle::ty::{TyCtxt, TypeVisitableExt};
+use rustc_trait_selection::infer::InferCtxtExt;
 
 use crate::conversion::{marker::MarkerConverter, rustc::RustcConverter};
 
@@ -40,10 +48,21 @@ pub struct RustcContext<'ast, 'tcx> {
     /// which makes it safe to access afterwards.
     ast_cx: OnceCell<&'ast MarkerContext<'ast>>,
     resolved_ty_ids: RefCell<FxHashMap<&'ast str, &'ast [TyDefId]>>,
+    resolved_fn_ids: RefCell<FxHashMap<&'ast str, &'ast [ItemId]>>,
+    trait_impls: RefCell<FxHashMap<ItemId, &'ast [ItemId]>>,
+    ty_impls: RefCell<FxHashMap<TyDefId, &'ast [ItemId]>>,
+    /// Diagnostics in files matching this filter are not emitted.
+    file_filter: &'ast FileFilter,
 }
 
 impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
-    pub fn new(rustc_cx: TyCtxt<'tcx>, lint_store: &'tcx LintStore, storage: &'ast Storage<'ast>) -> &'ast Self {
+    pub fn new(
+        rustc_cx: TyCtxt<'tcx>,
+        lint_store: &'tcx LintStore,
+        storage: &'ast Storage<'ast>,
+        sink: Option<&'ast dyn DiagnosticSink>,
+        file_filter: &'ast FileFilter,
+    ) -> &'ast Self {
         // Create context
         let driver_cx = storage.alloc(Self {
             rustc_cx,
@@ -53,11 +72,15 @@ impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
             rustc_converter: RustcConverter::new(rustc_cx),
             ast_cx: OnceCell::new(),
             resolved_ty_ids: RefCell::default(),
+            resolved_fn_ids: RefCell::default(),
+            trait_impls: RefCell::default(),
+            ty_impls: RefCell::default(),
+            file_filter,
         });
 
         // Create and link `MarkerContext`
         let map = storage.alloc(AstMapWrapper::new(driver_cx));
-        let context = storage.alloc(MarkerContextWrapper::new(driver_cx));
+        let context = storage.alloc(MarkerContextWrapper::new(driver_cx, sink));
         let ast_cx = storage.alloc(
             MarkerContext::builder()
                 .callbacks(context.create_callbacks())
@@ -73,20 +96,153 @@ impl<'ast, 'tcx> RustcContext<'ast, 'tcx> {
         // The `OnceCell` is filled in the new function and can never be not set.
         self.ast_cx.get().unwrap()
     }
+
+    /// Checks if the given span belongs to a file, which is excluded by the
+    /// [`FileFilter`]. Spans from macro expansions are checked at the location
+    /// of the outermost macro invocation.
+    fn is_excluded_span(&self, span: &Span<'ast>) -> bool {
+        match span.outermost_call_site().source() {
+            SpanSource::File(file) => self.file_filter.is_excluded(file.file()),
+            SpanSource::Macro(_) | SpanSource::Builtin(_) => false,
+            _ => unreachable!("all span sources are covered"),
+        }
+    }
+
+    /// Checks if the lint is suppressed at the given span by a `// marker:ignore`
+    /// comment. See [`is_suppressed_by_comment`] for the comment syntax. Spans
+    /// from macro expansions are checked at the location of the outermost macro
+    /// invocation.
+    fn is_suppressed_span(&self, lint: &Lint, span: rustc_span::Span) -> bool {
+        let source_map = self.rustc_cx.sess.source_map();
+        let Ok(lines) = source_map.span_to_lines(span.source_callsite()) else {
+            return false;
+        };
+        let (Some(first), Some(last)) = (lines.lines.first(), lines.lines.last()) else {
+            return false;
+        };
+        let file = &lines.file;
+        let (Some(first_line), Some(last_line)) = (file.get_line(first.line_index), file.get_line(last.line_index))
+        else {
+            return false;
+        };
+        let line_above = first.line_index.checked_sub(1).and_then(|index| file.get_line(index));
+        is_suppressed_by_comment(lint, line_above.as_deref(), &first_line, &last_line)
+    }
+
+    /// Resolves the given path, like `std::vec::Vec`, to the matching items of
+    /// each crate with the given name. The first segment has to be a crate name
+    /// or `crate`. Paths without any segments after the crate are rejected.
+    ///
+    /// This code is inspired by `clippy_utils::def_path_res` without the special
+    /// handling for primitive types and other items
+    fn resolve_path(&self, path: &str) -> Vec<hir::def::Res<hir::def_id::DefId>> {
+        // Path splitting and "validation"
+        let mut splits = path.split("::");
+        let Some(krate_name) = splits.next() else {
+            return vec![];
+        };
+        let segs: Vec<_> = splits.collect();
+        if segs.is_empty() {
+            return vec![];
+        }
+
+        let tcx = self.rustc_cx;
+        let krate_name = rustc_span::Symbol::intern(krate_name);
+        let additional_krate: &[_] = if krate_name == rustc_span::symbol::kw::Crate {
+            &[hir::def_id::LOCAL_CRATE]
+        } else {
+            &[]
+        };
+        let krates = tcx
+            .crates(())
+            .iter()
+            .copied()
+            .chain(std::iter::once(hir::def_id::LOCAL_CRATE))
+            .filter(|id| tcx.crate_name(*id) == krate_name)
+            .chain(additional_krate.iter().copied());
+        let mut searches: Vec<_> = krates
+            .map(rustc_span::def_id::CrateNum::as_def_id)
+            .map(|id| hir::def::Res::Def::<hir::def_id::DefId>(tcx.def_kind(id), id))
+            .collect();
+
+        let mut rest = &segs[..];
+        while let [seg, next_rest @ ..] = rest {
+            rest = next_rest;
+            let seg = rustc_span::Symbol::intern(seg);
+            searches = select_children_with_name(tcx, &searches, seg);
+        }
+
+        searches
+    }
+
+    /// Computes the layout of `ty`. Like [`Self::ty_implements_trait`], types
+    /// depending on generic parameters are rejected, since the `ParamEnv` is
+    /// unknown. Unsized types have no meaningful size and are rejected as well.
+    fn ty_layout(&self, ty: mid::ty::Ty<'tcx>) -> Option<mid::ty::layout::TyAndLayout<'tcx>> {
+        if ty.has_param() || ty.has_infer() {
+            return None;
+        }
+
+        let ty = self.rustc_cx.erase_regions(ty);
+        let layout = self.rustc_cx.layout_of(mid::ty::ParamEnv::reveal_all().and(ty)).ok()?;
+        (!layout.is_unsized()).then_some(layout)
+    }
+
+    /// Checks if `ty` implements the given trait. `generics` are the generic
+    /// arguments of the trait, without `Self`.
+    ///
+    /// Semantic types in Marker don't carry the `ParamEnv` of the item they
+    /// originate from. Types depending on generic parameters are therefore
+    /// rejected, instead of guessing which bounds apply.
+    fn ty_implements_trait(
+        &self,
+        ty: mid::ty::Ty<'tcx>,
+        trait_def_id: hir::def_id::DefId,
+        generics: &[mid::ty::GenericArg<'tcx>],
+    ) -> bool {
+        let tcx = self.rustc_cx;
+        // Inference types only occur in the placeholder of unstable types
+        if ty.has_param() || ty.has_infer() || generics.iter().any(TypeVisitableExt::has_param) {
+            return false;
+        }
+
+        // The host effect parameter of const traits is not exposed in the API,
+        // it has to be filled in here.
+        let trait_generics = tcx.generics_of(trait_def_id);
+        let host_effect = trait_generics.host_effect_index.map(|_| tcx.consts.true_.into());
+        // `+ 1` for the `Self` type
+        if trait_generics.count() != generics.len() + 1 + usize::from(host_effect.is_some()) {
+            return false;
+        }
+
+        let ty = tcx.erase_regions(ty);
+        if ty.has_escaping_bound_vars() {
+            return false;
+        }
+        let args = std::iter::once(ty.into())
+            .chain(generics.iter().map(|arg| tcx.erase_regions(*arg)))
+            .chain(host_effect);
+
+        let infcx = tcx.infer_ctxt().build();
+        infcx
+            .type_implements_trait(trait_def_id, args, mid::ty::ParamEnv::reveal_all())
+            .must_apply_modulo_regions()
+    }
 }
 
 impl<'ast, 'tcx: 'ast> MarkerContextDriver<'ast> for RustcContext<'ast, 'tcx> {
-    fn emit_diag(&'ast self, diag: &Diagnostic<'_, 'ast>) {
+    fn is_diag_suppressed(&'ast self, diag: &Diagnostic<'_, 'ast>) -> bool {
+        self.is_excluded_span(diag.span) || self.is_suppressed_span(diag.lint, self.rustc_converter.to_span(diag.span))
+    }
+
+    fn emit_diag(&'ast self, diag: &Diagnostic<'_, 'ast>) -> bool {
         let Some(id) = self.rustc_converter.try_to_hir_id_from_emission_node(diag.node) else {
-            return;
+            return false;
         };
+        let span = self.rustc_converter.to_span(diag.span);
         let lint = self.rustc_converter.to_lint(diag.lint);
-        self.rustc_cx.struct_span_lint_hir(
-            lint,
-            id,
-            self.rustc_converter.to_span(diag.span),
-            diag.msg().to_string(),
-            |builder| {
+        self.rustc_cx
+            .struct_span_lint_hir(lint, id, span, diag.msg().to_string(), |builder| {
                 for part in diag.parts.get() {
                     match part {
                         marker_api::diagnostic::DiagnosticPart::Help { msg } => {
@@ -109,11 +265,34 @@ impl<'ast, 'tcx: 'ast> MarkerContextDriver<'ast> for RustcContext<'ast, 'tcx> {
                                 self.rustc_converter.to_applicability(*app),
                             );
                         },
+                        marker_api::diagnostic::DiagnosticPart::MultipartSuggestion { msg, edits, app } => {
+                            builder.multipart_suggestion(
+                                msg.get().to_string(),
+                                edits
+                                    .get()
+                                    .iter()
+                                    .map(|edit| (self.rustc_converter.to_span(edit.span), edit.sugg.get().to_string()))
+                                    .collect(),
+                                self.rustc_converter.to_applicability(*app),
+                            );
+                        },
+                        marker_api::diagnostic::DiagnosticPart::Label { msg, span } => {
+                            builder.span_label(self.rustc_converter.to_span(span), msg.get().to_string());
+                        },
                         _ => unreachable!(),
                     }
                 }
-            },
-        );
+                // Rustc reserves the diagnostic code for the lint name. The lint
+                // metadata is therefore added as notes, similar to Clippy's lint URLs.
+                if let Some(category) = diag.lint.category {
+                    let category = format!("{category:?}").to_lowercase();
+                    builder.note(format!("this lint belongs to the `{category}` category"));
+                }
+                if let Some(url) = diag.lint.url {
+                    builder.note(format!("for further information visit {url}"));
+                }
+            });
+        true
     }
 
     fn resolve_ty_ids(&'ast self, path: &str) -> &'ast [TyDefId] {
@@ -122,50 +301,13 @@ impl<'ast, 'tcx: 'ast> MarkerContextDriver<'ast> for RustcContext<'ast, 'tcx> {
             return ids;
         }
 
-        // Path splitting and "validation"
-        let mut splits = path.split("::");
-        let Some(krate_name) = splits.next() else {
-            return &[];
-        };
-        let segs: Vec<_> = splits.collect();
-        if segs.is_empty() {
-            return &[];
-        }
         // This method is only intended to resolve `TyDefId`s, this means we can
         // ignore primitive types and all others which are specificity handled in
         // the `*TyKind` enums. Basically, we only need to find the ids of Enums,
         // Structs, Unions and maybe type aliases.
-        //
-        // This code is inspired by `clippy_utils::def_path_res` without the special
-        // handling for primitive types and other items
         let tcx = self.rustc_cx;
-        let krate_name = rustc_span::Symbol::intern(krate_name);
-        let additional_krate: &[_] = if krate_name == rustc_span::symbol::kw::Crate {
-            &[hir::def_id::LOCAL_CRATE]
-        } else {
-            &[]
-        };
-        let krates = tcx
-            .crates(())
-            .iter()
-            .copied()
-            .chain(std::iter::once(hir::def_id::LOCAL_CRATE))
-            .filter(|id| tcx.crate_name(*id) == krate_name)
-            .chain(additional_krate.iter().copied());
-        let mut searches: Vec<_> = krates
-            .map(rustc_span::def_id::CrateNum::as_def_id)
-            .map(|id| hir::def::Res::Def::<hir::def_id::DefId>(tcx.def_kind(id), id))
-            .collect();
-
-        let mut rest = &segs[..];
-        while let [seg, next_rest @ ..] = rest {
-            rest = next_rest;
-            let seg = rustc_span::Symbol::intern(seg);
-            searches = select_children_with_name(tcx, &searches, seg);
-        }
-
-        // Filtering to only take `DefId`s which are also `TyDefId`s
-        let ids: Vec<_> = searches
+        let ids: Vec<_> = self
+            .resolve_path(path)
             .into_iter()
             .filter_map(|res| res.opt_def_id())
             .filter(|def_id| {
@@ -189,11 +331,172 @@ impl<'ast, 'tcx: 'ast> MarkerContextDriver<'ast> for RustcContext<'ast, 'tcx> {
         ids
     }
 
+    fn resolve_fn_ids(&'ast self, path: &str) -> &'ast [ItemId] {
+        // Caching
+        if let Some(ids) = self.resolved_fn_ids.borrow().get(path) {
+            return ids;
+        }
+
+        // Free functions are found like types, as children of modules. Associated
+        // functions have to be searched in the impls of the parent type or trait.
+        let tcx = self.rustc_cx;
+        let mut candidates: Vec<_> = self
+            .resolve_path(path)
+            .into_iter()
+            .filter_map(|res| res.opt_def_id())
+            .collect();
+        if let Some((parent_path, name)) = path.rsplit_once("::") {
+            let name = rustc_span::Symbol::intern(name);
+            for parent in self.resolve_path(parent_path) {
+                let Some(parent_id) = parent.opt_def_id() else {
+                    continue;
+                };
+                let containers: Vec<_> = match tcx.def_kind(parent_id) {
+                    hir::def::DefKind::Trait => vec![parent_id],
+                    hir::def::DefKind::Struct | hir::def::DefKind::Union | hir::def::DefKind::Enum => {
+                        tcx.inherent_impls(parent_id).to_vec()
+                    },
+                    _ => continue,
+                };
+                for container in containers {
+                    tcx.associated_items(container)
+                        .filter_by_name_unhygienic(name)
+                        .map(|item| item.def_id)
+                        .collect_into(&mut candidates);
+                }
+            }
+        }
+
+        let ids: Vec<_> = candidates
+            .into_iter()
+            .filter(|def_id| matches!(tcx.def_kind(def_id), hir::def::DefKind::Fn | hir::def::DefKind::AssocFn))
+            .map(|def_id| self.marker_converter.to_item_id(def_id))
+            .collect();
+
+        // Allocation and caching
+        let ids = self.storage.alloc_slice(ids);
+        self.resolved_fn_ids
+            .borrow_mut()
+            .insert(self.storage.alloc_str(path), ids);
+        ids
+    }
+
+    fn impls_of_trait(&'ast self, trait_id: ItemId) -> &'ast [ItemId] {
+        // Caching
+        if let Some(ids) = self.trait_impls.borrow().get(&trait_id) {
+            return ids;
+        }
+
+        let tcx = self.rustc_cx;
+        let trait_def_id = self.rustc_converter.to_def_id(trait_id);
+        let ids: Vec<_> = if tcx.def_kind(trait_def_id) == hir::def::DefKind::Trait {
+            tcx.all_impls(trait_def_id)
+                .map(|impl_id| self.marker_converter.to_item_id(impl_id))
+                .collect()
+        } else {
+            vec![]
+        };
+
+        // Allocation and caching
+        let ids = self.storage.alloc_slice(ids);
+        self.trait_impls.borrow_mut().insert(trait_id, ids);
+        ids
+    }
+
+    fn impls_for_type(&'ast self, ty_id: TyDefId) -> &'ast [ItemId] {
+        // Caching
+        if let Some(ids) = self.ty_impls.borrow().get(&ty_id) {
+            return ids;
+        }
+
+        let tcx = self.rustc_cx;
+        let def_id = self.rustc_converter.to_def_id(ty_id);
+        let mut impls = vec![];
+        if matches!(
+            tcx.def_kind(def_id),
+            hir::def::DefKind::Struct | hir::def::DefKind::Union | hir::def::DefKind::Enum
+        ) {
+            impls.extend_from_slice(tcx.inherent_impls(def_id));
+            // Trait impls are indexed by their simplified `Self` type. This
+            // excludes blanket impls, which don't name the type.
+            let simplified = mid::ty::fast_reject::SimplifiedType::Adt(def_id);
+            for trait_def_id in tcx.all_traits() {
+                if let Some(trait_impls) = tcx.trait_impls_of(trait_def_id).non_blanket_impls().get(&simplified) {
+                    impls.extend_from_slice(trait_impls);
+                }
+            }
+        }
+        let ids: Vec<_> = impls
+            .into_iter()
+            .map(|impl_id| self.marker_converter.to_item_id(impl_id))
+            .collect();
+
+        // Allocation and caching
+        let ids = self.storage.alloc_slice(ids);
+        self.ty_impls.borrow_mut().insert(ty_id, ids);
+        ids
+    }
+
+    fn implements_trait(&'ast self, ty: DriverTyId, trait_id: TyDefId, generics: &[DriverTyId]) -> bool {
+        let ty = self.rustc_converter.to_driver_ty_id(ty);
+        let trait_def_id = self.rustc_converter.to_def_id(trait_id);
+        if self.rustc_cx.def_kind(trait_def_id) != hir::def::DefKind::Trait {
+            return false;
+        }
+        let generics: Vec<_> = generics
+            .iter()
+            .map(|id| self.rustc_converter.to_driver_ty_id(*id).into())
+            .collect();
+        self.ty_implements_trait(ty, trait_def_id, &generics)
+    }
+
+    fn ty_is_copy(&'ast self, ty: DriverTyId) -> bool {
+        let ty = self.rustc_converter.to_driver_ty_id(ty);
+        self.rustc_cx
+            .lang_items()
+            .copy_trait()
+            .is_some_and(|trait_def_id| self.ty_implements_trait(ty, trait_def_id, &[]))
+    }
+
+    fn ty_is_sized(&'ast self, ty: DriverTyId) -> bool {
+        let ty = self.rustc_converter.to_driver_ty_id(ty);
+        self.rustc_cx
+            .lang_items()
+            .sized_trait()
+            .is_some_and(|trait_def_id| self.ty_implements_trait(ty, trait_def_id, &[]))
+    }
+
+    fn ty_is_uninhabited(&'ast self, ty: DriverTyId) -> bool {
+        let ty = self.rustc_converter.to_driver_ty_id(ty);
+        let ty = self.rustc_cx.erase_regions(ty);
+        ty.is_privately_uninhabited(self.rustc_cx, mid::ty::ParamEnv::reveal_all())
+    }
+
+    fn ty_size(&'ast self, ty: DriverTyId) -> Option<u64> {
+        let ty = self.rustc_converter.to_driver_ty_id(ty);
+        self.ty_layout(ty).map(|layout| layout.size.bytes())
+    }
+
+    fn ty_align(&'ast self, ty: DriverTyId) -> Option<u64> {
+        let ty = self.rustc_converter.to_driver_ty_id(ty);
+        self.ty_layout(ty).map(|layout| layout.align.abi.bytes())
+    }
+
     fn expr_ty(&'ast self, expr: ExprId) -> marker_api::sem::TyKind<'ast> {
         let hir_id = self.rustc_converter.to_hir_id(expr);
         self.marker_converter.expr_ty(hir_id)
     }
 
+    fn expr_adjusted_ty(&'ast self, expr: ExprId) -> marker_api::sem::TyKind<'ast> {
+        let hir_id = self.rustc_converter.to_hir_id(expr);
+        self.marker_converter.expr_adjusted_ty(hir_id)
+    }
+
+    fn pat_ty(&'ast self, pat: PatId) -> marker_api::sem::TyKind<'ast> {
+        let hir_id = self.rustc_converter.to_hir_id(pat);
+        self.marker_converter.pat_ty(hir_id)
+    }
+
     fn span(&'ast self, span_id: SpanId) -> &'ast Span<'ast> {
         let rustc_span = self.rustc_converter.to_span_from_id(span_id);
         self.storage.alloc(self.marker_converter.to_span(rustc_span))
@@ -241,8 +544,66 @@ impl<'ast, 'tcx: 'ast> MarkerContextDriver<'ast> for RustcContext<'ast, 'tcx> {
         api_str
     }
 
-    fn resolve_method_target(&'ast self, _id: ExprId) -> ItemId {
-        todo!()
+    fn item_const_value(&'ast self, id: ItemId) -> Option<&'ast ConstValue<'ast>> {
+        let def_id = self.rustc_converter.to_def_id(id);
+        self.marker_converter.item_const_value(def_id)
+    }
+
+    fn const_expr_value(&'ast self, expr: ExprId) -> Option<&'ast ConstValue<'ast>> {
+        let hir_id = self.rustc_converter.to_hir_id(expr);
+        // The expression is the value of an anonymous constant, which owns a body
+        let def_id = self.rustc_cx.hir().enclosing_body_owner(hir_id);
+        self.marker_converter.item_const_value(def_id.to_def_id())
+    }
+
+    fn resolve_method_target(&'ast self, id: ExprId) -> Option<ItemId> {
+        let hir_id = self.rustc_converter.to_hir_id(id);
+        self.marker_converter.method_target(hir_id)
+    }
+
+    fn resolve_call_target(&'ast self, id: ExprId) -> Option<ItemId> {
+        let hir_id = self.rustc_converter.to_hir_id(id);
+        self.marker_converter.call_target(hir_id)
+    }
+
+    fn is_union_field_access(&'ast self, id: ExprId) -> bool {
+        let hir_id = self.rustc_converter.to_hir_id(id);
+        self.marker_converter.is_union_field_access(hir_id)
+    }
+
+    fn resolve_syn_ty(&'ast self, id: SynTyId) -> Option<marker_api::sem::TyKind<'ast>> {
+        let hir_id = self.rustc_converter.to_hir_id(id);
+        self.marker_converter.resolve_syn_ty(hir_id)
+    }
+
+    fn variant_discriminant(&'ast self, id: VariantId) -> Option<i128> {
+        let variant_id = self.rustc_converter.to_def_id(id);
+        let adt_def = self.rustc_cx.adt_def(self.rustc_cx.parent(variant_id));
+        if !adt_def.is_enum() {
+            return None;
+        }
+
+        let (_, discr) = adt_def
+            .discriminants(self.rustc_cx)
+            .find(|(idx, _)| adt_def.variant(*idx).def_id == variant_id)?;
+        let (size, signed) = discr.ty.int_size_and_signed(self.rustc_cx);
+        if signed {
+            // The value is stored as an unsigned integer of the discriminant's size
+            #[allow(clippy::cast_possible_wrap)]
+            Some(size.sign_extend(discr.val) as i128)
+        } else {
+            i128::try_from(discr.val).ok()
+        }
+    }
+
+    fn adt_fields(&'ast self, ty: DriverTyId) -> &'ast [marker_api::sem::AdtField<'ast>] {
+        let ty = self.rustc_converter.to_driver_ty_id(ty);
+        self.marker_converter.adt_fields(ty)
+    }
+
+    fn adt_variants(&'ast self, ty: DriverTyId) -> &'ast [marker_api::sem::AdtVariant<'ast>] {
+        let ty = self.rustc_converter.to_driver_ty_id(ty);
+        self.marker_converter.adt_variants(ty)
     }
 }
 
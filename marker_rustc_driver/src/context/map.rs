@@ -4,13 +4,17 @@ use marker_api::{
     common::Level,
     prelude::*,
 };
+use rustc_hir as hir;
 
 use super::RustcContext;
 
 impl<'ast, 'tcx: 'ast> AstMapDriver<'ast> for RustcContext<'ast, 'tcx> {
     fn item(&'ast self, id: ItemId) -> Option<ItemKind<'ast>> {
-        let rustc_id = self.rustc_converter.to_item_id(id);
-        self.marker_converter.item(rustc_id)
+        // Items from external crates are currently not available
+        let def_id = self.rustc_converter.to_def_id(id).as_local()?;
+        self.marker_converter.item(hir::ItemId {
+            owner_id: hir::OwnerId { def_id },
+        })
     }
 
     fn variant(&'ast self, id: VariantId) -> Option<&'ast EnumVariant<'ast>> {
@@ -46,6 +50,17 @@ impl<'ast, 'tcx: 'ast> AstMapDriver<'ast> for RustcContext<'ast, 'tcx> {
         }
     }
 
+    fn parent_expr(&'ast self, id: ExprId) -> Option<ExprKind<'ast>> {
+        let rustc_id = self.rustc_converter.to_hir_id(id);
+        self.marker_converter.parent_expr(rustc_id)
+    }
+
+    fn parent_item(&'ast self, id: ExprId) -> Option<ItemId> {
+        let rustc_id = self.rustc_converter.to_hir_id(id);
+        let owner = self.rustc_cx.hir().get_parent_item(rustc_id);
+        (owner != hir::CRATE_OWNER_ID).then(|| self.marker_converter.to_item_id(owner.to_def_id()))
+    }
+
     fn lint_level_at(&'ast self, api_lint: &'static Lint, node: NodeId) -> Level {
         if let Some(id) = self.rustc_converter.try_to_hir_id_from_emission_node(node) {
             let lint = self.rustc_converter.to_lint(api_lint);
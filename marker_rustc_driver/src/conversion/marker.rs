@@ -15,11 +15,12 @@ use marker_api::{
     ast::{Body, CommonItemData, Crate, EnumVariant, ItemField, ModItem, Visibility as AstVisibility},
     common::{Level, SymbolId},
     prelude::*,
-    sem::{Visibility as SemVisibility, VisibilityKind},
+    sem::{ConstValue, Visibility as SemVisibility, VisibilityKind},
     span::{ExpnInfo, FilePos, Span, SpanSource},
 };
 use rustc_hash::FxHashMap;
 use rustc_hir as hir;
+use rustc_hir::intravisit::{self, Visitor};
 
 /// An interface to convert rustc's IR to marker types.
 ///
@@ -66,6 +67,131 @@ impl<'ast, 'tcx> MarkerConverter<'ast, 'tcx> {
         })
     }
 
+    pub fn expr_adjusted_ty(&self, id: hir::HirId) -> marker_api::sem::TyKind<'ast> {
+        self.with_body(id, |inner| {
+            let ty_check = inner.rustc_ty_check();
+            // The last adjustment determines the final type
+            let ty = ty_check
+                .adjustments()
+                .get(id)
+                .and_then(|adjustments| adjustments.last())
+                .map_or_else(|| ty_check.node_type(id), |adjustment| adjustment.target);
+            inner.to_sem_ty(ty)
+        })
+    }
+
+    pub fn method_target(&self, id: hir::HirId) -> Option<ItemId> {
+        self.with_body(id, |inner| {
+            inner
+                .rustc_ty_check()
+                .type_dependent_def_id(id)
+                .map(|def_id| inner.to_item_id(def_id))
+        })
+    }
+
+    /// Returns the type of the pattern with the given id. Parameters of trait
+    /// functions without a body are identified by their type in the signature.
+    pub fn pat_ty(&self, id: hir::HirId) -> marker_api::sem::TyKind<'ast> {
+        let tcx = self.inner.rustc_cx;
+        let ty = if id == hir::HirId::INVALID {
+            None
+        } else {
+            match tcx.hir_node(id) {
+                hir::Node::Ty(_) => self.resolve_syn_ty(id),
+                _ => Some(self.with_body(id, |inner| {
+                    let ty = inner.rustc_ty_check().node_type(id);
+                    inner.to_sem_ty(ty)
+                })),
+            }
+        };
+
+        ty.unwrap_or_else(|| self.inner.to_unstable_sem_ty())
+    }
+
+    /// Resolves the function called by the call expression with the given id.
+    /// Constructors are normalized to the constructed struct or enum.
+    pub fn call_target(&self, id: hir::HirId) -> Option<ItemId> {
+        let tcx = self.inner.rustc_cx;
+        let hir::ExprKind::Call(callee, _) = tcx.hir().expect_expr(id).kind else {
+            return None;
+        };
+        self.with_body(id, |inner| {
+            let rustc_middle::ty::FnDef(def_id, _) = *inner.rustc_ty_check().expr_ty_adjusted(callee).kind() else {
+                return None;
+            };
+            let def_id = match tcx.def_kind(def_id) {
+                hir::def::DefKind::Ctor(hir::def::CtorOf::Struct, _) => tcx.parent(def_id),
+                hir::def::DefKind::Ctor(hir::def::CtorOf::Variant, _) => tcx.parent(tcx.parent(def_id)),
+                _ => def_id,
+            };
+            Some(inner.to_item_id(def_id))
+        })
+    }
+
+    /// Checks if the field expression with the given id accesses a union field.
+    /// The adjusted type of the operand includes auto-derefs, like for `(&u).a`.
+    pub fn is_union_field_access(&self, id: hir::HirId) -> bool {
+        let hir::ExprKind::Field(operand, _) = self.inner.rustc_cx.hir().expect_expr(id).kind else {
+            return false;
+        };
+        self.with_body(id, |inner| {
+            inner
+                .rustc_ty_check()
+                .expr_ty_adjusted(operand)
+                .ty_adt_def()
+                .is_some_and(rustc_middle::ty::AdtDef::is_union)
+        })
+    }
+
+    pub fn adt_fields(&self, ty: rustc_middle::ty::Ty<'tcx>) -> &'ast [marker_api::sem::AdtField<'ast>] {
+        self.inner.to_sem_adt_fields(ty)
+    }
+
+    pub fn adt_variants(&self, ty: rustc_middle::ty::Ty<'tcx>) -> &'ast [marker_api::sem::AdtVariant<'ast>] {
+        self.inner.to_sem_adt_variants(ty)
+    }
+
+    pub fn item_const_value(&self, def_id: hir::def_id::DefId) -> Option<&'ast ConstValue<'ast>> {
+        self.inner.to_item_const_value(def_id)
+    }
+
+    /// Resolves a syntactic type in an item signature to its semantic type.
+    /// Types inside bodies would require the type check results of the body,
+    /// these are therefore not supported.
+    pub fn resolve_syn_ty(&self, id: hir::HirId) -> Option<marker_api::sem::TyKind<'ast>> {
+        let tcx = self.inner.rustc_cx;
+        let Some(hir::Node::Ty(hir_ty)) = tcx.opt_hir_node(id) else {
+            return None;
+        };
+
+        for (_, node) in tcx.hir().parent_iter(id) {
+            match node {
+                hir::Node::Expr(_) | hir::Node::Stmt(_) | hir::Node::Local(_) => return None,
+                hir::Node::Item(_) | hir::Node::TraitItem(_) | hir::Node::ImplItem(_) | hir::Node::ForeignItem(_) => {
+                    break;
+                },
+                _ => {},
+            }
+        }
+
+        // Rustc reports an error for inferred types in item signatures, when
+        // they are lowered.
+        let mut finder = InferFinder::default();
+        finder.visit_ty(hir_ty);
+        if finder.found {
+            return None;
+        }
+
+        let owner = tcx.hir().get_parent_item(id).def_id;
+        let ty = rustc_hir_analysis::hir_ty_to_ty(tcx, hir_ty);
+
+        let old_owner = self.inner.rustc_sig_owner.replace(Some(owner));
+        let res = self.inner.to_sem_ty(ty);
+        self.inner.rustc_sig_owner.replace(old_owner);
+
+        Some(res)
+    }
+
     forward_to_inner!(pub fn to_lint_level(&self, level: rustc_lint::Level) -> Level);
 
     pub fn body(&self, id: hir::BodyId) -> &'ast Body<'ast> {
@@ -118,6 +244,29 @@ impl<'ast, 'tcx> MarkerConverter<'ast, 'tcx> {
         })
     }
 
+    pub fn parent_expr(&self, id: hir::HirId) -> Option<ExprKind<'ast>> {
+        for (parent_id, node) in self.inner.rustc_cx.hir().parent_iter(id) {
+            match node {
+                hir::Node::Expr(_) => {
+                    // The expression has been converted as part of its body. All
+                    // parent expressions are therefore in the cache. Expressions,
+                    // which are only used for desugaring and not represented in
+                    // Marker's AST, are not in the cache and skipped.
+                    let parent_id = self.inner.to_expr_id(parent_id);
+                    if let Some(expr) = self.inner.exprs.borrow().get(&parent_id) {
+                        return Some(*expr);
+                    }
+                },
+                hir::Node::Item(_) | hir::Node::TraitItem(_) | hir::Node::ImplItem(_) | hir::Node::ForeignItem(_) => {
+                    return None;
+                },
+                _ => {},
+            }
+        }
+
+        None
+    }
+
     pub fn variant(&self, id: VariantId) -> Option<&'ast EnumVariant<'ast>> {
         // Lint crates only gain access to ids of fields and variants, that are
         // in scope. Marker's conversion first transforms the entire crate. Any enums
@@ -137,6 +286,7 @@ impl<'ast, 'tcx> MarkerConverter<'ast, 'tcx> {
         self.inner.fields.borrow().get(&id).copied()
     }
 
+    forward_to_inner!(pub fn to_item_id(&self, id: hir::def_id::DefId) -> ItemId);
     forward_to_inner!(pub fn to_ty_def_id(&self, id: hir::def_id::DefId) -> TyDefId);
     forward_to_inner!(pub fn to_span(&self, rustc_span: rustc_span::Span) -> Span<'ast>);
     forward_to_inner!(pub fn to_span_source(&self, rust_span: rustc_span::Span) -> SpanSource<'ast>);
@@ -151,6 +301,32 @@ impl<'ast, 'tcx> MarkerConverter<'ast, 'tcx> {
     ) -> &'ast Crate<'ast>);
 }
 
+/// A visitor checking if a type contains an inferred type, like `_` or `[u8; _]`.
+#[derive(Default)]
+struct InferFinder {
+    found: bool,
+}
+
+impl<'v> Visitor<'v> for InferFinder {
+    fn visit_ty(&mut self, ty: &'v hir::Ty<'v>) {
+        if matches!(ty.kind, hir::TyKind::Infer) {
+            self.found = true;
+        } else {
+            intravisit::walk_ty(self, ty);
+        }
+    }
+
+    fn visit_infer(&mut self, _inf: &'v hir::InferArg) {
+        self.found = true;
+    }
+
+    fn visit_array_length(&mut self, len: &'v hir::ArrayLen) {
+        if matches!(len, hir::ArrayLen::Infer(..)) {
+            self.found = true;
+        }
+    }
+}
+
 macro_rules! forward_to_inner {
     (pub fn $fn_name:ident(&self $(, $arg_name:ident: $arg_ty:ty)* $(,)?) -> $ret_ty:ty) => {
         pub fn $fn_name(&self $(, $arg_name: $arg_ty)*) -> $ret_ty {
@@ -212,6 +388,10 @@ struct MarkerConverterInner<'ast, 'tcx> {
     /// Requested on demand from rustc using a [`hir::BodyId`] see
     /// [`MarkerConverterInner::rustc_body`] for more information
     rustc_ty_check: RefCell<Option<&'tcx rustc_middle::ty::TypeckResults<'tcx>>>,
+    /// This holds the item, whose signature is currently being resolved by
+    /// [`MarkerConverter::resolve_syn_ty`]. It's used to look up generic
+    /// parameters, which would otherwise be taken from the body.
+    rustc_sig_owner: RefCell<Option<hir::def_id::LocalDefId>>,
 }
 
 // General util functions
@@ -232,6 +412,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             lang_item_map: RefCell::default(),
             rustc_body: RefCell::default(),
             rustc_ty_check: RefCell::default(),
+            rustc_sig_owner: RefCell::default(),
         };
 
         s.fill_create_lang_item_map();
@@ -340,9 +521,11 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     .build(),
             )
             .ident(ident)
+            .attrs(self.to_attrs(hir::CRATE_HIR_ID))
             .build();
         ModItem::builder()
             .data(data)
+            .inner_span(self.to_span_id(krate_mod.spans.inner_span))
             .items(self.to_items(krate_mod.item_ids))
             .build()
     }
@@ -1,17 +1,18 @@
 use marker_api::{
     ast::{
-        ArrayExpr, AsExpr, AssignExpr, AwaitExpr, BinaryOpExpr, BinaryOpKind, BlockExpr, BoolLitExpr, BreakExpr,
-        CallExpr, CaptureKind, CharLitExpr, ClosureExpr, ClosureParam, CommonExprData, ConstExpr, ContinueExpr,
-        CtorExpr, CtorField, ExprKind, ExprPrecedence, FieldExpr, FloatLitExpr, FloatSuffix, ForExpr, IfExpr,
-        IndexExpr, IntLitExpr, IntSuffix, LetExpr, LoopExpr, MatchArm, MatchExpr, MethodExpr, PathExpr, RangeExpr,
-        RefExpr, ReturnExpr, StrLitData, StrLitExpr, TryExpr, TupleExpr, UnaryOpExpr, UnaryOpKind, UnstableExpr,
-        WhileExpr,
+        ArrayExpr, AsExpr, AssignExpr, AwaitExpr, BinOpResolution, BinaryOpExpr, BinaryOpKind, BlockExpr, BoolLitExpr,
+        BreakExpr, CallExpr, CaptureKind, CharLitExpr, ClosureCapture, ClosureCaptureKind, ClosureExpr, ClosureParam,
+        CommonExprData, ConstExpr, ContinueExpr, CtorExpr, CtorField, ExprKind, ExprPrecedence, FieldExpr,
+        FloatLitExpr, FloatSuffix, ForExpr, IfExpr, IndexExpr, IntLitExpr, IntSuffix, LetExpr, LoopExpr, MatchArm,
+        MatchExpr, MatchSource, MethodExpr, PathExpr, RangeExpr, RefExpr, ReturnExpr, StrLitData, StrLitExpr, TryExpr,
+        TupleExpr, UnaryOpExpr, UnaryOpKind, UnstableExpr, WhileExpr, YieldExpr,
     },
     common::{Safety, Syncness},
     span::Ident,
 };
 use rustc_hash::FxHashMap;
 use rustc_hir as hir;
+use rustc_middle as mid;
 use std::str::FromStr;
 
 use crate::conversion::marker::MarkerConverterInner;
@@ -52,6 +53,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 self.to_expr(left),
                 self.to_expr(right),
                 self.to_bin_op_kind(op),
+                self.to_bin_op_resolution(expr),
             ))),
             hir::ExprKind::Unary(op, expr) => {
                 ExprKind::UnaryOp(self.alloc(UnaryOpExpr::new(data, self.to_expr(expr), self.to_unary_op_kind(*op))))
@@ -211,9 +213,17 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             hir::ExprKind::Match(_scrutinee, _arms, hir::MatchSource::ForLoopDesugar) => {
                 ExprKind::For(self.alloc(self.to_for_from_desugar(expr)))
             },
-            hir::ExprKind::Match(scrutinee, arms, hir::MatchSource::Normal | hir::MatchSource::FormatArgs) => {
-                ExprKind::Match(self.alloc(MatchExpr::new(data, self.to_expr(scrutinee), self.to_match_arms(arms))))
-            },
+            hir::ExprKind::Match(
+                scrutinee,
+                arms,
+                source @ (hir::MatchSource::Normal | hir::MatchSource::FormatArgs),
+            ) => ExprKind::Match(self.alloc(MatchExpr::new(
+                data,
+                self.to_expr(scrutinee),
+                self.to_match_arms(arms),
+                self.to_match_source(*source),
+                self.to_exhaustive_without_wildcard(scrutinee, arms),
+            ))),
             hir::ExprKind::Match(_scrutinee, [_early_return, _continue], hir::MatchSource::TryDesugar(_)) => {
                 ExprKind::Try(self.alloc(self.to_try_expr_from_desugar(expr)))
             },
@@ -246,6 +256,9 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             hir::ExprKind::Ret(expr) => {
                 ExprKind::Return(self.alloc(ReturnExpr::new(data, expr.map(|expr| self.to_expr(expr)))))
             },
+            hir::ExprKind::Yield(value, hir::YieldSource::Yield) => {
+                ExprKind::Yield(self.alloc(YieldExpr::new(data, self.to_expr(value))))
+            },
             hir::ExprKind::Loop(block, label, source, _span) => match source {
                 hir::LoopSource::Loop => ExprKind::Loop(self.alloc(LoopExpr::new(
                     data,
@@ -387,6 +400,15 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
     }
 
+    /// Binary operators are type dependent, if they call the method of an
+    /// operator trait. Built-in operators don't have a type dependent target.
+    fn to_bin_op_resolution(&self, expr: &hir::Expr<'tcx>) -> BinOpResolution {
+        match self.rustc_ty_check().type_dependent_def_id(expr.hir_id) {
+            Some(def_id) => BinOpResolution::Overloaded(self.to_item_id(def_id)),
+            None => BinOpResolution::Builtin,
+        }
+    }
+
     #[must_use]
     fn to_unary_op_kind(&self, op: hir::UnOp) -> UnaryOpKind {
         match op {
@@ -413,9 +435,29 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             self.to_pat(arm.pat),
             guard,
             self.to_expr(arm.body),
+            self.is_irrefutable_pat(arm.pat),
         )
     }
 
+    /// See [`MatchExpr::is_exhaustive_without_wildcard`].
+    fn to_exhaustive_without_wildcard(&self, scrutinee: &hir::Expr<'tcx>, arms: &[hir::Arm<'tcx>]) -> Option<bool> {
+        let is_wildcard = |arm: &hir::Arm<'_>| matches!(arm.pat.kind, hir::PatKind::Wild) && arm.guard.is_none();
+        if !arms.iter().any(is_wildcard) {
+            return None;
+        }
+
+        // Arms with guards don't cover their pattern for certain
+        let pats: Vec<_> = arms
+            .iter()
+            .filter(|arm| arm.guard.is_none() && !is_wildcard(arm))
+            .map(|arm| arm.pat)
+            .collect();
+        if pats.iter().any(|pat| self.is_irrefutable_pat(pat)) {
+            return Some(true);
+        }
+        self.pats_cover_ty(&pats, self.rustc_ty_check().expr_ty(scrutinee))
+    }
+
     fn to_expr_from_closure(
         &self,
         data: CommonExprData<'ast>,
@@ -459,10 +501,12 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             },
             hir::ClosureKind::Coroutine(
                 hir::CoroutineKind::Desugared(hir::CoroutineDesugaring::Gen | hir::CoroutineDesugaring::AsyncGen, _)
-                | hir::CoroutineKind::Desugared(hir::CoroutineDesugaring::Async, hir::CoroutineSource::Closure)
-                | hir::CoroutineKind::Coroutine(_),
+                | hir::CoroutineKind::Desugared(hir::CoroutineDesugaring::Async, hir::CoroutineSource::Closure),
             ) => ExprKind::Unstable(self.alloc(UnstableExpr::new(data, ExprPrecedence::Closure))),
-            hir::ClosureKind::Closure => ExprKind::Closure(self.alloc(self.to_closure_expr(data, closure))),
+            // Coroutines are written as closures, which contain `yield` expressions
+            hir::ClosureKind::Closure | hir::ClosureKind::Coroutine(hir::CoroutineKind::Coroutine(_)) => {
+                ExprKind::Closure(self.alloc(self.to_closure_expr(data, closure)))
+            },
         }
     }
 
@@ -496,9 +540,50 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             params,
             return_ty,
             self.to_body_id(closure.body),
+            self.to_closure_captures(closure),
+            matches!(closure.kind, hir::ClosureKind::Coroutine(_)),
         )
     }
 
+    fn to_closure_captures(&self, closure: &hir::Closure<'tcx>) -> &'ast [ClosureCapture<'ast>] {
+        let tcx = self.rustc_cx;
+        let Some(min_captures) = tcx.typeck(closure.def_id).closure_min_captures.get(&closure.def_id) else {
+            return &[];
+        };
+        let param_env = tcx.param_env(closure.def_id);
+
+        self.alloc_slice(min_captures.iter().map(|(var, places)| {
+            // A variable can be captured with several disjoint places, the
+            // strongest capture kind is used for the variable.
+            let (kind, place) = places
+                .iter()
+                .map(|place| {
+                    let kind = match place.info.capture_kind {
+                        mid::ty::UpvarCapture::ByRef(mid::ty::BorrowKind::ImmBorrow) => ClosureCaptureKind::Ref,
+                        mid::ty::UpvarCapture::ByRef(
+                            mid::ty::BorrowKind::MutBorrow | mid::ty::BorrowKind::UniqueImmBorrow,
+                        ) => ClosureCaptureKind::MutRef,
+                        mid::ty::UpvarCapture::ByValue if place.place.ty().is_copy_modulo_regions(tcx, param_env) => {
+                            ClosureCaptureKind::Copy
+                        },
+                        mid::ty::UpvarCapture::ByValue => ClosureCaptureKind::Move,
+                    };
+                    (kind, place)
+                })
+                .max_by_key(|(kind, _)| *kind)
+                .expect("every captured variable has at least one captured place");
+            ClosureCapture::new(self.to_var_id(*var), kind, self.to_sem_ty(place.place.base_ty))
+        }))
+    }
+
+    fn to_match_source(&self, source: hir::MatchSource) -> MatchSource {
+        match source {
+            hir::MatchSource::Normal => MatchSource::Normal,
+            hir::MatchSource::FormatArgs => MatchSource::FormatArgs,
+            _ => unreachable!("other desugars are converted to dedicated expressions"),
+        }
+    }
+
     fn to_capture_kind(&self, capture: hir::CaptureBy) -> CaptureKind {
         match capture {
             rustc_ast::CaptureBy::Value { .. } => CaptureKind::Move,
@@ -4,14 +4,24 @@ use marker_api::ast::{
     WhereClauseKind,
 };
 use rustc_hir as hir;
+use rustc_span::symbol::kw;
 
 use crate::conversion::marker::MarkerConverterInner;
 
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     #[must_use]
     pub fn to_lifetime(&self, rust_lt: &hir::Lifetime) -> Option<Lifetime<'ast>> {
+        // Elided lifetimes, like in `&T`, either have no name or an empty span,
+        // if rustc introduced a `'_` for them. This check comes first, since
+        // elided lifetimes in statics and constants resolve to `'static`.
+        if rust_lt.ident.name == kw::Empty || rust_lt.ident.span.is_empty() {
+            return None;
+        }
+
         let kind = match rust_lt.res {
-            hir::LifetimeName::Param(_) if rust_lt.is_anonymous() => return None,
+            // An explicit `'_` in a function signature is resolved to a fresh
+            // lifetime parameter. It should be distinguishable from elided lifetimes.
+            hir::LifetimeName::Param(_) if rust_lt.ident.name == kw::UnderscoreLifetime => LifetimeKind::Infer,
             hir::LifetimeName::Param(local_id) => {
                 LifetimeKind::Label(self.to_symbol_id(rust_lt.ident.name), self.to_generic_id(local_id))
             },
@@ -1,14 +1,16 @@
 use marker_api::{
     ast::{
-        AdtKind, AssocItemKind, Body, CommonItemData, CommonPatData, ConstItem, EnumItem, EnumVariant, ExternBlockItem,
-        ExternCrateItem, ExternItemKind, FnItem, FnParam, IdentPat, ImplItem, ItemField, ItemKind, ModItem, PatKind,
-        StaticItem, StructItem, TraitItem, TyAliasItem, UnionItem, UnstableItem, UseItem, UseKind, Visibility,
+        AdtKind, AssocItemKind, Body, BodyParam, CommonItemData, CommonPatData, ConstItem, EnumItem, EnumVariant,
+        ExternBlockItem, ExternCrateItem, ExternItemKind, FnContext, FnItem, FnParam, IdentPat, ImplItem, ItemField,
+        ItemKind, ModItem, PatKind, RestrictedPath, StaticItem, StructItem, TraitItem, TyAliasItem, UnionItem,
+        UnstableItem, UseItem, UseKind, Visibility,
     },
     common::{Abi, Constness, Mutability, Safety, Syncness},
     prelude::*,
     CtorBlocker,
 };
 use rustc_hir as hir;
+use rustc_middle as mid;
 
 use crate::conversion::marker::MarkerConverterInner;
 
@@ -24,7 +26,11 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     }
 
     pub fn to_item_from_id(&self, item: hir::ItemId) -> Option<ItemKind<'ast>> {
-        let item = self.rustc_cx.hir().item(item);
+        // The ID might belong to an associated or foreign item, which are
+        // not stored as `hir::Item`s.
+        let Some(hir::Node::Item(item)) = self.rustc_cx.opt_hir_node_by_def_id(item.owner_id.def_id) else {
+            return None;
+        };
         self.to_item(item)
     }
 
@@ -51,6 +57,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             .span(self.to_span_id(rustc_item.span))
             .vis(self.to_visibility(rustc_item.owner_id.def_id, rustc_item.vis_span))
             .ident(ident)
+            .attrs(self.to_attrs(rustc_item.hir_id()))
             .build();
         let item =
             match &rustc_item.kind {
@@ -63,7 +70,12 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                         hir::UseKind::Glob => UseKind::Glob,
                         hir::UseKind::ListStem => return None,
                     };
-                    ItemKind::Use(self.alloc(UseItem::new(data, self.to_path(path), use_kind)))
+                    ItemKind::Use(self.alloc(UseItem::new(
+                        data,
+                        self.to_path(path),
+                        self.to_use_path_target(&path.res),
+                        use_kind,
+                    )))
                 },
                 hir::ItemKind::Static(rustc_ty, rustc_mut, rustc_body_id) => ItemKind::Static(self.alloc({
                     StaticItem::new(
@@ -87,7 +99,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                         data,
                         generics,
                         fn_sig,
-                        false,
+                        FnContext::Free,
                         hir::TraitFn::Provided(*body_id),
                     )))
                 },
@@ -95,6 +107,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     self.alloc(
                         ModItem::builder()
                             .data(data)
+                            .inner_span(self.to_span_id(rustc_mod.spans.inner_span))
                             .items(self.to_items(rustc_mod.item_ids))
                             .build(),
                     ),
@@ -124,6 +137,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                             self.to_span_id(variant.span),
                             self.to_adt_kind(&variant.data),
                             variant.disr_expr.map(|anon| self.to_const_expr(anon)),
+                            self.to_attrs(variant.hir_id),
                         )
                     }));
                     self.variants
@@ -182,18 +196,52 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     fn to_visibility(&self, owner_id: hir::def_id::LocalDefId, vis_span: rustc_span::Span) -> Visibility<'ast> {
         let span = (!vis_span.is_empty()).then(|| self.to_span_id(vis_span));
 
+        let restricted_path = span.and_then(|_| self.to_restricted_path(owner_id, vis_span));
+
         Visibility::builder()
             .span(span)
             .sem(self.to_sem_visibility(owner_id, span.is_some()))
+            .restricted_path(restricted_path)
             .build()
     }
 
+    /// Rustc's HIR only stores the span of the visibility. The span of the
+    /// path is therefore determined from the snippet of the visibility. The
+    /// span of the entire visibility is used, if the snippet is not available.
+    fn to_restricted_path(
+        &self,
+        owner_id: hir::def_id::LocalDefId,
+        vis_span: rustc_span::Span,
+    ) -> Option<&'ast RestrictedPath<'ast>> {
+        let mid::ty::Visibility::Restricted(module) = self.rustc_cx.visibility(owner_id) else {
+            return None;
+        };
+
+        // This covers `pub(crate)`, `pub(super)`, `pub(self)` and `pub(in <path>)`
+        let path_span = || {
+            let snippet = self.rustc_cx.sess.source_map().span_to_snippet(vis_span).ok()?;
+            let inner = snippet
+                .strip_prefix("pub")?
+                .trim_start()
+                .strip_prefix('(')?
+                .strip_suffix(')')?
+                .trim();
+            let path = inner.strip_prefix("in").map_or(inner, str::trim_start);
+            // The path is the last part of the snippet
+            let start = snippet.rfind(path)?;
+            Some(vis_span.from_inner(rustc_span::InnerSpan::new(start, start + path.len())))
+        };
+        let path_span = path_span().unwrap_or(vis_span);
+
+        Some(self.alloc(RestrictedPath::new(self.to_span_id(path_span), self.to_item_id(module))))
+    }
+
     fn to_fn_item(
         &self,
         data: CommonItemData<'ast>,
         generics: &hir::Generics<'tcx>,
         fn_sig: &hir::FnSig<'tcx>,
-        is_extern: bool,
+        context: FnContext,
         body_info: hir::TraitFn<'_>,
     ) -> FnItem<'ast> {
         let api_body = match &body_info {
@@ -228,9 +276,10 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             self.to_constness(header.constness),
             self.to_syncness(header.asyncness),
             self.to_safety(header.unsafety),
-            is_extern,
+            false,
             fn_sig.decl.implicit_self.has_implicit_self(),
             self.to_abi(header.abi),
+            context,
             params,
             return_ty,
             api_body,
@@ -244,7 +293,9 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     FnParam::new(
                         self.to_span_id(ident.span.to(ty.span)),
                         PatKind::Ident(self.alloc(IdentPat::new(
-                            CommonPatData::new(self.to_span_id(ident.span)),
+                            // Parameters without a body don't have a pattern. The type
+                            // is used, to resolve the pattern type from the signature.
+                            CommonPatData::new(self.to_pat_id(ty.hir_id), self.to_span_id(ident.span)),
                             self.to_symbol_id(ident.name),
                             self.to_var_id(hir::HirId::INVALID),
                             Mutability::Unmut,
@@ -282,6 +333,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 self.to_symbol_id(field.ident.name),
                 self.to_syn_ty(field.ty),
                 self.to_span_id(field.span),
+                self.to_attrs(field.hir_id),
             )
         }));
 
@@ -303,7 +355,8 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             return match item {
                 ItemKind::Static(data) => ExternItemKind::Static(data, CtorBlocker::new()),
                 ItemKind::Fn(data) => ExternItemKind::Fn(data, CtorBlocker::new()),
-                _ => unreachable!("only static and `Static` and `Fn` items can be found a foreign item id"),
+                ItemKind::Unstable(data) => ExternItemKind::Unstable(data, CtorBlocker::new()),
+                _ => unreachable!("only `Static`, `Fn` and `Unstable` items can be found a foreign item id"),
             };
         }
 
@@ -313,6 +366,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             .span(self.to_span_id(rustc_item.span))
             .vis(self.to_visibility(foreign_item.owner_id.def_id, foreign_item.vis_span))
             .ident(self.to_ident(rustc_item.ident))
+            .attrs(self.to_attrs(foreign_item.hir_id()))
             .build();
         let item = match &foreign_item.kind {
             hir::ForeignItemKind::Fn(decl, idents, generics) => {
@@ -331,6 +385,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                         true,
                         decl.implicit_self.has_implicit_self(),
                         abi,
+                        FnContext::Extern,
                         self.to_fn_params(decl, hir::TraitFn::Required(idents)),
                         return_ty,
                         None,
@@ -347,9 +402,13 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 )),
                 CtorBlocker::new(),
             ),
-            hir::ForeignItemKind::Type => {
-                todo!("foreign type are currently sadly not supported. See rust-marker/marker#182")
-            },
+            hir::ForeignItemKind::Type => ExternItemKind::Unstable(
+                self.alloc(UnstableItem::new(
+                    data,
+                    Some(self.to_symbol_id(rustc_span::sym::extern_types)),
+                )),
+                CtorBlocker::new(),
+            ),
         };
 
         self.items.borrow_mut().insert(id, item.as_item());
@@ -382,6 +441,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     .build(),
             )
             .ident(self.to_ident(rustc_item.ident))
+            .attrs(self.to_attrs(trait_item.hir_id()))
             .build();
 
         let item = match &trait_item.kind {
@@ -394,7 +454,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 CtorBlocker::new(),
             ),
             hir::TraitItemKind::Fn(fn_sig, trait_fn) => AssocItemKind::Fn(
-                self.alloc(self.to_fn_item(data, trait_item.generics, fn_sig, false, *trait_fn)),
+                self.alloc(self.to_fn_item(data, trait_item.generics, fn_sig, FnContext::TraitDecl, *trait_fn)),
                 CtorBlocker::new(),
             ),
             hir::TraitItemKind::Type(bounds, ty) => AssocItemKind::TyAlias(
@@ -436,6 +496,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             .span(self.to_span_id(rustc_item.span))
             .vis(self.to_visibility(rustc_item.id.owner_id.def_id, impl_item.vis_span))
             .ident(self.to_ident(rustc_item.ident))
+            .attrs(self.to_attrs(impl_item.hir_id()))
             .build();
 
         let item = match &impl_item.kind {
@@ -447,16 +508,24 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 )),
                 CtorBlocker::new(),
             ),
-            hir::ImplItemKind::Fn(fn_sig, body_id) => AssocItemKind::Fn(
-                self.alloc(self.to_fn_item(
-                    data,
-                    impl_item.generics,
-                    fn_sig,
-                    false,
-                    hir::TraitFn::Provided(*body_id),
-                )),
-                CtorBlocker::new(),
-            ),
+            hir::ImplItemKind::Fn(fn_sig, body_id) => {
+                let parent = self.rustc_cx.hir().get_parent_item(impl_item.hir_id());
+                let context = if self.rustc_cx.trait_id_of_impl(parent.to_def_id()).is_some() {
+                    FnContext::TraitImpl
+                } else {
+                    FnContext::InherentImpl
+                };
+                AssocItemKind::Fn(
+                    self.alloc(self.to_fn_item(
+                        data,
+                        impl_item.generics,
+                        fn_sig,
+                        context,
+                        hir::TraitFn::Provided(*body_id),
+                    )),
+                    CtorBlocker::new(),
+                )
+            },
             hir::ImplItemKind::Type(ty) => AssocItemKind::TyAlias(
                 self.alloc({
                     TyAliasItem::new(
@@ -482,8 +551,17 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
 
         self.with_body(body.id(), || {
-            let owner = self.to_item_id(self.rustc_cx.hir().body_owner_def_id(body.id()));
-            let api_body = self.alloc(Body::new(owner, self.to_expr(body.value)));
+            let owner_id = self.rustc_cx.hir().body_owner_def_id(body.id());
+            let owner = self.to_item_id(owner_id);
+            let is_const = self.rustc_cx.hir().body_const_context(owner_id).is_some();
+            let params = self.alloc_slice(body.params.iter().map(|param| {
+                BodyParam::new(
+                    self.to_span_id(param.span),
+                    self.to_pat(param.pat),
+                    self.to_sem_ty(self.rustc_ty_check().pat_ty(param.pat)),
+                )
+            }));
+            let api_body = self.alloc(Body::new(owner, params, self.to_expr(body.value), is_const));
             self.bodies.borrow_mut().insert(id, api_body);
             api_body
         })
@@ -4,6 +4,8 @@ use marker_api::ast::{
 };
 use rustc_hash::FxHashMap;
 use rustc_hir as hir;
+use rustc_middle as mid;
+use std::borrow::Borrow;
 
 use crate::conversion::marker::MarkerConverterInner;
 
@@ -24,10 +26,11 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         lhs_map: &FxHashMap<hir::HirId, ExprKind<'ast>>,
     ) -> PatKind<'ast> {
         // Here we don't need to take special care for caching, as marker patterns
-        // don't have IDs and can't be requested individually. Instead patterns are
-        // stored as part of their parent expressions or items. Not needing to deal
-        // with caching makes this implementation simpler.
-        let data = CommonPatData::new(self.to_span_id(pat.span));
+        // can't be requested individually. Instead patterns are stored as part of
+        // their parent expressions or items. Their IDs are only used to request
+        // their type. Not needing to deal with caching makes this implementation
+        // simpler.
+        let data = CommonPatData::new(self.to_pat_id(pat.hir_id), self.to_span_id(pat.span));
 
         match &pat.kind {
             hir::PatKind::Wild => PatKind::Wildcard(self.alloc(WildcardPat::new(data))),
@@ -96,7 +99,8 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 let pats = if let Some(rest_pos) = dotdot.as_opt_usize() {
                     let (start, end) = pats.split_at(rest_pos);
                     // This is a dummy span, it's dirty, but at least works for the mean time :)
-                    self.chain_pats(start, self.new_rest_pat(rustc_span::DUMMY_SP), end, lhs_map)
+                    let rest = self.new_rest_pat(hir::HirId::INVALID, rustc_span::DUMMY_SP);
+                    self.chain_pats(start, rest, end, lhs_map)
                 } else {
                     self.alloc_slice(pats.iter().map(|pat| self.to_pat_with_hls(pat, lhs_map)))
                 };
@@ -113,7 +117,7 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             ),
             hir::PatKind::Slice(start, wild, end) => {
                 let elements = if let Some(wild) = wild {
-                    self.chain_pats(start, self.new_rest_pat(wild.span), end, lhs_map)
+                    self.chain_pats(start, self.new_rest_pat(wild.hir_id, wild.span), end, lhs_map)
                 } else {
                     assert!(end.is_empty());
                     self.alloc_slice(start.iter().map(|pat| self.to_pat_with_hls(pat, lhs_map)))
@@ -166,14 +170,159 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         self.alloc_slice(api_pats)
     }
 
-    fn new_rest_pat(&self, span: rustc_span::Span) -> PatKind<'ast> {
-        let data = CommonPatData::new(self.to_span_id(span));
+    /// Rest patterns in tuples don't have a [`hir::HirId`], [`hir::HirId::INVALID`]
+    /// should be used for them.
+    fn new_rest_pat(&self, id: hir::HirId, span: rustc_span::Span) -> PatKind<'ast> {
+        let data = CommonPatData::new(self.to_pat_id(id), self.to_span_id(span));
         PatKind::Rest(self.alloc(RestPat::new(data)))
     }
 
     #[must_use]
     pub fn to_place_pat_from_expr(&self, expr: &hir::Expr<'tcx>) -> PatKind<'ast> {
-        let data = CommonPatData::new(self.to_span_id(expr.span));
+        let data = CommonPatData::new(self.to_pat_id(expr.hir_id), self.to_span_id(expr.span));
         PatKind::Place(self.alloc(PlacePat::builder().data(data).place(self.to_expr(expr)).build()))
     }
+
+    /// Returns `true`, if the pattern matches every value of its type. This is
+    /// an approximation, which doesn't perform a full exhaustiveness check.
+    /// Literal and range patterns are always considered refutable.
+    #[must_use]
+    pub fn is_irrefutable_pat(&self, pat: &hir::Pat<'tcx>) -> bool {
+        match pat.kind {
+            hir::PatKind::Wild | hir::PatKind::Binding(.., None) => true,
+            hir::PatKind::Binding(.., Some(pat)) | hir::PatKind::Ref(pat, _) | hir::PatKind::Box(pat) => {
+                self.is_irrefutable_pat(pat)
+            },
+            hir::PatKind::Tuple(pats, _) => pats.iter().all(|pat| self.is_irrefutable_pat(pat)),
+            hir::PatKind::Or(pats) => {
+                pats.iter().any(|pat| self.is_irrefutable_pat(pat))
+                    || self.pats_cover_ty(pats, self.rustc_ty_check().pat_ty(pat)) == Some(true)
+            },
+            hir::PatKind::Path(qpath) | hir::PatKind::TupleStruct(qpath, ..) | hir::PatKind::Struct(qpath, ..) => {
+                let is_single_ctor = match self.rustc_ty_check().qpath_res(&qpath, pat.hir_id) {
+                    hir::def::Res::Def(
+                        hir::def::DefKind::Struct
+                        | hir::def::DefKind::Union
+                        | hir::def::DefKind::TyAlias
+                        | hir::def::DefKind::AssocTy
+                        | hir::def::DefKind::Ctor(hir::def::CtorOf::Struct, _),
+                        _,
+                    )
+                    | hir::def::Res::SelfCtor(_)
+                    | hir::def::Res::SelfTyAlias { .. } => true,
+                    hir::def::Res::Def(hir::def::DefKind::Variant | hir::def::DefKind::Ctor(..), _) => {
+                        match self.rustc_ty_check().pat_ty(pat).kind() {
+                            mid::ty::Adt(adt, _) => adt.variants().len() == 1 && !is_foreign_non_exhaustive(*adt),
+                            _ => false,
+                        }
+                    },
+                    // Constants
+                    _ => false,
+                };
+                is_single_ctor && self.are_sub_pats_irrefutable(pat)
+            },
+            hir::PatKind::Slice(start, rest, end) => match self.rustc_ty_check().pat_ty(pat).peel_refs().kind() {
+                // The length of array patterns is checked by rustc
+                mid::ty::Array(..) => start
+                    .iter()
+                    .chain(rest)
+                    .chain(end)
+                    .all(|pat| self.is_irrefutable_pat(pat)),
+                mid::ty::Slice(_) => {
+                    start.is_empty() && end.is_empty() && rest.is_some_and(|pat| self.is_irrefutable_pat(pat))
+                },
+                _ => false,
+            },
+            hir::PatKind::Lit(_) | hir::PatKind::Range(..) | hir::PatKind::Never => false,
+        }
+    }
+
+    fn are_sub_pats_irrefutable(&self, pat: &hir::Pat<'tcx>) -> bool {
+        match pat.kind {
+            hir::PatKind::TupleStruct(_, pats, _) => pats.iter().all(|pat| self.is_irrefutable_pat(pat)),
+            hir::PatKind::Struct(_, fields, _) => fields.iter().all(|field| self.is_irrefutable_pat(field.pat)),
+            _ => true,
+        }
+    }
+
+    /// Returns `true`, if the given patterns cover all values of the type
+    /// together. `None` is returned, if this can't be determined. This is
+    /// currently only supported for enums and `bool`. Variants which are only
+    /// matched with refutable fields, like `Some(true) | Some(false)`, can't
+    /// be checked, as this would require checking the nested values.
+    #[must_use]
+    pub fn pats_cover_ty(&self, pats: &[impl Borrow<hir::Pat<'tcx>>], ty: mid::ty::Ty<'tcx>) -> Option<bool> {
+        let covers = |covers_value: &dyn Fn(&hir::Pat<'tcx>) -> bool| {
+            pats.iter().any(|pat| self.pat_covers(pat.borrow(), covers_value))
+        };
+
+        match ty.peel_refs().kind() {
+            mid::ty::Adt(adt, _) if adt.is_enum() => {
+                if is_foreign_non_exhaustive(*adt) {
+                    return Some(false);
+                }
+                let mut is_undecidable = false;
+                for variant in adt.variants() {
+                    let is_variant = |pat: &hir::Pat<'tcx>| self.pat_variant(pat) == Some(variant.def_id);
+                    if covers(&|pat| is_variant(pat) && self.are_sub_pats_irrefutable(pat)) {
+                        continue;
+                    }
+                    if !covers(&is_variant) {
+                        return Some(false);
+                    }
+                    is_undecidable = true;
+                }
+                (!is_undecidable).then_some(true)
+            },
+            mid::ty::Bool => Some([true, false].into_iter().all(|value| {
+                covers(&|pat| {
+                    matches!(
+                        pat.kind,
+                        hir::PatKind::Lit(hir::Expr {
+                            kind: hir::ExprKind::Lit(lit),
+                            ..
+                        }) if lit.node == rustc_ast::LitKind::Bool(value)
+                    )
+                })
+            })),
+            _ => None,
+        }
+    }
+
+    /// Returns `true`, if the pattern is irrefutable or matches all values
+    /// accepted by `covers_value`, looking through bindings, references and
+    /// or-patterns.
+    fn pat_covers(&self, pat: &hir::Pat<'tcx>, covers_value: &dyn Fn(&hir::Pat<'tcx>) -> bool) -> bool {
+        if self.is_irrefutable_pat(pat) {
+            return true;
+        }
+
+        match pat.kind {
+            hir::PatKind::Or(pats) => pats.iter().any(|pat| self.pat_covers(pat, covers_value)),
+            hir::PatKind::Binding(.., Some(pat)) | hir::PatKind::Ref(pat, _) => self.pat_covers(pat, covers_value),
+            _ => covers_value(pat),
+        }
+    }
+
+    /// The enum variant, that the given pattern resolves to.
+    fn pat_variant(&self, pat: &hir::Pat<'tcx>) -> Option<hir::def_id::DefId> {
+        let (hir::PatKind::Path(qpath) | hir::PatKind::TupleStruct(qpath, ..) | hir::PatKind::Struct(qpath, ..)) =
+            pat.kind
+        else {
+            return None;
+        };
+        match self.rustc_ty_check().qpath_res(&qpath, pat.hir_id) {
+            hir::def::Res::Def(hir::def::DefKind::Variant, id) => Some(id),
+            hir::def::Res::Def(hir::def::DefKind::Ctor(hir::def::CtorOf::Variant, _), id) => {
+                Some(self.rustc_cx.parent(id))
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Enums marked with `#[non_exhaustive]` can only be matched exhaustively
+/// inside the crate, that defines them.
+fn is_foreign_non_exhaustive(adt: mid::ty::AdtDef<'_>) -> bool {
+    adt.is_variant_list_non_exhaustive() && !adt.did().is_local()
 }
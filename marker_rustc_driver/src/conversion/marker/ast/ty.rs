@@ -12,7 +12,7 @@ use crate::conversion::marker::MarkerConverterInner;
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
     #[must_use]
     pub fn to_syn_ty(&self, rustc_ty: &'tcx hir::Ty<'tcx>) -> TyKind<'ast> {
-        let data = CommonSynTyData::new_syntactic(self.to_span_id(rustc_ty.span));
+        let data = CommonSynTyData::new_syntactic(self.to_syn_ty_id(rustc_ty.hir_id), self.to_span_id(rustc_ty.span));
 
         // Note: Here we can't reuse allocated nodes, as each one contains
         // a unique span id. These nodes don't need to be stored individually, as
@@ -1,7 +1,7 @@
 use std::mem::{size_of, transmute};
 
 use marker_api::{
-    ast::{AstPath, AstPathSegment, AstPathTarget, AstQPath, GenericArgs, TraitRef, TyKind},
+    ast::{AstPath, AstPathSegment, AstPathTarget, AstQPath, AttrArgsKind, Attribute, GenericArgs, TraitRef, TyKind},
     common::*,
     span::Ident,
 };
@@ -149,11 +149,21 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         transmute_id!(HirIdLayout as ExprId = id.into())
     }
 
+    #[must_use]
+    pub fn to_pat_id(&self, id: impl Into<HirIdLayout>) -> PatId {
+        transmute_id!(HirIdLayout as PatId = id.into())
+    }
+
     #[must_use]
     pub fn to_stmt_id(&self, id: impl Into<HirIdLayout>) -> StmtId {
         transmute_id!(HirIdLayout as StmtId = id.into())
     }
 
+    #[must_use]
+    pub fn to_syn_ty_id(&self, id: impl Into<HirIdLayout>) -> SynTyId {
+        transmute_id!(HirIdLayout as SynTyId = id.into())
+    }
+
     #[must_use]
     pub fn to_span_src_id(&self, id: rustc_span::SyntaxContext) -> SpanSrcId {
         // FIXME(xFrednet): This conversion is theoretically unsound, since
@@ -192,6 +202,41 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         Ident::new(self.to_symbol_id(ident.name), self.to_span_id(ident.span))
     }
 
+    #[must_use]
+    pub fn to_attrs(&self, hir_id: hir::HirId) -> &'ast [Attribute<'ast>] {
+        self.alloc_slice(self.rustc_cx.hir().attrs(hir_id).iter().map(|attr| self.to_attr(attr)))
+    }
+
+    fn to_attr(&self, attr: &rustc_ast::Attribute) -> Attribute<'ast> {
+        match &attr.kind {
+            rustc_ast::AttrKind::Normal(normal) => {
+                let item = &normal.item;
+                let segments = self.alloc_slice(item.path.segments.iter().map(|seg| self.to_ident(seg.ident)));
+                let (args_kind, args_span) = match &item.args {
+                    rustc_ast::AttrArgs::Empty => (AttrArgsKind::Empty, None),
+                    rustc_ast::AttrArgs::Delimited(args) => (AttrArgsKind::List, Some(args.dspan.entire())),
+                    rustc_ast::AttrArgs::Eq(_, rustc_ast::AttrArgsEq::Hir(lit)) => {
+                        (AttrArgsKind::NameValue, Some(lit.span))
+                    },
+                    rustc_ast::AttrArgs::Eq(_, rustc_ast::AttrArgsEq::Ast(expr)) => {
+                        (AttrArgsKind::NameValue, Some(expr.span))
+                    },
+                };
+                Attribute::new(
+                    self.to_span_id(attr.span),
+                    segments,
+                    args_kind,
+                    args_span.map(|span| self.to_span_id(span)),
+                )
+            },
+            rustc_ast::AttrKind::DocComment(..) => {
+                let span = self.to_span_id(attr.span);
+                let segments = self.alloc_slice([Ident::new(self.to_symbol_id(rustc_span::sym::doc), span)]);
+                Attribute::new(span, segments, AttrArgsKind::DocComment, Some(span))
+            },
+        }
+    }
+
     #[must_use]
     pub fn to_mutability(&self, mutability: rustc_ast::Mutability) -> Mutability {
         match mutability {
@@ -409,6 +454,32 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
         }
     }
 
+    /// Use paths can resolve to multiple targets, one per namespace. This
+    /// returns the first target, that can be represented by Marker.
+    pub fn to_use_path_target(&self, res: &[hir::def::Res]) -> AstPathTarget {
+        res.iter()
+            .find_map(|res| match res {
+                hir::def::Res::Def(hir::def::DefKind::Mod, id) => Some(AstPathTarget::Item(self.to_item_id(*id))),
+                hir::def::Res::Def(
+                    hir::def::DefKind::TyAlias { .. }
+                    | hir::def::DefKind::Fn
+                    | hir::def::DefKind::Enum
+                    | hir::def::DefKind::Struct
+                    | hir::def::DefKind::Union
+                    | hir::def::DefKind::Trait
+                    | hir::def::DefKind::ForeignTy
+                    | hir::def::DefKind::TraitAlias
+                    | hir::def::DefKind::Const
+                    | hir::def::DefKind::Static(_)
+                    | hir::def::DefKind::Ctor(..)
+                    | hir::def::DefKind::Variant,
+                    _,
+                ) => Some(self.to_path_target(res)),
+                _ => None,
+            })
+            .unwrap_or(AstPathTarget::Unresolved)
+    }
+
     #[must_use]
     pub fn to_path<T>(&self, path: &hir::Path<'tcx, T>) -> AstPath<'ast> {
         AstPath::new(self.alloc_slice(path.segments.iter().map(|seg| self.to_path_segment(seg))))
@@ -1,3 +1,4 @@
+mod const_value;
 mod generic;
 mod item;
 mod ty;
@@ -0,0 +1,70 @@
+use marker_api::sem::{ConstValue, ConstValueKind};
+use rustc_hir as hir;
+use rustc_middle as mid;
+use rustc_middle::ty::TypeVisitableExt;
+use rustc_target::abi::Size;
+
+use crate::conversion::marker::MarkerConverterInner;
+
+impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Evaluates the value of the given const or static item or anonymous constant.
+    pub fn to_item_const_value(&self, def_id: hir::def_id::DefId) -> Option<&'ast ConstValue<'ast>> {
+        let tcx = self.rustc_cx;
+        // Generic items can't be evaluated without concrete generic arguments
+        if tcx.generics_of(def_id).count() != 0 || tcx.is_foreign_item(def_id) {
+            return None;
+        }
+
+        let ty = tcx.type_of(def_id).instantiate_identity();
+        let scalar = match tcx.def_kind(def_id) {
+            hir::def::DefKind::AnonConst => {
+                // Anonymous constants can directly refer to generic parameters,
+                // like the `N` in `[u8; N]`, without being generic themselves
+                let ct = mid::ty::Const::from_anon_const(tcx, def_id.as_local()?);
+                if ct.has_param() {
+                    return None;
+                }
+                match tcx.const_eval_poly(def_id).ok()? {
+                    mid::mir::ConstValue::Scalar(scalar) => Some(scalar),
+                    _ => None,
+                }
+            },
+            hir::def::DefKind::Const | hir::def::DefKind::AssocConst => match tcx.const_eval_poly(def_id).ok()? {
+                mid::mir::ConstValue::Scalar(scalar) => Some(scalar),
+                _ => None,
+            },
+            hir::def::DefKind::Static(_) => {
+                let alloc = tcx.eval_static_initializer(def_id).ok()?;
+                let layout = tcx.layout_of(mid::ty::ParamEnv::reveal_all().and(ty)).ok()?;
+                if layout.abi.is_scalar() {
+                    let range = mid::mir::interpret::alloc_range(Size::ZERO, layout.size);
+                    alloc.inner().read_scalar(&tcx, range, false).ok()
+                } else {
+                    None
+                }
+            },
+            _ => return None,
+        };
+
+        let kind = scalar
+            .and_then(|scalar| scalar.try_to_int().ok())
+            .and_then(|int| to_const_value_kind(ty, int))
+            .unwrap_or(ConstValueKind::Unstable);
+        Some(self.alloc(ConstValue::new(kind)))
+    }
+}
+
+fn to_const_value_kind(ty: mid::ty::Ty<'_>, int: mid::ty::ScalarInt) -> Option<ConstValueKind> {
+    let kind = match ty.kind() {
+        mid::ty::TyKind::Bool => ConstValueKind::Bool(int.try_to_bool().ok()?),
+        mid::ty::TyKind::Char => ConstValueKind::Char(char::from_u32(int.try_to_u32().ok()?)?),
+        mid::ty::TyKind::Int(_) => ConstValueKind::Int(int.try_to_int(int.size()).ok()?),
+        mid::ty::TyKind::Uint(_) => ConstValueKind::Uint(int.try_to_uint(int.size()).ok()?),
+        mid::ty::TyKind::Float(mid::ty::FloatTy::F32) => {
+            ConstValueKind::Float(f32::from_bits(int.try_to_u32().ok()?).into())
+        },
+        mid::ty::TyKind::Float(mid::ty::FloatTy::F64) => ConstValueKind::Float(f64::from_bits(int.try_to_u64().ok()?)),
+        _ => return None,
+    };
+    Some(kind)
+}
@@ -1,15 +1,89 @@
 use marker_api::{
     common::{NumKind, TextKind},
     sem::{
-        self, AdtTy, AliasTy, ArrayTy, BoolTy, ClosureTy, ConstValue, FnPtrTy, FnTy, GenericTy, NeverTy, NumTy,
-        RawPtrTy, RefTy, SliceTy, TextTy, TraitObjTy, TupleTy, TyKind, UnstableTy,
+        self, AdtField, AdtTy, AdtVariant, AliasTy, ArrayTy, BoolTy, ClosureTy, ConstValue, ConstValueKind, FnPtrTy,
+        FnTy, ForeignTy, GenericTy, NeverTy, NumTy, RawPtrTy, RefTy, SliceTy, TextTy, TraitObjTy, TupleTy, TyKind,
+        UnstableTy,
     },
 };
 use rustc_middle as mid;
+use rustc_middle::ty::TypeVisitableExt;
 
 use crate::conversion::marker::MarkerConverterInner;
 
 impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
+    /// Returns an unstable semantic type for nodes without a type, like rest
+    /// patterns in tuples. The driver type is rustc's placeholder for the `Self`
+    /// type of trait objects, which is rejected by semantic queries.
+    #[must_use]
+    pub fn to_unstable_sem_ty(&self) -> TyKind<'ast> {
+        let rustc_ty = self.rustc_cx.types.trait_object_dummy_self;
+        let data = sem::CommonTyData::builder()
+            .driver_id(self.to_driver_ty_id(rustc_ty))
+            .build();
+        TyKind::Unstable(self.alloc(UnstableTy::builder().data(data).build()))
+    }
+
+    /// Returns the fields of the given struct or union type. Enums and other
+    /// types have no fields.
+    #[must_use]
+    pub fn to_sem_adt_fields(&self, rustc_ty: mid::ty::Ty<'tcx>) -> &'ast [AdtField<'ast>] {
+        match rustc_ty.kind() {
+            mid::ty::TyKind::Adt(def, args) if !def.is_enum() => self.to_sem_fields(def.non_enum_variant(), args),
+            _ => &[],
+        }
+    }
+
+    /// Returns the variants of the given enum type.
+    #[must_use]
+    pub fn to_sem_adt_variants(&self, rustc_ty: mid::ty::Ty<'tcx>) -> &'ast [AdtVariant<'ast>] {
+        match rustc_ty.kind() {
+            mid::ty::TyKind::Adt(def, args) if def.is_enum() => {
+                let variants: Vec<_> = def
+                    .variants()
+                    .iter()
+                    .map(|variant| {
+                        AdtVariant::builder()
+                            .id(self.to_variant_id(variant.def_id))
+                            .name(self.to_symbol_id(variant.name))
+                            .fields(self.to_sem_fields(variant, args))
+                            .build()
+                    })
+                    .collect();
+                self.alloc_slice(variants)
+            },
+            _ => &[],
+        }
+    }
+
+    fn to_sem_fields(
+        &self,
+        variant: &mid::ty::VariantDef,
+        args: mid::ty::GenericArgsRef<'tcx>,
+    ) -> &'ast [AdtField<'ast>] {
+        let tcx = self.rustc_cx;
+        let fields: Vec<_> = variant
+            .fields
+            .iter()
+            .map(|field| {
+                let rustc_ty = field.ty(tcx, args);
+                // Generic parameters can only be converted inside the body or
+                // signature, which defines them. That information is not
+                // available from the type.
+                let ty = if rustc_ty.has_param() {
+                    self.to_unstable_sem_ty()
+                } else {
+                    let rustc_ty = tcx
+                        .try_normalize_erasing_regions(mid::ty::ParamEnv::reveal_all(), rustc_ty)
+                        .unwrap_or(rustc_ty);
+                    self.to_sem_ty(rustc_ty)
+                };
+                AdtField::builder().name(self.to_symbol_id(field.name)).ty(ty).build()
+            })
+            .collect();
+        self.alloc_slice(fields)
+    }
+
     #[must_use]
     pub fn to_sem_ty(&self, rustc_ty: mid::ty::Ty<'tcx>) -> TyKind<'ast> {
         let data = sem::CommonTyData::builder()
@@ -64,15 +138,20 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                         .build(),
                 ),
             ),
-            mid::ty::TyKind::Foreign(_) => {
-                todo!("foreign type are currently sadly not supported. See rust-marker/marker#182")
-            },
+            mid::ty::TyKind::Foreign(def_id) => TyKind::Foreign(
+                self.alloc(
+                    ForeignTy::builder()
+                        .data(data)
+                        .def_id(self.to_ty_def_id(*def_id))
+                        .build(),
+                ),
+            ),
             mid::ty::TyKind::Array(inner, _len) => TyKind::Array(
                 self.alloc(
                     ArrayTy::builder()
                         .data(data)
                         .inner_ty(self.to_sem_ty(*inner))
-                        .len(ConstValue::new())
+                        .len(ConstValue::new(ConstValueKind::Unstable))
                         .build(),
                 ),
             ),
@@ -146,15 +225,28 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     ),
                 )
             },
-            mid::ty::TyKind::Closure(id, generics) => TyKind::Closure(
-                self.alloc(
-                    ClosureTy::builder()
-                        .data(data)
-                        .def_id(self.to_ty_def_id(*id))
-                        .generics(self.to_sem_generic_args(generics))
-                        .build(),
-                ),
-            ),
+            mid::ty::TyKind::Closure(id, generics) => {
+                // The closure signature uses the "rust-call" ABI, where all
+                // parameters are passed as a single tuple
+                let sig = generics.as_closure().sig().skip_binder();
+                let params = match sig.inputs().first().map(|ty| ty.kind()) {
+                    Some(mid::ty::TyKind::Tuple(params)) => {
+                        self.alloc_slice(params.iter().map(|param| self.to_sem_ty(param)))
+                    },
+                    _ => unreachable!("closure parameters are always tupled: {sig:#?}"),
+                };
+                TyKind::Closure(
+                    self.alloc(
+                        ClosureTy::builder()
+                            .data(data)
+                            .def_id(self.to_ty_def_id(*id))
+                            .generics(self.to_sem_generic_args(generics))
+                            .params(params)
+                            .return_ty(self.to_sem_ty(sig.output()))
+                            .build(),
+                    ),
+                )
+            },
             mid::ty::TyKind::Coroutine(_, _, _) | mid::ty::TyKind::CoroutineWitness(_, _) => {
                 TyKind::Unstable(self.alloc(UnstableTy::builder().data(data).build()))
             },
@@ -168,14 +260,16 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                 ),
             ),
             mid::ty::TyKind::Param(param) => {
-                let body_id = self
-                    .rustc_body
-                    .borrow()
-                    .expect("semantic `TyKind::Param` is only valid inside bodies");
                 // This is a local id, this makes sense, since rustc only accesses
                 // expressions and therefore semantic types of the current crate.
                 // This should be fine...
-                let owner = self.rustc_cx.hir().body_owner_def_id(body_id);
+                let owner = match *self.rustc_body.borrow() {
+                    Some(body_id) => self.rustc_cx.hir().body_owner_def_id(body_id),
+                    None => self
+                        .rustc_sig_owner
+                        .borrow()
+                        .expect("semantic `TyKind::Param` is only valid inside bodies and item signatures"),
+                };
                 let generic_info = self
                     .rustc_cx
                     .generics_of(owner.to_def_id())
@@ -34,6 +34,11 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
             };
         }
 
+        // Dummy spans don't point to any source code
+        if rust_span.is_dummy() {
+            return SpanSource::Builtin(self.builtin_span_source);
+        }
+
         let src_file = self.rustc_cx.sess.source_map().lookup_source_file(rust_span.lo());
         let name = match &src_file.name {
             rustc_span::FileName::Real(
@@ -43,26 +48,35 @@ impl<'ast, 'tcx> MarkerConverterInner<'ast, 'tcx> {
                     ..
                 },
             ) => file_path.to_string_lossy().into_owned(),
-            _ => {
-                format!("MarkerConverter::to_span_source(): Unexpected file name: {rust_span:#?} -> {src_file:#?}")
-            },
+            // Other sources, like crate attributes passed via `-Zcrate-attr`,
+            // don't belong to a file written by the user.
+            _ => return SpanSource::Builtin(self.builtin_span_source),
         };
         SpanSource::File(self.alloc(FileInfo::new(self.storage.alloc_str(&name), self.to_span_src_id(ctxt))))
     }
 
-    pub fn try_to_expn_info(&self, id: rustc_span::ExpnId) -> Option<&'ast ExpnInfo<'ast>> {
-        (id != rustc_span::ExpnId::root()).then(|| self.alloc(self.to_expn_info(&id.expn_data())))
+    pub fn try_to_expn_info(&self, mut id: rustc_span::ExpnId) -> Option<&'ast ExpnInfo<'ast>> {
+        // Expansions, which don't come from macros, like desugarings, are skipped
+        while id != rustc_span::ExpnId::root() {
+            let data = id.expn_data();
+            if matches!(data.kind, rustc_span::ExpnKind::Macro(_, _)) {
+                return Some(self.alloc(self.to_expn_info(&data)));
+            }
+            id = data.parent;
+        }
+        None
     }
 
     pub fn to_expn_info(&self, data: &rustc_span::ExpnData) -> ExpnInfo<'ast> {
-        debug_assert!(
-            matches!(&data.kind, rustc_span::ExpnKind::Macro(_, _)),
-            "this expansion data doesn't belong to a macro: {data:#?}"
-        );
+        let rustc_span::ExpnKind::Macro(_, name) = data.kind else {
+            unreachable!("this expansion data doesn't belong to a macro: {data:#?}")
+        };
         ExpnInfo::new(
             self.to_expn_id(data.parent),
             self.to_span_id(data.call_site),
+            (!data.def_site.is_dummy()).then(|| self.to_span_id(data.def_site)),
             self.to_macro_id(data.macro_def_id.expect("filled, because this belongs to a macro")),
+            self.to_symbol_id(name),
         )
     }
 
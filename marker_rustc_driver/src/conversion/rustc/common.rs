@@ -1,7 +1,7 @@
 use std::mem::{size_of, transmute};
 
 use marker_api::{
-    common::{CrateId, DriverTyId, ExpnId, Level, SpanId, SpanSrcId, SymbolId},
+    common::{CrateId, DriverTyId, ExpnId, Level, PatId, SpanId, SpanSrcId, SymbolId, SynTyId},
     diagnostic::Applicability,
     prelude::*,
     span::SpanPos,
@@ -56,6 +56,8 @@ impl_into_hir_id_for!(ExprId);
 impl_into_hir_id_for!(VarId);
 impl_into_hir_id_for!(StmtId);
 impl_into_hir_id_for!(FieldId);
+impl_into_hir_id_for!(SynTyId);
+impl_into_hir_id_for!(PatId);
 
 #[derive(Debug, Clone, Copy)]
 pub struct SpanSourceInfo {
@@ -137,6 +139,8 @@ impl<'ast, 'tcx> RustcConverter<'ast, 'tcx> {
             NodeId::Body(id) => return Some(self.to_body_id(id).hir_id),
             NodeId::Field(id) => return Some(self.to_hir_id(id)),
             NodeId::Variant(id) => self.to_def_id(id),
+            NodeId::Ty(id) => return Some(self.to_hir_id(id)),
+            NodeId::Generic(id) => self.to_def_id(id),
             _ => unreachable!(),
         };
 
@@ -23,6 +23,7 @@ extern crate rustc_errors;
 extern crate rustc_hash;
 extern crate rustc_hir;
 extern crate rustc_hir_analysis;
+extern crate rustc_infer;
 extern crate rustc_interface;
 extern crate rustc_lint;
 extern crate rustc_lint_defs;
@@ -30,6 +31,7 @@ extern crate rustc_middle;
 extern crate rustc_session;
 extern crate rustc_span;
 extern crate rustc_target;
+extern crate rustc_trait_selection;
 
 pub mod context;
 pub mod conversion;
@@ -39,10 +41,11 @@ use std::env;
 use std::ops::Deref;
 
 use camino::Utf8Path;
-use marker_adapter::{LintCrateInfo, LINT_CRATES_ENV};
+use marker_adapter::{FileFilter, LintCrateInfo, EXCLUDED_FILES_ENV, LINT_CRATES_ENV};
 use marker_error::Context;
 
 use crate::conversion::rustc::RustcConverter;
+use crate::lint_pass::StaticPasses;
 
 // region replace rust toolchain dev
 const RUSTC_TOOLCHAIN_VERSION: &str = "nightly-2023-12-28";
@@ -65,6 +68,8 @@ impl rustc_driver::Callbacks for DefaultCallbacks {
 struct MarkerCallback {
     env_vars: Vec<&'static str>,
     lint_crates: Vec<LintCrateInfo>,
+    file_filter: FileFilter,
+    static_passes: StaticPasses,
 }
 
 impl rustc_driver::Callbacks for MarkerCallback {
@@ -81,12 +86,14 @@ impl rustc_driver::Callbacks for MarkerCallback {
         // code is executed.
         assert!(config.register_lints.is_none());
         let lint_crates = std::mem::take(&mut self.lint_crates);
+        let file_filter = std::mem::take(&mut self.file_filter);
+        let static_passes = self.static_passes;
 
         config.register_lints = Some(Box::new(move |_sess, lint_store| {
             // It looks like it can happen, that the `config` function is called
             // with a different thread than the actual lint pass later, how interesting.
             // This will not make sure that the adapter is always initiated.
-            if let Err(err) = lint_pass::RustcLintPass::init_adapter(&lint_crates) {
+            if let Err(err) = lint_pass::RustcLintPass::init_adapter(&lint_crates, file_filter.clone(), static_passes) {
                 err.print();
                 // FIXME: we need to figure out the way to run the initialization code
                 // earlier where we can cleanly report an error and exit the process.
@@ -184,6 +191,10 @@ Common options:
         --rustc              Pass all arguments to rustc
     -V, --version            Print version information and exit
         --toolchain          Print the required toolchain and API version
+        --explain-lint <LINT>
+                             Print the documentation of a lint from the lint crates
+        --list-lints <FORMAT>
+                             Print all lints from the lint crates, as `human` or `json`
 
 ---
 
@@ -195,6 +206,22 @@ interfacing with the driver directly and use `cargo marker` instead.
 
 #[allow(clippy::missing_errors_doc)]
 pub fn try_main(args: impl Iterator<Item = String>) -> Result<(), MainError> {
+    try_main_with_static_passes(args, Vec::new)
+}
+
+/// This works like [`try_main`], but additionally registers the lint passes
+/// created by `static_passes`. This allows building a custom driver binary, which
+/// links a fixed set of [`LintPass`](marker_api::LintPass) implementations
+/// statically, instead of loading lint crates as dynamic libraries. Lint crates
+/// specified by `cargo-marker` are still loaded and called before the static passes.
+///
+/// The function is called once per checked crate, on the thread running the
+/// lint passes.
+#[allow(clippy::missing_errors_doc)]
+pub fn try_main_with_static_passes(
+    args: impl Iterator<Item = String>,
+    static_passes: StaticPasses,
+) -> Result<(), MainError> {
     // Note: This driver has two different kinds of "arguments".
     // 1. Normal arguments, passed directly to the binary. (Collected below)
     // 2. Arguments provided to `cargo-marker` which are forwarded to this process as environment
@@ -246,6 +273,14 @@ pub fn try_main(args: impl Iterator<Item = String>) -> Result<(), MainError> {
         return Ok(());
     }
 
+    if let Some(lint_name) = arg_value(&orig_args, "--explain-lint", |_| true) {
+        return explain_lint(lint_name, static_passes);
+    }
+
+    if let Some(format) = arg_value(&orig_args, "--list-lints", |_| true) {
+        return list_lints(format, static_passes);
+    }
+
     // Setting RUSTC_WRAPPER causes Cargo to pass 'rustc' as the first argument.
     // We're invoking the compiler programmatically, so we'll ignore this.
     let wrapper_mode = orig_args.get(1).map(Utf8Path::new).and_then(Utf8Path::file_stem) == Some("rustc");
@@ -272,7 +307,7 @@ pub fn try_main(args: impl Iterator<Item = String>) -> Result<(), MainError> {
     let in_primary_package = env::var("CARGO_PRIMARY_PACKAGE").is_ok();
 
     let enable_marker = !cap_lints_allow && (!no_deps || in_primary_package);
-    let env_vars = vec![LINT_CRATES_ENV, MARKER_SYSROOT_ENV];
+    let env_vars = vec![LINT_CRATES_ENV, EXCLUDED_FILES_ENV, MARKER_SYSROOT_ENV];
     if !enable_marker {
         rustc_driver::RunCompiler::new(&orig_args, &mut DefaultCallbacks { env_vars }).run()?;
         return Ok(());
@@ -281,6 +316,7 @@ pub fn try_main(args: impl Iterator<Item = String>) -> Result<(), MainError> {
     let lint_crates = LintCrateInfo::list_from_env()
         .context(|| "Error while determining the lint crates to load")?
         .unwrap_or_default();
+    let file_filter = FileFilter::from_env().context(|| "Error while determining the excluded files")?;
 
     let additional_args = [
         // Make it possible to use `#[allow(marker::{lint_name})]` without
@@ -302,12 +338,84 @@ pub fn try_main(args: impl Iterator<Item = String>) -> Result<(), MainError> {
 
     orig_args.extend(additional_args);
 
-    let mut callback = MarkerCallback { env_vars, lint_crates };
+    let mut callback = MarkerCallback {
+        env_vars,
+        lint_crates,
+        file_filter,
+        static_passes,
+    };
     rustc_driver::RunCompiler::new(&orig_args, &mut callback).run()?;
 
     Ok(())
 }
 
+/// Loads the lint crates from the environment and prints the documentation of
+/// the lint with the given name.
+fn explain_lint(lint_name: &str, static_passes: StaticPasses) -> Result<(), MainError> {
+    let lint_crates = LintCrateInfo::list_from_env()
+        .context(|| "Error while determining the lint crates to load")?
+        .unwrap_or_default();
+    let adapter =
+        lint_pass::create_adapter(&lint_crates, static_passes).context(|| "Error while loading the lint crates")?;
+
+    let Some(lint) = adapter.lint_by_name(lint_name) else {
+        return Err(marker_error::Error::root(format!(
+            "The lint `{lint_name}` was not found in the loaded lint crates"
+        ))
+        .into());
+    };
+
+    println!(
+        "{} (default level: {})",
+        lint.name.to_lowercase(),
+        format!("{:?}", lint.default_level).to_lowercase()
+    );
+    if let Some(category) = lint.category {
+        println!("category: {}", format!("{category:?}").to_lowercase());
+    }
+    if let Some(url) = lint.url {
+        println!("documentation: {url}");
+    }
+    println!();
+    // Doc comments are stored with the space after the `///`
+    for line in lint.explanation.lines() {
+        println!("{}", line.strip_prefix(' ').unwrap_or(line));
+    }
+
+    Ok(())
+}
+
+/// Loads the lint crates from the environment and prints all of their lints,
+/// either as one line per lint, or as JSON with the `json` format.
+fn list_lints(format: &str, static_passes: StaticPasses) -> Result<(), MainError> {
+    let lint_crates = LintCrateInfo::list_from_env()
+        .context(|| "Error while determining the lint crates to load")?
+        .unwrap_or_default();
+    let adapter =
+        lint_pass::create_adapter(&lint_crates, static_passes).context(|| "Error while loading the lint crates")?;
+
+    match format {
+        "json" => println!("{}", adapter.lints_json()),
+        "human" => {
+            for lint in adapter.marker_lints() {
+                println!(
+                    "{} (default level: {})",
+                    lint.name.to_lowercase(),
+                    format!("{:?}", lint.default_level).to_lowercase()
+                );
+            }
+        },
+        _ => {
+            return Err(marker_error::Error::root(format!(
+                "The lint list format `{format}` is unknown, expected `human` or `json`"
+            ))
+            .into());
+        },
+    }
+
+    Ok(())
+}
+
 pub enum MainError {
     Custom(marker_error::Error),
     Rustc(rustc_span::ErrorGuaranteed),
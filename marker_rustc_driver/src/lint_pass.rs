@@ -1,7 +1,7 @@
 use std::cell::OnceCell;
 
-use marker_adapter::{Adapter, LintCrateInfo};
-use marker_api::Lint;
+use marker_adapter::{Adapter, FileFilter, LintCrateInfo};
+use marker_api::{Lint, LintPass};
 
 use crate::context::{storage::Storage, RustcContext};
 
@@ -21,13 +21,33 @@ thread_local! {
     static ADAPTER: OnceCell<Adapter> = OnceCell::new();
 }
 
+/// A function, which creates the lint passes linked statically into the driver.
+/// See [`crate::try_main_with_static_passes`].
+pub type StaticPasses = fn() -> Vec<Box<dyn LintPass>>;
+
+/// Creates an [`Adapter`], which loads the given lint crates and calls the lint
+/// passes created by `static_passes` afterwards.
+#[allow(clippy::missing_errors_doc)]
+pub fn create_adapter(
+    lint_crates: &[LintCrateInfo],
+    static_passes: StaticPasses,
+) -> Result<Adapter, marker_adapter::Error> {
+    Adapter::with_static_passes(lint_crates, static_passes())
+}
+
 pub struct RustcLintPass;
 
 impl RustcLintPass {
     #[allow(clippy::missing_errors_doc)]
-    pub fn init_adapter(lint_crates: &[LintCrateInfo]) -> Result<(), marker_adapter::Error> {
+    pub fn init_adapter(
+        lint_crates: &[LintCrateInfo],
+        file_filter: FileFilter,
+        static_passes: StaticPasses,
+    ) -> Result<(), marker_adapter::Error> {
         ADAPTER.with(move |cell| {
-            cell.get_or_try_init(|| Adapter::new(lint_crates))?;
+            cell.get_or_try_init(|| {
+                create_adapter(lint_crates, static_passes).map(|adapter| adapter.with_file_filter(file_filter))
+            })?;
             Ok(())
         })
     }
@@ -58,12 +78,14 @@ pub fn process_crate(rustc_cx: &rustc_lint::LateContext<'_>, adapter: &Adapter)
 fn process_crate_lifetime<'ast, 'tcx: 'ast>(
     rustc_cx: &rustc_lint::LateContext<'tcx>,
     storage: &'ast Storage<'ast>,
-    adapter: &Adapter,
+    adapter: &'ast Adapter,
 ) {
     let driver_cx = RustcContext::new(
         rustc_cx.tcx,
         rustc_lint::unerased_lint_store(rustc_cx.tcx.sess),
         storage,
+        adapter.diagnostic_sink(),
+        adapter.file_filter(),
     );
 
     // To support debug printing of AST nodes, as these might sometimes require the
@@ -24,12 +24,6 @@ fn main() {
     let handler = EarlyDiagCtxt::new(ErrorOutputType::default());
     rustc_driver::init_rustc_env_logger(&handler);
 
-    // FIXME(xFrednet): The ICE hook would ideally distinguish where the error
-    // happens. Panics from lint crates should probably not terminate Marker
-    // completely, but instead warn the user and continue linting with the other
-    // lint crate. It would also be cool if the ICE hook printed the node that
-    // caused the panic in the lint crate. rust-marker/marker#10
-
     rustc_driver::install_ice_hook(BUG_REPORT_URL, |handler| {
         handler.note(format!("{}", rustc_tools_util::get_version_info!()));
         handler.note("Achievement Unlocked: [Free Ice Cream]");
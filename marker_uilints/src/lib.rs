@@ -3,16 +3,36 @@
 
 mod utils;
 
+use std::ops::ControlFlow;
+
 use marker_api::{
-    ast::{AstPathTarget, EnumVariant, ItemField, LetStmt, StaticItem},
-    diagnostic::Applicability,
+    ast::{
+        AstPathTarget, Attribute, BinOpResolution, Crate, EnumVariant, ItemField, LetStmt, StaticItem, VisibilityScope,
+    },
+    common::Level,
+    diagnostic::{Applicability, EmissionNode},
     prelude::*,
     sem::TyKind,
-    LintPass, LintPassInfo, LintPassInfoBuilder,
+    serialize::ItemNode,
+    ConfigValue, LintPass, LintPassInfo, LintPassInfoBuilder,
 };
+use marker_utils::visitor::Traversable;
 
 #[derive(Default)]
-struct TestLintPass {}
+struct TestLintPass {
+    /// Items with the `test_crate_post` prefix, which are reported in
+    /// [`LintPass::check_crate_post`].
+    crate_post_items: Vec<ItemId>,
+    config: Config,
+}
+
+/// The configuration of this lint crate, it's provided by the uitest setup.
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    /// Items with this prefix are reported with their configuration.
+    item_prefix: Option<String>,
+}
 
 marker_api::export_lint_pass!(TestLintPass);
 
@@ -68,6 +88,15 @@ marker_api::declare_lint! {
     Warn,
 }
 
+marker_api::declare_lint! {
+    /// # What it does
+    /// A lint to test the optional fields of `declare_lint!`.
+    TEST_LINT_METADATA,
+    Warn,
+    category: Style,
+    url: "https://github.com/rust-marker/marker",
+}
+
 fn emit_item_with_test_name_lint<'ast>(
     cx: &'ast MarkerContext<'ast>,
     node: impl EmissionNode<'ast>,
@@ -77,6 +106,20 @@ fn emit_item_with_test_name_lint<'ast>(
     cx.emit_lint(ITEM_WITH_TEST_NAME, node, msg);
 }
 
+fn emit_attrs_lint<'ast>(cx: &MarkerContext<'ast>, node: impl EmissionNode<'ast>, attrs: &[Attribute<'ast>]) {
+    cx.emit_lint(TEST_LINT, node, "checking attributes").decorate(|diag| {
+        for attr in attrs {
+            let path: Vec<_> = attr.segments().iter().map(Ident::name).collect();
+            diag.note(format!(
+                "`{}` ({:?}) -> args: {:?}",
+                path.join("::"),
+                attr.args_kind(),
+                attr.args_snippet()
+            ));
+        }
+    });
+}
+
 impl LintPass for TestLintPass {
     fn info(&self) -> LintPassInfo {
         LintPassInfoBuilder::new(Box::new([
@@ -84,27 +127,101 @@ impl LintPass for TestLintPass {
             ITEM_WITH_TEST_NAME,
             PRINT_EVERY_EXPR,
             utils::TEST_CONTAINS_RETURN,
+            utils::TEST_TRAVERSE_PAT,
+            utils::TEST_VISIT_PATH,
+            utils::TEST_EXPR_EQ,
+            utils::TEST_ALL_ITEMS,
+            utils::TEST_IS_UNREACHABLE,
+            TEST_LINT_METADATA,
         ]))
+        .expr_lint(PRINT_EVERY_EXPR)
+        .expr_lint(TEST_LINT)
+        .config_schema::<Config>()
         .build()
     }
 
+    fn setup(&mut self, config: ConfigValue<'_>) {
+        self.config = config.deserialize().expect("the config was validated by the driver");
+    }
+
+    fn check_crate<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _krate: &'ast Crate<'ast>) {
+        self.crate_post_items.clear();
+    }
+
+    fn check_crate_post<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, krate: &'ast Crate<'ast>) {
+        utils::check_all_items(cx, krate);
+
+        let Some(first) = self.crate_post_items.first().and_then(|id| cx.ast().item(*id)) else {
+            return;
+        };
+        let msg = format!(
+            "found {} items with the `test_crate_post` prefix",
+            self.crate_post_items.len()
+        );
+        cx.emit_lint(TEST_LINT, first, msg).decorate(|diag| {
+            for item in self.crate_post_items.iter().filter_map(|id| cx.ast().item(*id)) {
+                diag.span_note("collected this item", item.span());
+            }
+        });
+    }
+
     fn check_item<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
+        if item
+            .ident()
+            .is_some_and(|ident| ident.name().starts_with("test_crate_post"))
+        {
+            self.crate_post_items.push(item.id());
+        }
+
         utils::check_item(cx, item);
+        utils::check_traverse_pat(cx, item);
+        utils::check_visit_path(cx, item);
+        utils::check_is_unreachable(cx, item);
+
+        check_configured_prefix(cx, item, &self.config);
+        check_source_file(cx, item);
+        check_item_children(cx, item);
 
         if let ItemKind::Fn(item) = item {
-            if let Some(ident) = item.ident() {
-                if ident.name() == "test_ty_id_resolution_trigger" {
-                    test_ty_id_resolution(cx);
-                } else if ident.name() == "uilints_please_ice_on_this" {
-                    panic!("free ice cream for everyone!!!");
-                }
-            }
+            check_fn_item(cx, item);
         }
 
         if let ItemKind::Static(item) = item {
             check_static_item(cx, item);
         }
 
+        if let ItemKind::Impl(item) = item {
+            if item.ty().span().snippet_or("").starts_with("TestImplTrait") {
+                check_impl_trait_ref(cx, item);
+            }
+        }
+
+        if let ItemKind::Trait(item) = item {
+            if matches!(item.ident(), Some(ident) if ident.name().starts_with("TestImplsOf")) {
+                check_impls_of_trait(cx, item);
+            }
+        }
+
+        if matches!(item.ident(), Some(ident) if ident.name().starts_with("TEST_VALUE")) {
+            check_const_value(cx, item);
+        }
+
+        if matches!(item.ident(), Some(ident) if ident.name().starts_with("TestConstExpr")) {
+            check_const_expr_values(cx, item);
+        }
+
+        if matches!(item.ident(), Some(ident) if ident.name().starts_with("test_serialize")) {
+            check_serialize(cx, item);
+        }
+
+        if matches!(item.ident(), Some(ident) if ident.name().starts_with("test_try_span")) {
+            check_try_span(cx, item);
+        }
+
+        if matches!(item.ident(), Some(ident) if ident.name().starts_with("TestAttrs")) {
+            emit_attrs_lint(cx, item, item.attrs());
+        }
+
         if matches!(
             item.ident().map(marker_api::span::Ident::name),
             Some(name) if name.starts_with("FindMe") || name.starts_with("FIND_ME") || name.starts_with("find_me")
@@ -155,17 +272,7 @@ impl LintPass for TestLintPass {
                 .map(|name| name.name().starts_with("test_vis"))
                 .unwrap_or_default()
             {
-                cx.emit_lint(TEST_ITEM_VISIBILITY, item, "can you see this item?")
-                    .decorate(|diag| {
-                        let ast_vis = item.visibility();
-                        let vis = ast_vis.semantics();
-                        diag.span(item.ident().unwrap().span());
-                        diag.note(format!("vis.is_default()      -> {}", vis.is_default()));
-                        diag.note(format!("vis.is_pub()          -> {}", vis.is_pub()));
-                        diag.note(format!("vis.is_crate_scoped() -> {}", vis.is_crate_scoped()));
-                        diag.note(format!("vis.scope()           -> {:?}", vis.scope()));
-                        diag.note(format!("vis.span(): `{:?}`", ast_vis.span().map(|s| s.snippet_or(""))));
-                    });
+                check_visibility(cx, item);
             }
         }
     }
@@ -174,18 +281,72 @@ impl LintPass for TestLintPass {
         if field.ident().starts_with("find_me") {
             emit_item_with_test_name_lint(cx, field, "a field");
         }
+        if field.ident().starts_with("test_attrs") {
+            emit_attrs_lint(cx, field, field.attrs());
+        }
+        if field.ident().starts_with("resolve_ty") {
+            cx.emit_lint(TEST_LINT, field, "resolving the field type")
+                .decorate(|diag| {
+                    diag.span(field.ty().span());
+                    diag.note(format!("{:#?}", field.ty().resolve()));
+                });
+        }
     }
 
     fn check_variant<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, variant: &'ast EnumVariant<'ast>) {
         if variant.ident().starts_with("FindMe") {
             emit_item_with_test_name_lint(cx, variant, "an enum variant");
         }
+        if variant.ident().starts_with("TestAttrs") {
+            emit_attrs_lint(cx, variant, variant.attrs());
+        }
+        if variant.ident().starts_with("TestDiscr") {
+            cx.emit_lint(TEST_LINT, variant, "testing `EnumVariant::discriminant_value`")
+                .decorate(|diag| {
+                    diag.note(format!("discriminant_value() -> {:?}", variant.discriminant_value()));
+                });
+        }
+    }
+
+    fn check_body<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, body: &'ast ast::Body<'ast>) {
+        let owner_name = body
+            .owner_item()
+            .and_then(|item| item.ident().map(|ident| ident.name().to_lowercase()))
+            .unwrap_or_default();
+        if owner_name.starts_with("test_const_context") {
+            cx.emit_lint(TEST_LINT, body.expr(), "testing `Body::is_const`")
+                .decorate(|diag| {
+                    diag.note(format!("is_const() -> {}", body.is_const()));
+                });
+        }
+
+        if owner_name.starts_with("test_body_params") {
+            check_body_params(cx, body.expr(), body);
+        }
+
+        if owner_name.starts_with("test_body_owner") {
+            check_body_owner(cx, body.expr(), body);
+        }
     }
 
+    #[allow(clippy::too_many_lines)]
     fn check_stmt<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>) {
+        utils::check_expr_eq(cx, stmt);
+
         // I didn't realize that `let_chains` are still unstable. This makes the
         // code significantly less readable -.-
         if let StmtKind::Let(lets) = stmt {
+            // The pattern of `let-else` statements is usually refutable, like `Some(x)`
+            if let Some(els) = lets.els() {
+                if lets.pat().span().snippet_or("").contains("_print_let_else") {
+                    cx.emit_lint(TEST_LINT, stmt, "print let-else").decorate(|diag| {
+                        diag.span(els.span());
+                        diag.note(format!("init: `{}`", lets.init().unwrap().span().snippet_or("..")));
+                        diag.note(format!("{els:#?}"));
+                    });
+                }
+            }
+
             let PatKind::Ident(ident) = lets.pat() else { return };
             let Some(expr) = lets.init() else { return };
             if ident.name().starts_with("_print") {
@@ -226,16 +387,391 @@ impl LintPass for TestLintPass {
                 });
             } else if ident.name().starts_with("_check_ast_map") {
                 check_ast_map(cx, lets);
+            } else if ident.name().starts_with("_check_ctor_target") {
+                check_ctor_target(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_method_target") {
+                let ExprKind::Method(method) = expr else {
+                    unreachable!("the init expression should be a method call")
+                };
+                cx.emit_lint(TEST_AST_MAP, stmt, "testing `MarkerContext::resolve_method_target`")
+                    .decorate(|diag| {
+                        let target = cx.resolve_method_target(method);
+                        let name = target
+                            .and_then(|id| cx.ast().item(id))
+                            .and_then(|item| item.ident().map(|ident| ident.name().to_string()));
+                        diag.note(format!("`resolve_method_target()` --> {target:?} (name: {name:?})"));
+                    });
+            } else if ident.name().starts_with("_check_subspan") {
+                check_subspan(cx, stmt);
+            } else if ident.name().starts_with("_check_call_target") {
+                let ExprKind::Call(call) = expr else {
+                    unreachable!("the init expression should be a call")
+                };
+                cx.emit_lint(TEST_AST_MAP, stmt, "testing `CallExpr::resolved_fn`")
+                    .decorate(|diag| {
+                        let target = call.resolved_fn();
+                        let name = target
+                            .and_then(|id| cx.ast().item(id))
+                            .and_then(|item| item.ident().map(|ident| ident.name().to_string()));
+                        diag.note(format!("`resolved_fn()` --> {target:?} (name: {name:?})"));
+                        let is_drop = target.is_some_and(|id| cx.resolve_fn_ids("std::mem::drop").contains(&id));
+                        diag.note(format!("is `std::mem::drop` --> {is_drop}"));
+                    });
+            } else if ident.name().starts_with("_check_fn_ids") {
+                check_fn_ids(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_ty_traits") {
+                check_ty_traits(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_ty_layout") {
+                cx.emit_lint(TEST_AST_MAP, stmt, "testing `MarkerContext::ty_size`")
+                    .decorate(|diag| {
+                        diag.note(format!("`ty_size()` --> {:?}", cx.ty_size(expr.ty())));
+                        diag.note(format!("`ty_align()` --> {:?}", cx.ty_align(expr.ty())));
+                        diag.note(format!(
+                            "`ty_size()` (refs peeled) --> {:?}",
+                            cx.ty_size(expr.ty().peel_refs())
+                        ));
+                    });
+            } else if ident.name().starts_with("_check_uninhabited") {
+                let ty = expr.ty();
+                cx.emit_lint(TEST_AST_MAP, stmt, "testing `MarkerContext::ty_is_uninhabited`")
+                    .decorate(|diag| {
+                        diag.note(format!("expr type --> {}", cx.ty_is_uninhabited(ty)));
+                        if let TyKind::Adt(adt) = ty {
+                            for (index, arg) in adt.generics().args().iter().enumerate() {
+                                if let marker_api::sem::GenericArgKind::Ty(arg) = arg {
+                                    diag.note(format!("generic arg {index} --> {}", cx.ty_is_uninhabited(*arg)));
+                                }
+                            }
+                        }
+                    });
+            } else if ident.name().starts_with("_check_inline_closure") {
+                check_inline_closure(cx, expr);
+            } else if ident.name().starts_with("_check_swap_operands") {
+                check_swap_operands(cx, expr);
+            } else if ident.name().starts_with("_check_invalid_multipart") {
+                check_invalid_multipart(cx, ident.name(), expr);
+            } else if ident.name().starts_with("_check_parent") {
+                check_parent_nodes(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_match_source") {
+                check_match_source(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_match_arms") {
+                check_match_arms(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_closure_body") {
+                let ExprKind::Closure(closure) = expr else {
+                    unreachable!("the init expression should be a closure")
+                };
+                cx.emit_lint(TEST_LINT, stmt, "print closure body").decorate(|diag| {
+                    diag.note(format!("is_coroutine() -> {}", closure.is_coroutine()));
+                    diag.note(format!("{:#?}", closure.body_expr()));
+                });
+            } else if ident.name().starts_with("_check_captures") {
+                check_closure_captures(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_body_params") {
+                let ExprKind::Closure(closure) = expr else {
+                    unreachable!("the init expression should be a closure")
+                };
+                check_body_params(cx, stmt, cx.ast().body(closure.body_id()));
+            } else if ident.name().starts_with("_check_closure_ty") {
+                cx.emit_lint(TEST_LINT, stmt, "testing `ClosureTy`").decorate(|diag| {
+                    diag.note(format!("ty() -> {}", describe_ty(expr.ty())));
+                });
+            } else if ident.name().starts_with("_check_ice") {
+                panic!("free ice cream for every statement!!!");
+            } else if ident.name().starts_with("_check_adt_fields") {
+                check_adt_fields(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_impls_for") {
+                check_impls_for_type(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_adjusted_ty") {
+                check_adjusted_ty(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_bin_op_resolution") {
+                check_bin_op_resolution(cx, stmt, expr);
+            } else if ident.name().starts_with("_check_str_lit") {
+                let ExprKind::StrLit(lit) = expr else {
+                    unreachable!("the init expression should be a string literal")
+                };
+                cx.emit_lint(TEST_LINT, stmt, "testing `StrLitExpr`").decorate(|diag| {
+                    diag.note(format!("is_raw_lit() -> {}", lit.is_raw_lit()));
+                    diag.note(format!("is_byte_str() -> {}", lit.is_byte_str()));
+                    diag.note(format!("str_value() -> {:?}", lit.str_value()));
+                    diag.note(format!("value() -> {:?}", lit.value()));
+                    diag.note(format!("byte_value() -> {:?}", lit.byte_value()));
+                });
+            } else if ident.name().starts_with("_check_emit_lint_once") {
+                // The snippet of the init expression is used as the key
+                let key = expr.span().snippet_or("<..>").to_string();
+                cx.emit_lint_once(TEST_LINT, stmt, &key, format!("first emission for `{key}`"));
+            } else if ident.name().starts_with("_check_int_lit") {
+                let ExprKind::IntLit(lit) = expr else {
+                    unreachable!("the init expression should be an integer literal")
+                };
+                cx.emit_lint(TEST_LINT, stmt, "testing `IntLitExpr`").decorate(|diag| {
+                    diag.note(format!("num_kind() -> {:?}", lit.num_kind()));
+                    diag.note(format!("fits_suffix() -> {:?}", lit.fits_suffix()));
+                    diag.note(format!("value_as::<u8>() -> {:?}", lit.value_as::<u8>()));
+                    diag.note(format!("value_as::<i64>() -> {:?}", lit.value_as::<i64>()));
+                });
+            } else if ident.name().starts_with("_check_macro_def_site") {
+                cx.emit_lint(TEST_AST_MAP, stmt, "testing `ExpnInfo::def_site`")
+                    .decorate(|diag| {
+                        for frame in cx.macro_backtrace(expr.span()) {
+                            // `macro_rules!` definitions span multiple lines
+                            let def_site = frame
+                                .def_site()
+                                .map(|span| span.snippet_or("<..>").lines().next().unwrap_or_default().to_string());
+                            diag.note(format!("`{}!` defined at: {def_site:?}", frame.name()));
+                        }
+                    });
+            } else if ident.name().starts_with("_check_macro_backtrace") {
+                cx.emit_lint(TEST_AST_MAP, stmt, "testing `MarkerContext::macro_backtrace`")
+                    .decorate(|diag| {
+                        for frame in cx.macro_backtrace(expr.span()) {
+                            diag.note(format!(
+                                "`{}!` called at: {:?}",
+                                frame.name(),
+                                frame.call_site().snippet_or("<..>")
+                            ));
+                        }
+                        diag.note(format!(
+                            "call site snippet: {:?}",
+                            expr.span().call_site_snippet().unwrap_or("<..>")
+                        ));
+                    });
             }
         }
     }
 
     fn check_expr<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, expr: ExprKind<'ast>) {
+        // `PRINT_EVERY_EXPR` and `TEST_LINT` are declared as the `expr_lint`s of
+        // this pass. The driver should therefore never call this function, if
+        // both of them are allowed.
+        if let ExprKind::StrLit(lit) = expr {
+            match lit.str_value() {
+                Some("uilints_check_expr_should_be_skipped") => {
+                    assert!(
+                        cx.lint_level(PRINT_EVERY_EXPR, expr) != Level::Allow
+                            || cx.lint_level(TEST_LINT, expr) != Level::Allow
+                    );
+                },
+                Some("uilints_check_expr_emits_test_lint") => {
+                    cx.emit_lint(TEST_LINT, expr, "`check_expr` was called for this expression");
+                },
+                _ => {},
+            }
+        }
+
         cx.emit_lint(PRINT_EVERY_EXPR, expr, "expr").decorate(|diag| {
             diag.note(&format!("SpanSource: {:#?}", expr.span().source()));
             diag.note(&format!("Snippet: {:#?}", expr.span().snippet_or("<..>")));
         });
     }
+
+    fn check_ty<'ast>(&mut self, cx: &'ast MarkerContext<'ast>, ty: ast::TyKind<'ast>) {
+        let ast::TyKind::Path(path_ty) = ty else { return };
+        let Some(segment) = path_ty.path().segments().last() else {
+            return;
+        };
+        if segment.ident().name() == "CheckTy" {
+            cx.emit_lint(TEST_LINT, ty, "checking `CheckTy`").decorate(|diag| {
+                let type_args: Vec<_> = segment
+                    .generics()
+                    .type_args()
+                    .map(|arg| arg.span().snippet_or("<..>").to_string())
+                    .collect();
+                diag.note(format!("type arguments: {type_args:?}"));
+            });
+        }
+    }
+
+    fn check_generic_param<'ast>(
+        &mut self,
+        cx: &'ast MarkerContext<'ast>,
+        param: &'ast ast::GenericParamKind<'ast>,
+        generics: &'ast ast::GenericParams<'ast>,
+    ) {
+        let name = match param {
+            ast::GenericParamKind::Lifetime(param) => param.name(),
+            ast::GenericParamKind::Ty(param) => param.name(),
+            ast::GenericParamKind::Const(param) => param.name(),
+            _ => return,
+        };
+        if !name.to_lowercase().contains("check") {
+            return;
+        }
+
+        cx.emit_lint(TEST_LINT, param, format!("checking generic param `{name}`"))
+            .decorate(|diag| {
+                let bounds: Vec<_> = generics
+                    .bounds_for(param.id())
+                    .map(|bound| bound.span().snippet_or("<..>"))
+                    .collect();
+                diag.note(format!("bounds: {bounds:?}"));
+            });
+    }
+}
+
+/// Suggests replacing immediately invoked closures without parameters, like
+/// `(|| 5)()`, with the body expression of the closure.
+fn check_inline_closure<'ast>(cx: &'ast MarkerContext<'ast>, expr: ExprKind<'ast>) {
+    let ExprKind::Call(call) = expr else { return };
+    let ExprKind::Closure(closure) = call.func() else {
+        return;
+    };
+    if !closure.params().is_empty() || !call.args().is_empty() {
+        return;
+    }
+
+    let body = closure.body_expr();
+    cx.emit_lint(TEST_LINT, expr, "this closure is called immediately")
+        .decorate(|diag| {
+            diag.span_suggestion(
+                "try using the expression directly",
+                expr.span(),
+                body.span().snippet_or("<..>"),
+                Applicability::MachineApplicable,
+            );
+        });
+}
+
+/// Suggests swapping the operands of a binary operation, using a multipart
+/// suggestion with one edit per operand.
+fn check_swap_operands<'ast>(cx: &'ast MarkerContext<'ast>, expr: ExprKind<'ast>) {
+    let ExprKind::BinaryOp(op) = expr else { return };
+    let (left, right) = (op.left(), op.right());
+    let (left, right) = (left.span(), right.span());
+
+    cx.emit_lint(TEST_LINT, expr, "testing `DiagnosticBuilder::multipart_suggestion`")
+        .decorate(|diag| {
+            diag.multipart_suggestion(
+                "try swapping the operands",
+                &[
+                    (left.clone(), right.snippet_or("<..>").to_string()),
+                    (right.clone(), left.snippet_or("<..>").to_string()),
+                ],
+                Applicability::MachineApplicable,
+            );
+        });
+}
+
+/// Adds multipart suggestions, which would be rejected by rustc. These should
+/// be dropped by the [`DiagnosticBuilder`], instead of ending in an ICE.
+fn check_invalid_multipart<'ast>(cx: &'ast MarkerContext<'ast>, name: &str, expr: ExprKind<'ast>) {
+    let ExprKind::BinaryOp(op) = expr else { return };
+    let left = op.left();
+    let left = left.span();
+
+    cx.emit_lint(TEST_LINT, expr, "testing invalid multipart suggestions")
+        .decorate(|diag| {
+            if name.ends_with("_empty") {
+                diag.multipart_suggestion("no edits", &[], Applicability::MachineApplicable);
+                diag.multipart_suggestion(
+                    "only no-op edits",
+                    &[(left.with_end(left.start()), String::new())],
+                    Applicability::MachineApplicable,
+                );
+            } else {
+                diag.multipart_suggestion(
+                    "overlapping edits",
+                    &[(expr.span().clone(), "1".to_string()), (left.clone(), "2".to_string())],
+                    Applicability::MachineApplicable,
+                );
+            }
+            diag.multipart_suggestion(
+                "valid edits after no-op edits are kept",
+                &[
+                    (left.with_end(left.start()), String::new()),
+                    (left.clone(), "(1)".to_string()),
+                ],
+                Applicability::MaybeIncorrect,
+            );
+        });
+}
+
+/// Emits a lint for the `mut` keyword of the statement and notes the results of
+/// [`Span::subspan`] for some ranges in the init expression.
+fn check_subspan<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>) {
+    let StmtKind::Let(lets) = stmt else { return };
+    let init = lets.init().expect("the statement should have an init expression");
+    let init_span = init.span();
+    let describe = |range: std::ops::Range<usize>| {
+        let span = init_span.subspan(range.clone());
+        let snippet = span.as_ref().map(|span| span.snippet_or("<..>"));
+        format!("`init.subspan({range:?})` -> {snippet:?}")
+    };
+
+    cx.emit_lint(TEST_LINT, stmt, "testing `Span::subspan`")
+        .decorate(|diag| {
+            diag.span(
+                &stmt
+                    .span()
+                    .subspan(4..7)
+                    .expect("the statement should start with `let mut`"),
+            );
+            diag.note(describe(0..2));
+            diag.note(describe(2..4));
+            diag.note(describe(3..4));
+            diag.note(describe(5..5));
+            diag.note(describe(0..init_span.len()));
+            diag.note(describe(7..9));
+        });
+}
+
+/// The root module of the `test_try_span` test has attributes with and
+/// without spans, since Marker passes crate attributes via `-Zcrate-attr`.
+fn check_try_span<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
+    let describe = |span: Option<&Span<'_>>| span.map(|span| span.snippet_or("..").to_string());
+
+    cx.emit_lint(TEST_LINT, item, "testing `HasSpan::try_span`")
+        .decorate(|diag| {
+            let ident = item.ident().unwrap();
+            // The ident of the root module has no span
+            diag.span(ident.try_span().unwrap_or(item.span()));
+            diag.note(format!("item.try_span() -> {:?}", item.try_span().is_some()));
+            diag.note(format!("ident.try_span() -> {:?}", describe(ident.try_span())));
+            for attr in item.attrs() {
+                let path: Vec<_> = attr.segments().iter().map(Ident::name).collect();
+                diag.note(format!(
+                    "`{}`.try_span() -> {:?}",
+                    path.join("::"),
+                    describe(attr.try_span())
+                ));
+            }
+        });
+}
+
+fn check_parent_nodes<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    let parent_snippet = |expr: ExprKind<'ast>| {
+        cx.ast()
+            .parent_expr(expr.id())
+            .map(|parent| parent.span().snippet_or("..").to_string())
+    };
+
+    cx.emit_lint(
+        TEST_AST_MAP,
+        stmt,
+        "testing `AstMap::parent_expr` and `AstMap::parent_item`",
+    )
+    .decorate(|diag| {
+        diag.note(format!("parent_expr(init) -> {:?}", parent_snippet(expr)));
+        let item = cx
+            .ast()
+            .parent_item(expr.id())
+            .and_then(|id| cx.ast().item(id))
+            .and_then(|item| item.ident().map(|ident| ident.name().to_string()));
+        diag.note(format!("parent_item(init) -> {item:?}"));
+
+        match expr {
+            ExprKind::BinaryOp(op) => {
+                diag.note(format!("parent_expr(left) -> {:?}", parent_snippet(op.left())));
+            },
+            ExprKind::Closure(closure) => {
+                diag.note(format!(
+                    "parent_expr(body) -> {:?}",
+                    parent_snippet(closure.body_expr())
+                ));
+            },
+            _ => {},
+        }
+    });
 }
 
 fn check_ast_map<'ast>(cx: &'ast MarkerContext<'ast>, lets: &'ast LetStmt<'ast>) {
@@ -268,6 +804,721 @@ fn check_ast_map<'ast>(cx: &'ast MarkerContext<'ast>, lets: &'ast LetStmt<'ast>)
     }
 }
 
+fn check_item_children<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
+    fn collect_children<'ast>(cx: &'ast MarkerContext<'ast>, id: ItemId, depth: usize, notes: &mut Vec<String>) {
+        for child in cx.ast().item_children(id) {
+            let name = child
+                .ident()
+                .map(Ident::name)
+                .filter(|name| !name.is_empty())
+                .unwrap_or("<unnamed>");
+            notes.push(format!("{}{name}", "  ".repeat(depth)));
+            collect_children(cx, child.id(), depth + 1, notes);
+        }
+    }
+
+    let ItemKind::Mod(module) = item else { return };
+    let Some(ident) = module.ident() else { return };
+    if !ident.name().starts_with("test_item_children") {
+        return;
+    }
+
+    let mut notes = vec![];
+    collect_children(cx, module.id(), 0, &mut notes);
+    cx.emit_lint(TEST_LINT, item, "testing `item_children`")
+        .decorate(|diag| {
+            diag.span(ident.span());
+            for note in notes {
+                diag.note(note);
+            }
+        });
+}
+
+fn check_source_file<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
+    let Some(ident) = item.ident() else { return };
+    if !ident.name().starts_with("test_source_file") {
+        return;
+    }
+
+    cx.emit_lint(TEST_LINT, item, "testing `ItemKind::source_file`")
+        .decorate(|diag| {
+            diag.span(ident.span());
+            diag.note(format!("source file: {:?}", item.source_file()));
+            if let ItemKind::Mod(module) = item {
+                diag.note(format!("declared in: {:?}", module.span().source_file()));
+            }
+        });
+}
+
+fn check_visibility<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
+    cx.emit_lint(TEST_ITEM_VISIBILITY, item, "can you see this item?")
+        .decorate(|diag| {
+            let ast_vis = item.visibility();
+            let vis = ast_vis.semantics();
+            diag.span(item.ident().unwrap().span());
+            diag.note(format!("vis.is_default()      -> {}", vis.is_default()));
+            diag.note(format!("vis.is_pub()          -> {}", vis.is_pub()));
+            diag.note(format!("vis.is_crate_scoped() -> {}", vis.is_crate_scoped()));
+            diag.note(format!("vis.scope()           -> {:?}", vis.scope()));
+            diag.note(format!("vis.span(): `{:?}`", ast_vis.span().map(|s| s.snippet_or(""))));
+            let kind = match ast_vis.kind() {
+                VisibilityScope::Public => "Public".to_string(),
+                VisibilityScope::Crate => "Crate".to_string(),
+                VisibilityScope::Restricted(path) => format!("Restricted(`{}`)", path.span().snippet_or("")),
+                VisibilityScope::Inherited => "Inherited".to_string(),
+                _ => unreachable!(),
+            };
+            diag.note(format!("vis.kind(): {kind}"));
+            if let Some(path) = ast_vis.restricted_path() {
+                let module = cx
+                    .ast()
+                    .item(path.module())
+                    .and_then(|module| module.ident().map(|ident| ident.name().to_string()));
+                diag.note(format!(
+                    "vis.restricted_path(): `{}` -> {module:?}",
+                    path.span().snippet_or("")
+                ));
+            }
+        });
+}
+
+fn check_const_value<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
+    let value = match item {
+        ItemKind::Const(item) => item.value(),
+        ItemKind::Static(item) => item.value(),
+        _ => unreachable!("only const and static items should have this prefix"),
+    };
+    cx.emit_lint(TEST_LINT, item, "checking the const value")
+        .decorate(|diag| {
+            diag.note(format!("value() -> {:?}", value.map(sem::ConstValue::kind)));
+        });
+}
+
+fn check_const_expr_values<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
+    let exprs: Vec<_> = match item {
+        ItemKind::TyAlias(alias) => match alias.aliased_ty() {
+            Some(marker_api::ast::TyKind::Array(array)) => array.len().into_iter().collect(),
+            _ => vec![],
+        },
+        ItemKind::Enum(item) => item.variants().iter().filter_map(EnumVariant::discriminant).collect(),
+        _ => unreachable!("only type aliases and enums should have this prefix"),
+    };
+    cx.emit_lint(TEST_LINT, item, "checking the const expression values")
+        .decorate(|diag| {
+            for expr in exprs {
+                diag.span_note(
+                    format!("value() -> {:?}", expr.value().map(sem::ConstValue::kind)),
+                    expr.expr().span(),
+                );
+            }
+        });
+}
+
+fn check_match_arms<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    let ExprKind::Match(match_expr) = expr else {
+        unreachable!("the init expression should be a match expression")
+    };
+
+    cx.emit_lint(TEST_LINT, stmt, "testing `MatchExpr::is_exhaustive_without_wildcard`")
+        .decorate(|diag| {
+            diag.note(format!(
+                "`is_exhaustive_without_wildcard()` --> {:?}",
+                match_expr.is_exhaustive_without_wildcard()
+            ));
+            for arm in match_expr.arms() {
+                diag.note(format!(
+                    "arm `{}`: is_wildcard: {}, is_irrefutable: {}",
+                    arm.pat().span().snippet_or("<..>"),
+                    arm.is_wildcard(),
+                    arm.is_irrefutable()
+                ));
+            }
+        });
+}
+
+fn check_match_source<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    let mut notes = vec![];
+    let _: Option<()> = expr.for_each_expr(cx, |expr| {
+        match expr {
+            ExprKind::Match(match_expr) => notes.push(format!(
+                "`match_source()` of `{}` --> {:?}",
+                expr.span().snippet_or("<..>"),
+                match_expr.match_source()
+            )),
+            ExprKind::For(_) => notes.push(format!("`{}` is a `ForExpr`", expr.span().snippet_or("<..>"))),
+            _ => {},
+        }
+        ControlFlow::Continue(())
+    });
+
+    cx.emit_lint(TEST_LINT, stmt, "testing `MatchExpr::match_source`")
+        .decorate(|diag| {
+            for note in notes {
+                diag.note(note);
+            }
+        });
+}
+
+fn check_closure_captures<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    let ExprKind::Closure(closure) = expr else {
+        unreachable!("the init expression should be a closure")
+    };
+    cx.emit_lint(TEST_LINT, stmt, "testing `ClosureExpr::captures`")
+        .decorate(|diag| {
+            diag.note(format!("is_move() -> {}", closure.is_move()));
+            for capture in closure.captures() {
+                diag.note(format!(
+                    "captures {:?} by {:?}: {:?}",
+                    capture.var(),
+                    capture.kind(),
+                    capture.ty()
+                ));
+            }
+        });
+}
+
+/// A short description of the type, which is enough for these tests
+fn describe_ty(ty: TyKind<'_>) -> String {
+    match ty {
+        TyKind::Ref(ty) => format!("&{}", describe_ty(ty.inner_ty())),
+        TyKind::Slice(ty) => format!("[{}]", describe_ty(ty.inner_ty())),
+        TyKind::Array(ty) => format!("[{}; _]", describe_ty(ty.inner_ty())),
+        TyKind::Tuple(ty) => format!(
+            "({})",
+            ty.types()
+                .iter()
+                .map(|ty| describe_ty(*ty))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        TyKind::Text(ty) if ty.is_str() => "str".to_string(),
+        TyKind::Bool(_) => "bool".to_string(),
+        TyKind::Num(_) => "<num>".to_string(),
+        TyKind::Adt(_) => "<adt>".to_string(),
+        TyKind::TraitObj(_) => "dyn <trait>".to_string(),
+        TyKind::Closure(ty) => format!(
+            "|{}| -> {}",
+            ty.params()
+                .iter()
+                .map(|ty| describe_ty(*ty))
+                .collect::<Vec<_>>()
+                .join(", "),
+            describe_ty(ty.return_ty())
+        ),
+        _ => "<..>".to_string(),
+    }
+}
+
+fn check_adjusted_ty<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    cx.emit_lint(TEST_LINT, stmt, "testing `ExprKind::adjusted_ty`")
+        .decorate(|diag| {
+            diag.note(format!("ty() -> {}", describe_ty(expr.ty())));
+            diag.note(format!("adjusted_ty() -> {}", describe_ty(expr.adjusted_ty())));
+        });
+}
+
+fn check_adt_fields<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    fn describe_field(field: &marker_api::sem::AdtField<'_>) -> String {
+        let ty = match field.ty() {
+            TyKind::Unstable(_) => "<unstable>".to_string(),
+            ty => describe_ty(ty),
+        };
+        format!("{}: {ty}", field.name())
+    }
+
+    let TyKind::Adt(adt) = expr.ty().peel_refs() else {
+        unreachable!("the init expression should be an ADT")
+    };
+    cx.emit_lint(TEST_LINT, stmt, "testing `AdtTy::fields` and `AdtTy::variants`")
+        .decorate(|diag| {
+            let fields: Vec<_> = adt.fields().iter().map(describe_field).collect();
+            diag.note(format!("fields() -> [{}]", fields.join(", ")));
+            for variant in adt.variants() {
+                let fields: Vec<_> = variant.fields().iter().map(describe_field).collect();
+                diag.note(format!("variant `{}` -> [{}]", variant.name(), fields.join(", ")));
+            }
+        });
+}
+
+fn check_fn_ids<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    // Calls of unsafe functions are wrapped in an `unsafe` block
+    let expr = match expr {
+        ExprKind::Block(block) => block.expr().expect("the block should have an expression"),
+        _ => expr,
+    };
+    let ExprKind::Call(call) = expr else {
+        unreachable!("the init expression should be a call")
+    };
+    let ExprKind::Path(path) = call.func() else {
+        unreachable!("the called function should be a path")
+    };
+    let AstPathTarget::Item(target) = path.path().resolve() else {
+        unreachable!("the path should resolve to an item")
+    };
+
+    cx.emit_lint(TEST_LINT, stmt, "testing `MarkerContext::resolve_fn_ids`")
+        .decorate(|diag| {
+            for path in [
+                "std::mem::transmute",
+                "core::mem::drop",
+                "std::vec::Vec::new",
+                "core::clone::Clone::clone",
+                "crate::local_fn",
+                "crate::Local::assoc_fn",
+                "crate::LocalTrait::trait_fn",
+                "std::vec::Vec",
+                "std::mem",
+                "std",
+                "std::mem::unknown",
+            ] {
+                let ids = cx.resolve_fn_ids(path);
+                diag.note(format!(
+                    "{path:?} -> {} id(s), matches: {}",
+                    ids.len(),
+                    ids.contains(&target)
+                ));
+            }
+        });
+}
+
+fn check_fn_item<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast ast::FnItem<'ast>) {
+    if let Some(ident) = item.ident() {
+        if ident.name() == "test_ty_id_resolution_trigger" {
+            test_ty_id_resolution(cx);
+        } else if ident.name() == "uilints_please_ice_on_this" {
+            panic!("free ice cream for everyone!!!");
+        } else if ident.name().starts_with("test_return_ty") {
+            cx.emit_lint(TEST_LINT, item, "checking the return type")
+                .decorate(|diag| {
+                    diag.span(ident.span());
+                    diag.note(format!("returns_impl_trait() -> {}", item.returns_impl_trait()));
+                    diag.note(format!("returns_self() -> {}", item.returns_self()));
+                });
+        } else if ident.name().starts_with("test_lint_metadata") {
+            cx.emit_lint(TEST_LINT_METADATA, item, "a lint with a category and URL")
+                .decorate(|diag| {
+                    diag.span(ident.span());
+                });
+        } else if ident.name().starts_with("test_bounds_for") {
+            cx.emit_lint(TEST_LINT, item, "testing `GenericParams::bounds_for`")
+                .decorate(|diag| {
+                    diag.span(ident.span());
+                    let generics = item.generics();
+                    for param in generics.params() {
+                        let ast::GenericParamKind::Ty(param) = param else {
+                            continue;
+                        };
+                        let bounds: Vec<_> = generics
+                            .bounds_for(param.id())
+                            .map(|bound| bound.span().snippet_or("<..>"))
+                            .collect();
+                        diag.note(format!("`{}`: {bounds:?}", param.name()));
+                    }
+                });
+        } else if ident.name().starts_with("test_ref_lifetime") {
+            cx.emit_lint(TEST_LINT, item, "testing `RefTy::lifetime`")
+                .decorate(|diag| {
+                    diag.span(ident.span());
+                    for param in item.params() {
+                        let ast::TyKind::Ref(ref_ty) = param.ty() else {
+                            continue;
+                        };
+                        let lifetime = ref_ty.lifetime().map(|lt| {
+                            format!(
+                                "label: {:?}, is_static: {}, is_infer: {}",
+                                lt.label(),
+                                lt.is_static(),
+                                lt.is_infer()
+                            )
+                        });
+                        diag.note(format!("`{}` -> {lifetime:?}", param.ty().span().snippet_or("<..>")));
+                    }
+                });
+        } else if ident.name().starts_with("test_binding_mode") {
+            check_binding_modes(cx, item);
+        } else if ident.name().starts_with("test_pat_ty") {
+            check_pat_tys(cx, item);
+        } else if ident.name().starts_with("test_union_access") {
+            check_union_access(cx, item);
+        } else if ident.name().starts_with("test_let_chain") {
+            check_let_chains(cx, item);
+        } else if ident.name().starts_with("test_fn_context") {
+            cx.emit_lint(TEST_LINT, item, "checking the fn context")
+                .decorate(|diag| {
+                    diag.span(ident.span());
+                    diag.note(format!("context() -> {:?}", item.context()));
+                    diag.note(format!("has body -> {}", item.body_id().is_some()));
+                });
+        }
+    }
+}
+
+fn check_binding_modes<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast ast::FnItem<'ast>) {
+    struct IdentPatCollector {
+        notes: Vec<String>,
+    }
+    impl marker_utils::visitor::Visitor<()> for IdentPatCollector {
+        fn scope(&self) -> marker_utils::visitor::VisitorScope {
+            marker_utils::visitor::VisitorScope::AllBodies
+        }
+
+        fn visit_pat<'v_ast>(&mut self, _cx: &'v_ast MarkerContext<'v_ast>, pat: PatKind<'v_ast>) -> ControlFlow<()> {
+            if let PatKind::Ident(ident) = pat {
+                let sub_pat = ident
+                    .binding_pat()
+                    .map(|sub_pat| sub_pat.span().snippet_or("<..>").to_string());
+                self.notes.push(format!(
+                    "`{}` -> {:?}, binding pattern: {sub_pat:?}",
+                    ident.name(),
+                    ident.binding_mode()
+                ));
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut collector = IdentPatCollector { notes: vec![] };
+    let _ = marker_utils::visitor::traverse_item(cx, &mut collector, ItemKind::Fn(item));
+    cx.emit_lint(TEST_LINT, item, "testing `IdentPat::binding_mode`")
+        .decorate(|diag| {
+            if let Some(ident) = item.ident() {
+                diag.span(ident.span());
+            }
+            for note in &collector.notes {
+                diag.note(note);
+            }
+        });
+}
+
+fn check_pat_tys<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast ast::FnItem<'ast>) {
+    struct PatTyCollector {
+        notes: Vec<String>,
+    }
+    impl marker_utils::visitor::Visitor<()> for PatTyCollector {
+        fn scope(&self) -> marker_utils::visitor::VisitorScope {
+            marker_utils::visitor::VisitorScope::AllBodies
+        }
+
+        fn visit_pat<'v_ast>(&mut self, _cx: &'v_ast MarkerContext<'v_ast>, pat: PatKind<'v_ast>) -> ControlFlow<()> {
+            self.notes.push(format!(
+                "`{}` -> {}",
+                pat.span().snippet_or("<..>"),
+                describe_ty(pat.ty())
+            ));
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut collector = PatTyCollector { notes: vec![] };
+    let _ = marker_utils::visitor::traverse_item(cx, &mut collector, ItemKind::Fn(item));
+    cx.emit_lint(TEST_LINT, item, "testing `PatKind::ty`").decorate(|diag| {
+        if let Some(ident) = item.ident() {
+            diag.span(ident.span());
+        }
+        for note in &collector.notes {
+            diag.note(note);
+        }
+    });
+}
+
+fn check_union_access<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast ast::FnItem<'ast>) {
+    struct FieldExprCollector {
+        notes: Vec<String>,
+    }
+    impl marker_utils::visitor::Visitor<()> for FieldExprCollector {
+        fn scope(&self) -> marker_utils::visitor::VisitorScope {
+            marker_utils::visitor::VisitorScope::AllBodies
+        }
+
+        fn visit_expr<'v_ast>(
+            &mut self,
+            _cx: &'v_ast MarkerContext<'v_ast>,
+            expr: ExprKind<'v_ast>,
+        ) -> ControlFlow<()> {
+            if let ExprKind::Field(field) = expr {
+                self.notes.push(format!(
+                    "`{}` -> is_union_access: {}",
+                    field.span().snippet_or("<..>"),
+                    field.is_union_access()
+                ));
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut collector = FieldExprCollector { notes: vec![] };
+    let _ = marker_utils::visitor::traverse_item(cx, &mut collector, ItemKind::Fn(item));
+    cx.emit_lint(TEST_LINT, item, "testing `FieldExpr::is_union_access`")
+        .decorate(|diag| {
+            if let Some(ident) = item.ident() {
+                diag.span(ident.span());
+            }
+            for note in &collector.notes {
+                diag.note(note);
+            }
+        });
+}
+
+fn check_let_chains<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast ast::FnItem<'ast>) {
+    struct IfExprCollector {
+        notes: Vec<String>,
+    }
+    impl marker_utils::visitor::Visitor<()> for IfExprCollector {
+        fn scope(&self) -> marker_utils::visitor::VisitorScope {
+            marker_utils::visitor::VisitorScope::AllBodies
+        }
+
+        fn visit_expr<'v_ast>(
+            &mut self,
+            _cx: &'v_ast MarkerContext<'v_ast>,
+            expr: ExprKind<'v_ast>,
+        ) -> ControlFlow<()> {
+            if let ExprKind::If(if_expr) = expr {
+                let chain = if_expr.let_chain().map(|chain| {
+                    chain
+                        .iter()
+                        .map(|cond| {
+                            let kind = if matches!(cond, ExprKind::Let(_)) {
+                                "let"
+                            } else {
+                                "bool"
+                            };
+                            format!("{kind}: `{}`", cond.span().snippet_or("<..>"))
+                        })
+                        .collect::<Vec<_>>()
+                });
+                self.notes.push(format!(
+                    "`{}` -> let_chain: {chain:?}",
+                    if_expr.condition().span().snippet_or("<..>")
+                ));
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut collector = IfExprCollector { notes: vec![] };
+    let _ = marker_utils::visitor::traverse_item(cx, &mut collector, ItemKind::Fn(item));
+    cx.emit_lint(TEST_LINT, item, "testing `IfExpr::let_chain`")
+        .decorate(|diag| {
+            if let Some(ident) = item.ident() {
+                diag.span(ident.span());
+            }
+            for note in &collector.notes {
+                diag.note(note);
+            }
+        });
+}
+
+fn check_impl_trait_ref<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast ast::ImplItem<'ast>) {
+    cx.emit_lint(TEST_LINT, item, "testing `ImplItem::trait_ref`")
+        .decorate(|diag| {
+            if let Some(trait_ref) = item.trait_ref() {
+                let trait_item = cx.ast().item(trait_ref.trait_id());
+                diag.note(format!(
+                    "trait item: {:?}",
+                    trait_item.and_then(|item| item.ident().map(|ident| ident.name().to_string()))
+                ));
+            }
+        });
+}
+
+fn describe_impl<'ast>(cx: &'ast MarkerContext<'ast>, id: ItemId) -> String {
+    let Some(ItemKind::Impl(item)) = cx.ast().item(id) else {
+        return "<external impl>".to_string();
+    };
+    let ty = item.ty().span().snippet_or("..");
+    let Some(trait_ref) = item.trait_ref() else {
+        return format!("impl {ty}");
+    };
+    let trait_name = cx
+        .ast()
+        .item(trait_ref.trait_id())
+        .and_then(|item| item.ident().map(|ident| ident.name().to_string()))
+        .unwrap_or_else(|| "<external trait>".to_string());
+    format!("impl {trait_name} for {ty}")
+}
+
+fn check_impls_of_trait<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast ast::TraitItem<'ast>) {
+    cx.emit_lint(TEST_LINT, item, "testing `MarkerContext::impls_of_trait`")
+        .decorate(|diag| {
+            diag.span(item.ident().unwrap().span());
+            for id in cx.impls_of_trait(item.id()) {
+                diag.note(describe_impl(cx, *id));
+            }
+        });
+}
+
+fn check_impls_for_type<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    let TyKind::Adt(adt) = expr.ty() else {
+        unreachable!("the test expressions should be ADTs")
+    };
+    cx.emit_lint(TEST_LINT, stmt, "testing `MarkerContext::impls_for_type`")
+        .decorate(|diag| {
+            let impls = cx.impls_for_type(adt.def_id());
+            let external = impls.iter().filter(|id| cx.ast().item(**id).is_none()).count();
+            diag.note(format!("external impls: {}", external > 0));
+            for id in impls {
+                // Impls of foreign traits should include the impls of other crates
+                let Some(ItemKind::Impl(impl_item)) = cx.ast().item(*id) else {
+                    continue;
+                };
+                diag.note(describe_impl(cx, *id));
+                if let Some(trait_ref) = impl_item.trait_ref().filter(|r| cx.ast().item(r.trait_id()).is_none()) {
+                    let external = cx
+                        .impls_of_trait(trait_ref.trait_id())
+                        .iter()
+                        .any(|id| cx.ast().item(*id).is_none());
+                    diag.note(format!("  trait has external impls: {external}"));
+                }
+            }
+        });
+}
+
+fn check_body_owner<'ast>(cx: &'ast MarkerContext<'ast>, node: impl EmissionNode<'ast>, body: &'ast ast::Body<'ast>) {
+    fn describe_owner(body: &ast::Body<'_>) -> String {
+        match body.owner_item() {
+            Some(ItemKind::Fn(item)) => format!("fn `{}`", item.ident().map_or("<..>", Ident::name)),
+            Some(item) => format!("other item `{}`", item.ident().map_or("<..>", Ident::name)),
+            None => "<no item>".to_string(),
+        }
+    }
+
+    struct ClosureCollector {
+        notes: Vec<String>,
+    }
+    impl marker_utils::visitor::Visitor<()> for ClosureCollector {
+        fn scope(&self) -> marker_utils::visitor::VisitorScope {
+            marker_utils::visitor::VisitorScope::AllBodies
+        }
+
+        fn visit_expr<'v_ast>(&mut self, cx: &'v_ast MarkerContext<'v_ast>, expr: ExprKind<'v_ast>) -> ControlFlow<()> {
+            if let ExprKind::Closure(closure) = expr {
+                let body = cx.ast().body(closure.body_id());
+                self.notes.push(format!(
+                    "closure `{}` -> owner_item: {}",
+                    closure.span().snippet_or("<..>"),
+                    describe_owner(body)
+                ));
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut collector = ClosureCollector { notes: vec![] };
+    let _ = marker_utils::visitor::traverse_body(cx, &mut collector, body);
+    cx.emit_lint(TEST_LINT, node, "testing `Body::owner_item`")
+        .decorate(|diag| {
+            diag.note(format!("owner_item: {}", describe_owner(body)));
+            for note in &collector.notes {
+                diag.note(note);
+            }
+        });
+}
+
+fn check_body_params<'ast>(cx: &'ast MarkerContext<'ast>, node: impl EmissionNode<'ast>, body: &'ast ast::Body<'ast>) {
+    cx.emit_lint(TEST_LINT, node, "testing `Body::params`")
+        .decorate(|diag| {
+            for param in body.params() {
+                diag.note(format!(
+                    "param `{}`: {}",
+                    param.pat().span().snippet_or("<..>"),
+                    describe_ty(param.ty())
+                ));
+            }
+        });
+}
+
+fn check_bin_op_resolution<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    let ExprKind::BinaryOp(op) = expr else {
+        unreachable!("the init expression should be a binary operation")
+    };
+    // The ids of the trait methods are not stable between toolchains, only
+    // the resolution kind is printed.
+    let resolution = match op.resolved_op() {
+        BinOpResolution::Builtin => "Builtin",
+        BinOpResolution::Overloaded(_) => "Overloaded",
+        _ => unreachable!(),
+    };
+    cx.emit_lint(TEST_LINT, stmt, "testing `BinaryOpExpr::resolved_op`")
+        .decorate(|diag| {
+            diag.note(format!("`resolved_op()` --> {resolution}"));
+        });
+}
+
+fn check_ty_traits<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    cx.emit_lint(TEST_AST_MAP, stmt, "testing `MarkerContext::implements_trait`")
+        .decorate(|diag| {
+            let ty = expr.ty();
+            diag.note(format!("`ty_is_copy()` --> {}", cx.ty_is_copy(ty)));
+            diag.note(format!(
+                "`ty_is_sized()` (refs peeled) --> {}",
+                cx.ty_is_sized(ty.peel_refs())
+            ));
+
+            let implements = |path: &str, generics: &[TyKind<'ast>]| {
+                cx.resolve_ty_ids(path)
+                    .iter()
+                    .any(|trait_id| cx.implements_trait(ty, *trait_id, generics))
+            };
+            diag.note(format!(
+                "implements `Debug` --> {}",
+                implements("core::fmt::Debug", &[])
+            ));
+            diag.note(format!(
+                "implements `PartialEq<Self>` --> {}",
+                implements("core::cmp::PartialEq", &[ty])
+            ));
+            diag.note(format!(
+                "implements `PartialEq` (no generics) --> {}",
+                implements("core::cmp::PartialEq", &[])
+            ));
+        });
+}
+
+fn check_ctor_target<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>, expr: ExprKind<'ast>) {
+    let ctors: Vec<_> = match expr {
+        ExprKind::Ctor(ctor) => vec![ctor],
+        ExprKind::Method(method) => method
+            .args()
+            .iter()
+            .filter_map(|arg| match arg {
+                ExprKind::Ctor(ctor) => Some(*ctor),
+                _ => None,
+            })
+            .collect(),
+        _ => unreachable!(),
+    };
+
+    cx.emit_lint(TEST_AST_MAP, stmt, "testing `MarkerContext::ctor_target`")
+        .decorate(|diag| {
+            for ctor in ctors {
+                let target = cx.ctor_target(ctor);
+                let name = match target {
+                    Some(AstPathTarget::Variant(var_id)) => cx.ast().variant(var_id).map(|var| var.ident().to_string()),
+                    Some(AstPathTarget::Item(item_id)) => cx
+                        .ast()
+                        .item(item_id)
+                        .and_then(|item| item.ident().map(|ident| ident.name().to_string())),
+                    _ => None,
+                };
+                diag.note(format!("`ctor_target()` --> {target:?} (name: {name:?})"));
+            }
+        })
+        .done();
+}
+
+fn check_configured_prefix<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>, config: &Config) {
+    if let (Some(prefix), Some(ident)) = (&config.item_prefix, item.ident()) {
+        if ident.name().starts_with(prefix.as_str()) {
+            cx.emit_lint(TEST_LINT, item, "item with the configured prefix")
+                .decorate(|diag| {
+                    diag.span(ident.span());
+                    diag.note(format!("config: {config:?}"));
+                });
+        }
+    }
+}
+
 fn check_static_item<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast StaticItem<'ast>) {
     if let Some(name) = item.ident() {
         let name = name.name();
@@ -276,6 +1527,13 @@ fn check_static_item<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast StaticItem
                 diag.span(item.ty().span());
             });
             eprintln!("{:#?}\n\n", item.ty());
+        } else if name.starts_with("TEST_STATIC_MUT") {
+            if item.is_mut() {
+                cx.emit_lint(TEST_LINT, item, "found a `static mut` item")
+                    .decorate(|diag| {
+                        diag.help("consider using a `static` with interior mutability, like an atomic or `Mutex`");
+                    });
+            }
         } else if name.starts_with("FIND_ITEM") {
             cx.emit_lint(TEST_LINT, item, "hey there is a static item here")
                 .decorate(|diag| {
@@ -283,6 +1541,7 @@ fn check_static_item<'ast>(cx: &'ast MarkerContext<'ast>, item: &'ast StaticItem
                     diag.help("a help");
                     diag.span_note("a spanned note", item.span());
                     diag.span_help("a spanned help", item.span());
+                    diag.span_label("a label", item.ty().span());
                     diag.span_suggestion("try", item.span(), "duck", Applicability::Unspecified);
                 });
         }
@@ -334,3 +1593,16 @@ fn test_ty_id_resolution<'ast>(cx: &'ast MarkerContext<'ast>) {
     eprintln!("=====================================================================");
     eprintln!();
 }
+
+/// Serializes the item to JSON and checks that deserializing it results in the
+/// same representation.
+fn check_serialize<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
+    let node = ItemNode::new(item);
+    let json = serde_json::to_string_pretty(&node).unwrap();
+    let roundtrip: ItemNode = serde_json::from_str(&json).unwrap();
+    assert_eq!(node, roundtrip, "the node should survive the JSON roundtrip");
+
+    cx.emit_lint(TEST_LINT, item, "serializing the item").decorate(|diag| {
+        diag.note(json);
+    });
+}
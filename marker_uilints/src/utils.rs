@@ -1,5 +1,14 @@
-use marker_api::prelude::*;
-use marker_utils::visitor::BoolTraversable;
+use std::ops::ControlFlow;
+
+use marker_api::{
+    ast::{AstPath, AstPathTarget, Crate},
+    prelude::*,
+};
+use marker_utils::{
+    control_flow,
+    eq::ExprEq,
+    visitor::{self, BoolTraversable, Visitor, VisitorScope},
+};
 
 marker_api::declare_lint! {
     /// # What it does
@@ -8,6 +17,41 @@ marker_api::declare_lint! {
     Warn,
 }
 
+marker_api::declare_lint! {
+    /// # What it does
+    /// Tests the [`marker_utils::visitor::traverse_pat`] function.
+    TEST_TRAVERSE_PAT,
+    Warn,
+}
+
+marker_api::declare_lint! {
+    /// # What it does
+    /// Tests the [`marker_utils::visitor::Visitor::visit_path`] function.
+    TEST_VISIT_PATH,
+    Warn,
+}
+
+marker_api::declare_lint! {
+    /// # What it does
+    /// Tests the [`marker_utils::eq::expr_eq`] function.
+    TEST_EXPR_EQ,
+    Warn,
+}
+
+marker_api::declare_lint! {
+    /// # What it does
+    /// Tests the [`marker_utils::visitor::all_items`] function.
+    TEST_ALL_ITEMS,
+    Warn,
+}
+
+marker_api::declare_lint! {
+    /// # What it does
+    /// Tests the [`marker_utils::control_flow::is_unreachable`] function.
+    TEST_IS_UNREACHABLE,
+    Warn,
+}
+
 pub fn check_item<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
     let ItemKind::Fn(fn_item) = item else { return };
     let Some(ident) = fn_item.ident() else { return };
@@ -26,3 +70,177 @@ pub fn check_item<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
         });
     }
 }
+
+pub fn check_traverse_pat<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
+    struct PatCollector {
+        pats: Vec<String>,
+    }
+    impl Visitor<()> for PatCollector {
+        fn scope(&self) -> VisitorScope {
+            VisitorScope::AllBodies
+        }
+
+        fn visit_pat<'v_ast>(&mut self, _cx: &'v_ast MarkerContext<'v_ast>, pat: PatKind<'v_ast>) -> ControlFlow<()> {
+            self.pats.push(pat.span().snippet_or("<..>").to_string());
+            ControlFlow::Continue(())
+        }
+    }
+
+    let ItemKind::Fn(fn_item) = item else { return };
+    let Some(ident) = fn_item.ident() else { return };
+
+    if ident.name().starts_with("test_traverse_pat") {
+        let mut collector = PatCollector { pats: vec![] };
+        let _ = visitor::traverse_item(cx, &mut collector, item);
+
+        cx.emit_lint(TEST_TRAVERSE_PAT, item, "testing `traverse_pat`")
+            .decorate(|diag| {
+                diag.span(ident.span());
+                for pat in &collector.pats {
+                    diag.note(format!("visited: `{pat}`"));
+                }
+            });
+    }
+}
+
+pub fn check_visit_path<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
+    struct PathCollector {
+        paths: Vec<String>,
+    }
+    impl Visitor<()> for PathCollector {
+        fn scope(&self) -> VisitorScope {
+            VisitorScope::AllBodies
+        }
+
+        fn visit_path<'v_ast>(
+            &mut self,
+            cx: &'v_ast MarkerContext<'v_ast>,
+            path: &'v_ast AstPath<'v_ast>,
+            target: AstPathTarget,
+        ) -> ControlFlow<()> {
+            let segments: Vec<_> = path.segments().iter().map(|seg| seg.ident().name()).collect();
+            let target = match target {
+                AstPathTarget::Item(id) => match cx
+                    .ast()
+                    .item(id)
+                    .and_then(|item| Some(item.ident()?.name().to_string()))
+                {
+                    Some(name) => format!("item `{name}`"),
+                    None => "item".to_string(),
+                },
+                AstPathTarget::Variant(id) => match cx.ast().variant(id) {
+                    Some(variant) => format!("variant `{}`", variant.ident()),
+                    None => "variant".to_string(),
+                },
+                AstPathTarget::SelfTy(_) => "self type".to_string(),
+                AstPathTarget::Var(_) => "variable".to_string(),
+                AstPathTarget::Generic(_) => "generic".to_string(),
+                _ => "unresolved".to_string(),
+            };
+            self.paths.push(format!("`{}` -> {target}", segments.join("::")));
+            ControlFlow::Continue(())
+        }
+    }
+
+    let Some(ident) = item.ident() else { return };
+
+    if ident.name().starts_with("test_path_visitor") {
+        let mut collector = PathCollector { paths: vec![] };
+        let _ = visitor::traverse_item(cx, &mut collector, item);
+
+        cx.emit_lint(TEST_VISIT_PATH, item, "testing `visit_path`")
+            .decorate(|diag| {
+                diag.span(ident.span());
+                for path in &collector.paths {
+                    diag.note(format!("visited: {path}"));
+                }
+            });
+    }
+}
+
+/// Checks every integer literal in functions starting with `test_is_unreachable`.
+pub fn check_is_unreachable<'ast>(cx: &'ast MarkerContext<'ast>, item: ItemKind<'ast>) {
+    struct IntLitCollector {
+        results: Vec<String>,
+    }
+    impl Visitor<()> for IntLitCollector {
+        fn scope(&self) -> VisitorScope {
+            VisitorScope::AllBodies
+        }
+
+        fn visit_expr<'v_ast>(&mut self, cx: &'v_ast MarkerContext<'v_ast>, expr: ExprKind<'v_ast>) -> ControlFlow<()> {
+            if let ExprKind::IntLit(lit) = expr {
+                let res = control_flow::is_unreachable(cx, expr);
+                self.results
+                    .push(format!("`{}` -> unreachable: {res}", lit.span().snippet_or("<..>")));
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    let Some(ident) = item.ident() else { return };
+
+    if ident.name().starts_with("test_is_unreachable") {
+        let mut collector = IntLitCollector { results: vec![] };
+        let _ = visitor::traverse_item(cx, &mut collector, item);
+
+        cx.emit_lint(TEST_IS_UNREACHABLE, item, "testing `is_unreachable`")
+            .decorate(|diag| {
+                diag.span(ident.span());
+                for res in &collector.results {
+                    diag.note(res.clone());
+                }
+            });
+    }
+}
+
+/// Compares the two elements of tuples, assigned to variables starting with
+/// `_check_expr_eq`. The bodies are compared, if both elements are closures.
+/// Local variables are compared by name, if the variable name contains
+/// `ignore_locals`.
+pub fn check_expr_eq<'ast>(cx: &'ast MarkerContext<'ast>, stmt: StmtKind<'ast>) {
+    let StmtKind::Let(lets) = stmt else { return };
+    let PatKind::Ident(ident) = lets.pat() else { return };
+    if !ident.name().starts_with("_check_expr_eq") {
+        return;
+    }
+    let Some(ExprKind::Tuple(tuple)) = lets.init() else {
+        unreachable!("the init expression should be a tuple")
+    };
+    let [a, b] = tuple.elements() else {
+        unreachable!("the tuple should have two elements")
+    };
+
+    let (a, b) = match (a, b) {
+        (ExprKind::Closure(a), ExprKind::Closure(b)) => (a.body_expr(), b.body_expr()),
+        _ => (*a, *b),
+    };
+
+    let ignore_locals = ident.name().contains("ignore_locals");
+    let res = ExprEq::new(cx).ignore_local_identity(ignore_locals).eq_expr(a, b);
+    cx.emit_lint(TEST_EXPR_EQ, stmt, format!("testing `expr_eq` -> {res}"))
+        .decorate(|diag| {
+            diag.span(ident.span());
+        });
+}
+
+pub fn check_all_items<'ast>(cx: &'ast MarkerContext<'ast>, krate: &'ast Crate<'ast>) {
+    let items: Vec<_> = visitor::all_items(cx, krate)
+        .filter(|item| {
+            item.ident()
+                .is_some_and(|ident| ident.name().starts_with("test_all_items"))
+        })
+        .collect();
+    let Some(first) = items.first() else { return };
+
+    cx.emit_lint(
+        TEST_ALL_ITEMS,
+        *first,
+        format!("found {} items with the `test_all_items` prefix", items.len()),
+    )
+    .decorate(|diag| {
+        for item in &items {
+            diag.span_note("found this item", item.span());
+        }
+    });
+}
@@ -0,0 +1,33 @@
+use marker_uitest::Emission;
+
+#[test]
+fn test_lint_test_emissions() {
+    let test = marker_uitest::simple_lint_test!("../target").unwrap();
+
+    let emissions = test
+        .run("pub fn find_me_fn() {}\n\npub static FIND_ME_STATIC: u32 = 0;\n")
+        .unwrap();
+    let lints: Vec<_> = emissions.iter().map(|e| (e.lint.as_str(), e.line)).collect();
+    assert_eq!(
+        lints,
+        [
+            ("marker::marker_uilints::item_with_test_name", 1),
+            ("marker::marker_uilints::item_with_test_name", 3),
+        ]
+    );
+    assert!(emissions.iter().all(|e| e.level == "warning"));
+
+    assert!(test.run("pub fn other() {}").unwrap().is_empty());
+    assert!(test.run("pub fn broken() -> u32 {}").is_err());
+}
+
+#[test]
+fn test_lint_test_emission_message() {
+    let test = marker_uitest::simple_lint_test!("../target").unwrap();
+
+    let emissions = test.run("pub struct FindMeStruct;").unwrap();
+    let [Emission { message, .. }] = emissions.as_slice() else {
+        panic!("expected one emission, got: {emissions:?}");
+    };
+    assert_eq!(message, "found a `struct` item with a test name");
+}
@@ -0,0 +1,18 @@
+#![allow(unused)]
+
+fn test_binding_mode(mut value: Option<String>, mut counter: u32) {
+    match value {
+        Some(ref text) => {},
+        Some(ref mut text) => {},
+        whole @ Some(_) => {},
+        None => {},
+    }
+
+    let (a, mut b) = (1, 2);
+    let ref c = 3;
+
+    // Default binding modes are not visible in the binding mode
+    if let Some(text) = &Some(String::new()) {}
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use std::fmt::Debug;
+
+struct Wrapper<'check, CheckT: Copy + 'check, Other>(&'check CheckT, Other);
+
+fn generic_fn<CheckFn, const CHECK_LEN: usize>(_value: [CheckFn; CHECK_LEN])
+where
+    CheckFn: Debug,
+{
+}
+
+trait Trait<CheckTrait: ?Sized> {
+    type Assoc<CheckAssoc>;
+
+    fn method<CheckMethod: Clone>(&self);
+}
+
+impl<CheckImpl: Default> Trait<CheckImpl> for () {
+    type Assoc<CheckAssoc> = Vec<CheckAssoc>;
+
+    fn method<CheckMethod: Clone>(&self) {}
+}
+
+type Alias<CheckAlias> = Option<CheckAlias>;
+
+#[allow(marker::marker_uilints::test_lint)]
+fn allowed<CheckAllowed>() {}
+
+fn unrelated<'a, T>(_a: &'a T) {}
+
+fn main() {}
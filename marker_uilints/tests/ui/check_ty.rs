@@ -0,0 +1,19 @@
+struct CheckTy<T = ()>(T);
+
+struct Fields {
+    direct: CheckTy,
+    nested: Vec<Option<CheckTy>>,
+    tuple: (u8, &'static [CheckTy]),
+}
+
+fn signature(_param: CheckTy<u32>, _fn_ptr: fn(CheckTy) -> u8) -> Box<CheckTy> {
+    let _local: Option<CheckTy<CheckTy>> = None;
+    todo!()
+}
+
+#[allow(marker::marker_uilints::test_lint)]
+fn allowed(_param: CheckTy) {}
+
+fn unrelated(_a: u32, _b: Vec<String>) {}
+
+fn main() {}
@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+struct Point {
+    x: u32,
+    y: u32,
+    name: &'static str,
+}
+
+struct Tuple(bool, [u8; 4]);
+
+struct Wrapper<T> {
+    inner: T,
+    flag: bool,
+}
+
+enum Shape {
+    Empty,
+    Circle(u32),
+    Rect { width: u32, height: (bool, u8) },
+}
+
+union Union {
+    a: u32,
+    b: f32,
+}
+
+fn generic<T>(value: Wrapper<T>) {
+    let _check_adt_fields = value;
+}
+
+fn main() {
+    let _check_adt_fields = Point { x: 1, y: 2, name: "" };
+    let _check_adt_fields = Tuple(true, [0; 4]);
+    let _check_adt_fields = Wrapper { inner: "", flag: false };
+    let _check_adt_fields = Shape::Empty;
+    let _check_adt_fields = Union { a: 1 };
+
+    // Types from external crates
+    let _check_adt_fields = Some(1u8);
+    let _check_adt_fields = std::ops::Range { start: 1u8, end: 2 };
+}
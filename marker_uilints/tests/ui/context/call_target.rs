@@ -0,0 +1,48 @@
+struct Counter(u32);
+
+impl Counter {
+    fn new() -> Self {
+        Self(0)
+    }
+
+    fn with_value(value: u32) -> Self {
+        let _check_call_target = Self(value);
+        Self(value)
+    }
+}
+
+enum Value {
+    Int(u32),
+}
+
+type Alias = Value;
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn apply<F: Fn(u32) -> u32>(f: F) -> u32 {
+    let _check_call_target = f(1);
+    f(2)
+}
+
+fn main() {
+    let _check_call_target = double(1);
+    let _check_call_target = Counter::new();
+    let _check_call_target = std::mem::drop(String::new());
+    let _check_call_target = Alias::Int(1);
+
+    let local = double;
+    let _check_call_target = local(2);
+
+    let ptr: fn(u32) -> u32 = double;
+    let _check_call_target = ptr(3);
+
+    let closure = |x: u32| x + 1;
+    let _check_call_target = closure(4);
+
+    let _check_call_target = <u32 as Default>::default();
+
+    let _ = apply(double);
+    let _ = Counter::with_value(1);
+}
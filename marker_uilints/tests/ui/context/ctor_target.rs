@@ -0,0 +1,18 @@
+enum LocalOption<T> {
+    MySome(T),
+    MyNone,
+}
+
+struct LocalTuple(u32);
+
+fn main() {
+    let _check_ctor_target = Option::Some(12);
+    let _check_ctor_target = [1, 2, 3].map(Option::Some);
+
+    let _check_ctor_target = LocalOption::MySome(12);
+    let _check_ctor_target = [1, 2, 3].map(LocalOption::MySome);
+    let _check_ctor_target: LocalOption<u32> = LocalOption::MyNone;
+
+    let _check_ctor_target = LocalTuple(17);
+    let _check_ctor_target = [1, 2, 3].map(LocalTuple);
+}
@@ -0,0 +1,23 @@
+fn main() {
+    let _check_emit_lint_once_a = 1;
+    let _check_emit_lint_once_b = 1;
+    let _check_emit_lint_once_c = 2;
+    let _check_emit_lint_once_d = 1;
+}
+
+// An allowed emission doesn't prevent later emissions with the same key
+#[allow(marker::marker_uilints::test_lint)]
+fn allowed() {
+    let _check_emit_lint_once_allowed = 3;
+}
+
+fn not_allowed() {
+    let _check_emit_lint_once_e = 3;
+    let _check_emit_lint_once_f = 3;
+}
+
+// A suppressed emission doesn't prevent later emissions with the same key
+fn suppressed() {
+    let _check_emit_lint_once_suppressed = 4; // marker:ignore test_lint
+    let _check_emit_lint_once_g = 4;
+}
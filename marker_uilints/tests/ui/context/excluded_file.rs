@@ -0,0 +1,6 @@
+// This file is excluded via `MARKER_EXCLUDED_FILES` in the uitest setup. The
+// statement below would usually trigger a lint.
+
+fn main() {
+    let _check_macro_backtrace = 1 + 1;
+}
@@ -0,0 +1,18 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CopyType(u32);
+
+struct NonCopyType(String);
+
+fn generic<T: Copy + std::fmt::Debug>(value: T) {
+    // The bounds of `T` are not considered
+    let _check_ty_traits = value;
+}
+
+fn main() {
+    let _check_ty_traits = CopyType(1);
+    let _check_ty_traits = NonCopyType(String::new());
+    let _check_ty_traits = "str slice";
+    let _check_ty_traits = vec![1, 2, 3];
+
+    generic(1);
+}
@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+trait TestImplsOfLocal {}
+
+impl TestImplsOfLocal for u32 {}
+impl TestImplsOfLocal for Local {}
+impl<T> TestImplsOfLocal for Vec<T> {}
+
+trait Blanket {}
+
+impl<T> Blanket for T {}
+
+#[derive(Clone, Debug)]
+struct Local;
+
+impl Local {
+    fn new() -> Self {
+        Local
+    }
+}
+
+impl Local {}
+
+impl Default for Local {
+    fn default() -> Self {
+        Local
+    }
+}
+
+struct Empty;
+
+fn main() {
+    let _check_impls_for_local = Local;
+    let _check_impls_for_empty = Empty;
+    let _check_impls_for_string = String::new();
+}
@@ -0,0 +1,24 @@
+macro_rules! inner {
+    ($a:expr) => {
+        $a + 1
+    };
+}
+
+macro_rules! middle {
+    ($a:expr) => {
+        inner!($a * 2)
+    };
+}
+
+macro_rules! outer {
+    () => {
+        middle!(3)
+    };
+}
+
+fn main() {
+    let _check_macro_backtrace = 1 + 1;
+    let _check_macro_backtrace = inner!(1);
+    let _check_macro_backtrace = outer!();
+    let _check_macro_backtrace: Vec<u32> = vec![1, 2];
+}
@@ -0,0 +1,24 @@
+// Macros used inside of desugared expressions
+macro_rules! inner {
+    ($a:expr) => {
+        $a + 1
+    };
+}
+
+fn try_op() -> Option<u32> {
+    let _check_macro_backtrace = inner!(Some(1)?);
+    None
+}
+
+async fn async_fn() {
+    let _check_macro_backtrace = inner!(2);
+}
+
+fn main() {
+    for _ in 0..2 {
+        let _check_macro_backtrace = inner!(3);
+    }
+    let _ = async {
+        let _check_macro_backtrace = inner!(4);
+    };
+}
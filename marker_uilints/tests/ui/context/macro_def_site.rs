@@ -0,0 +1,18 @@
+macro_rules! inner {
+    ($a:expr) => {
+        $a + 1
+    };
+}
+
+macro_rules! outer {
+    () => {
+        inner!(2)
+    };
+}
+
+fn main() {
+    let _check_macro_def_site = 1 + 1;
+    let _check_macro_def_site = inner!(1);
+    let _check_macro_def_site = outer!();
+    let _check_macro_def_site = line!();
+}
@@ -0,0 +1,25 @@
+struct Counter(u32);
+
+impl Counter {
+    fn get(&self) -> u32 {
+        self.0
+    }
+}
+
+trait Reset {
+    fn reset(&mut self) -> u32;
+}
+
+impl Reset for Counter {
+    fn reset(&mut self) -> u32 {
+        std::mem::take(&mut self.0)
+    }
+}
+
+fn main() {
+    let mut counter = Counter(3);
+    let _check_method_target = counter.get();
+    let _check_method_target = counter.reset();
+    let _check_method_target = Some(1).is_some();
+    let _check_method_target = [1, 2].iter().collect::<Vec<_>>();
+}
@@ -0,0 +1,15 @@
+struct Counter(u32);
+
+impl Counter {
+    fn increment(&mut self) {
+        if true { let _check_parent_method = self.0 + 1; }
+    }
+}
+
+const VALUE: u32 = { let _check_parent_const = 17; _check_parent_const };
+
+fn main() {
+    let _check_parent_binary = (1 + 2) * 3;
+    let _check_parent_closure = |x: u32| x + 1;
+    for i in 0..3 { let _check_parent_for = i; }
+}
@@ -0,0 +1,23 @@
+fn local_fn() {}
+
+struct Local;
+
+impl Local {
+    fn assoc_fn() {}
+}
+
+trait LocalTrait {
+    fn trait_fn() {}
+}
+
+impl LocalTrait for Local {}
+
+fn main() {
+    let _check_fn_ids_transmute: u32 = unsafe { std::mem::transmute(1.0f32) };
+    let _check_fn_ids_drop = std::mem::drop(String::new());
+    let _check_fn_ids_vec_new: Vec<u8> = Vec::new();
+    let _check_fn_ids_clone = Clone::clone(&1);
+    let _check_fn_ids_local = local_fn();
+    let _check_fn_ids_assoc = Local::assoc_fn();
+    let _check_fn_ids_trait = <Local as LocalTrait>::trait_fn();
+}
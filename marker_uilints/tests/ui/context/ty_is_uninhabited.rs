@@ -0,0 +1,27 @@
+#![feature(never_type)]
+#![allow(dead_code)]
+
+use std::convert::Infallible;
+use std::marker::PhantomData;
+
+enum Void {}
+
+struct Wrapper(u32, Void);
+
+union Union {
+    a: u32,
+}
+
+fn generic<T>() {
+    let _check_uninhabited = None::<T>;
+}
+
+fn main() {
+    let _check_uninhabited = 1u32;
+    let _check_uninhabited = None::<Infallible>;
+    let _check_uninhabited = Ok::<u32, !>(1);
+    let _check_uninhabited = None::<Result<Void, Infallible>>;
+    let _check_uninhabited = None::<Wrapper>;
+    let _check_uninhabited = None::<(u32, !)>;
+    let _check_uninhabited = PhantomData::<Union>;
+}
@@ -0,0 +1,23 @@
+#[allow(dead_code)]
+enum Large {
+    Small(u8),
+    Big([u64; 128]),
+}
+
+#[repr(align(16))]
+struct Aligned(u8);
+
+fn generic<T: Default>() {
+    let _check_ty_layout = T::default();
+    let _check_ty_layout: Vec<T> = Vec::new();
+}
+
+fn main() {
+    let _check_ty_layout = 1u32;
+    let _check_ty_layout = (1u8, 2u64);
+    let _check_ty_layout = Large::Small(1);
+    let _check_ty_layout = Aligned(0);
+    let _check_ty_layout = Box::new([0u8; 1024]);
+    let _check_ty_layout = "unsized behind a reference";
+    let _check_ty_layout: &[u16] = &[1, 2];
+}
@@ -0,0 +1,9 @@
+use std::fmt::Debug;
+
+fn main() {
+    let text = String::from("duck");
+    let _check_adjusted_ty_deref: &str = &text;
+    let _check_adjusted_ty_unsize: &[u8] = &[1, 2, 3];
+    let _check_adjusted_ty_dyn: &dyn Debug = &text;
+    let _check_adjusted_ty_none = 17;
+}
@@ -0,0 +1,28 @@
+use std::ops::Add;
+
+#[derive(Clone, Copy)]
+struct Meters(u32);
+
+impl Add for Meters {
+    type Output = Meters;
+
+    fn add(self, rhs: Meters) -> Meters {
+        Meters(self.0 + rhs.0)
+    }
+}
+
+fn generic_add<T: Add<Output = T>>(a: T, b: T) -> T {
+    let _check_bin_op_resolution_generic = a + b;
+    _check_bin_op_resolution_generic
+}
+
+fn main() {
+    let _check_bin_op_resolution_int = 1 + 2;
+    let _check_bin_op_resolution_bool = true && false;
+    let _check_bin_op_resolution_cmp = 1 < 2;
+    let _check_bin_op_resolution_string = String::from("duck") + "s";
+    let _check_bin_op_resolution_local = Meters(1) + Meters(2);
+    let _check_bin_op_resolution_str_eq = "duck" == "goose";
+
+    let _ = generic_add(1, 2);
+}
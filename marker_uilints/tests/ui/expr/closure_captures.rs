@@ -0,0 +1,20 @@
+struct Large {
+    data: [u64; 16],
+    name: String,
+}
+
+fn main() {
+    let large = Large {
+        data: [0; 16],
+        name: String::new(),
+    };
+    let mut count = 0;
+    let number = 7;
+
+    let _check_captures_none = || 1 + 1;
+    let _check_captures_ref = || large.data.len() + number;
+    let mut _check_captures_mut_ref = || count += 1;
+    let _check_captures_copy = move || number;
+    // Only the `name` field is moved, the capture is still reported for `large`
+    let _check_captures_move = move || large.name;
+}
@@ -0,0 +1,6 @@
+fn main() {
+    let _check_closure_ty = |x| u32::max(x, 1);
+
+    let _check_closure_ty = |a: &str, (b, c): (u8, bool)| {};
+    let _check_closure_ty = || [1u8; 4];
+}
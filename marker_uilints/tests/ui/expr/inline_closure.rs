@@ -0,0 +1,10 @@
+fn main() {
+    let _check_inline_closure_lit = (|| 5)();
+    let _check_inline_closure_block = (|| {
+        let x = 2;
+        x + 3
+    })();
+
+    // No suggestion, as the closure has parameters
+    let _check_inline_closure_param = (|x: u32| x)(5);
+}
@@ -0,0 +1,33 @@
+#![allow(overflowing_literals)]
+
+fn main() {
+    // Boundary values with suffixes
+    let _check_int_lit_u8_max = 255_u8;
+    let _check_int_lit_u8_over = 256_u8;
+    let _check_int_lit_i8_max = 127_i8;
+    let _check_int_lit_i8_over = 128_i8;
+    let _check_int_lit_u16_max = 65_535_u16;
+    let _check_int_lit_u16_over = 65_536_u16;
+    let _check_int_lit_i16_max = 32_767_i16;
+    let _check_int_lit_i16_over = 32_768_i16;
+    let _check_int_lit_u32_max = 4_294_967_295_u32;
+    let _check_int_lit_u32_over = 4_294_967_296_u32;
+    let _check_int_lit_i32_max = 2_147_483_647_i32;
+    let _check_int_lit_i32_over = 2_147_483_648_i32;
+    let _check_int_lit_u64_max = 18_446_744_073_709_551_615_u64;
+    let _check_int_lit_u64_over = 18_446_744_073_709_551_616_u64;
+    let _check_int_lit_i64_max = 9_223_372_036_854_775_807_i64;
+    let _check_int_lit_i64_over = 9_223_372_036_854_775_808_i64;
+    let _check_int_lit_u128_max = 340_282_366_920_938_463_463_374_607_431_768_211_455_u128;
+    let _check_int_lit_i128_max = 170_141_183_460_469_231_731_687_303_715_884_105_727_i128;
+    let _check_int_lit_i128_over = 170_141_183_460_469_231_731_687_303_715_884_105_728_i128;
+
+    // Pointer sized types depend on the target
+    let _check_int_lit_usize = 1_usize;
+    let _check_int_lit_usize_64_bit = 4_294_967_296_usize;
+    let _check_int_lit_isize = 2_147_483_647_isize;
+
+    // The type is inferred from the expected type
+    let _check_int_lit_inferred: u8 = 300;
+    let _check_int_lit_default = 0;
+}
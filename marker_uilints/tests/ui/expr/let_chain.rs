@@ -0,0 +1,13 @@
+#![feature(let_chains)]
+
+fn test_let_chain(a: Option<u32>, b: Option<u32>, flag: bool) {
+    if let Some(x) = a && let Some(y) = b {}
+
+    if let Some(x) = a && x > 2 && let Some(y) = b && flag {}
+
+    if let Some(x) = a {}
+
+    if flag && a.is_some() {}
+}
+
+fn main() {}
@@ -0,0 +1,103 @@
+#![allow(unreachable_patterns)]
+
+use std::num::IntErrorKind;
+
+#[derive(Clone, Copy)]
+enum Animal {
+    Duck,
+    Goose(u8),
+    Penguin { height: u32 },
+}
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+enum Wrapper {
+    Value(u32),
+}
+
+fn main() {
+    let animal = Animal::Duck;
+
+    let _check_match_arms_all_variants = match animal {
+        Animal::Duck => 1,
+        Animal::Goose(_) => 2,
+        Animal::Penguin { .. } => 3,
+        _ => 4,
+    };
+
+    let _check_match_arms_missing_variant = match animal {
+        Animal::Duck | Animal::Goose(_) => 1,
+        _ => 2,
+    };
+
+    let _check_match_arms_refutable_fields = match animal {
+        Animal::Duck => 1,
+        Animal::Goose(1) => 2,
+        Animal::Penguin { height: _ } => 3,
+        _ => 4,
+    };
+
+    let _check_match_arms_nested_variants = match Some(true) {
+        Some(true) | Some(false) | None => 1,
+        _ => 2,
+    };
+
+    let _check_match_arms_nested_variants_missing = match Some(true) {
+        Some(true) | Some(false) => 1,
+        _ => 2,
+    };
+
+    let _check_match_arms_guard = match &animal {
+        Animal::Duck => 1,
+        Animal::Goose(x) if *x > 2 => 2,
+        Animal::Goose(_) => 2,
+        other @ Animal::Penguin { .. } => 3,
+        _ => 4,
+    };
+
+    let _check_match_arms_no_wildcard = match animal {
+        Animal::Duck => 1,
+        other => 2,
+    };
+
+    let _check_match_arms_bool = match (true, 1) {
+        (true, _) => 1,
+        (false, x) => x,
+    };
+
+    let _check_match_arms_bool_wildcard = match true {
+        true => 1,
+        false => 2,
+        _ => 3,
+    };
+
+    let _check_match_arms_int = match 7 {
+        0..=9 => 1,
+        _ => 2,
+    };
+
+    let _check_match_arms_struct = match (Point { x: 1, y: 2 }) {
+        Point { x: 0, .. } => 1,
+        Point { x, y: _ } => x,
+    };
+
+    let _check_match_arms_single_variant = match Wrapper::Value(3) {
+        Wrapper::Value(value) => value,
+    };
+
+    let _check_match_arms_slice = match &[1, 2, 3][..] {
+        [] => 1,
+        [first, ..] => *first,
+        [..] => 3,
+    };
+
+    let _check_match_arms_non_exhaustive = match IntErrorKind::Empty {
+        IntErrorKind::Empty => 1,
+        _ => 2,
+    };
+
+    let _ = Point { x: 1, y: 2 }.y;
+}
@@ -0,0 +1,16 @@
+fn main() {
+    let x = Some(1);
+
+    let _check_match_source_normal = match x {
+        Some(value) => value,
+        None => 0,
+    };
+
+    // The `match` of the `for` loop desugar is represented as a `ForExpr`
+    let _check_match_source_for = for value in [1, 2, 3] {
+        let _ = value;
+    };
+
+    // Arguments used by several placeholders are evaluated in a `match`
+    let _check_match_source_format_args = format!("{0} {0:?}", x.unwrap());
+}
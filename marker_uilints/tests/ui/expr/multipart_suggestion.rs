@@ -0,0 +1,7 @@
+fn main() {
+    let first = 1;
+    let second = 2_i32;
+
+    let _check_swap_operands_ident = first - second;
+    let _check_swap_operands_nested = (first + 3) * second.pow(2);
+}
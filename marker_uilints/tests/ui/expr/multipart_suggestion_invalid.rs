@@ -0,0 +1,9 @@
+fn main() {
+    let first = 1;
+    let second = 2;
+
+    // Empty and overlapping edits would cause an ICE in rustc. The diagnostic
+    // should be emitted, without the invalid suggestions.
+    let _check_invalid_multipart_empty = first + second;
+    let _check_invalid_multipart_overlap = first * second;
+}
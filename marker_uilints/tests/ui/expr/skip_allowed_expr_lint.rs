@@ -0,0 +1,13 @@
+fn main() {
+    // `check_expr` isn't called, since all lints declared with `expr_lint` are
+    // allowed here. The lint pass would panic otherwise.
+    #[allow(marker::marker_uilints::test_lint)]
+    let _skipped = "uilints_check_expr_should_be_skipped";
+
+    #[allow(marker::marker_uilints::test_lint)]
+    #[warn(marker::marker_uilints::print_every_expr)]
+    let _checked = "uilints_check_expr_should_be_skipped";
+
+    // `check_expr` is still called, if only some of the declared lints are allowed
+    let _checked = "uilints_check_expr_emits_test_lint";
+}
@@ -0,0 +1,9 @@
+fn main() {
+    let _check_str_lit_standard = "duck";
+    let _check_str_lit_escaped = "tab:\t, quote:\", unicode:\u{1F980}, \
+        continued";
+    let _check_str_lit_raw = r#"no \t escapes "here""#;
+    let _check_str_lit_byte = b"bytes\n\0";
+    let _check_str_lit_byte_invalid = b"not utf-8: \xFF";
+    let _check_str_lit_raw_byte = br"raw \x00 bytes";
+}
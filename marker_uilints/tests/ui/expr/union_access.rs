@@ -0,0 +1,21 @@
+#![allow(unused)]
+
+union IntOrFloat {
+    int: u32,
+    float: f32,
+}
+
+struct Wrapper {
+    value: IntOrFloat,
+    other: u32,
+}
+
+fn test_union_access(value: IntOrFloat, reference: &IntOrFloat, wrapper: &mut Wrapper) {
+    let int = unsafe { value.int };
+    let float = unsafe { reference.float };
+    wrapper.value.int = 3;
+    let other = wrapper.other;
+    let tuple = (1, 2).0;
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![feature(coroutines)]
+
+fn main() {
+    let _check_closure_body_yield = || {
+        yield 1;
+    };
+    let _check_closure_body_yield_unit = || {
+        yield;
+    };
+    let _check_closure_body_plain = || 1;
+}
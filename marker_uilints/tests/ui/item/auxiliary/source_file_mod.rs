@@ -0,0 +1 @@
+pub fn test_source_file_in_module() {}
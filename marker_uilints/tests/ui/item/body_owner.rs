@@ -0,0 +1,10 @@
+fn test_body_owner_fn() -> u32 {
+    let closure = |x: u32| x + 1;
+    closure(1)
+}
+
+const TEST_BODY_OWNER_CONST: u32 = 1 + 2;
+
+static TEST_BODY_OWNER_STATIC: [u8; 2] = [0; 2];
+
+fn main() {}
@@ -0,0 +1,20 @@
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn test_body_params_fn(a: u32, (b, c): (u8, &str), Point { x, .. }: Point, _: &[u16]) {}
+
+fn test_body_params_none() {}
+
+trait Trait {
+    fn test_body_params_trait(&self, value: u64) {}
+}
+
+fn takes(_: u8, _: &str, _: bool) {}
+
+fn main() {
+    let _check_body_params_typed = |a: u32, b: &str| {};
+    let _check_body_params_inferred = |(a, b), c| takes(a, b, c);
+    let _check_body_params_none = || 1;
+}
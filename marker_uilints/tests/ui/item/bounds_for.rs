@@ -0,0 +1,20 @@
+use std::fmt::Debug;
+
+fn test_bounds_for_inline<T: Copy + Clone, U: ?Sized>(_t: T, _u: &U) {}
+
+fn test_bounds_for_where<T, U>(_t: T, _u: U)
+where
+    T: Clone + Debug,
+    U: 'static,
+    Vec<U>: Debug,
+{
+}
+
+fn test_bounds_for_mixed<'a, T: Copy + 'a>(_t: &'a T)
+where
+    T: Clone,
+    for<'b> T: PartialEq<&'b T>,
+{
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+const TEST_CONST_CONTEXT_CONST: u32 = 1 + 2;
+
+static TEST_CONST_CONTEXT_STATIC: u32 = 3;
+
+const fn test_const_context_const_fn() -> u32 {
+    4
+}
+
+fn test_const_context_fn() -> u32 {
+    let closure = || 5;
+    closure()
+}
+
+struct S;
+
+impl S {
+    const fn test_const_context_assoc_fn(&self) -> u32 {
+        6
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+const LEN: usize = 4;
+
+type TestConstExprArray = [u8; LEN * 4];
+type TestConstExprShift = [u16; 1 << 10];
+
+#[repr(i8)]
+enum TestConstExprEnum {
+    Neg = -3 * 4,
+    Const = LEN as i8,
+    Implicit,
+}
+
+// Depends on the generic parameter
+type TestConstExprGeneric<const N: usize> = [u8; N];
+
+fn main() {}
@@ -0,0 +1,18 @@
+const TEST_VALUE_MAX: u32 = 1 << 20;
+const TEST_VALUE_NEG: i8 = -3 * 4;
+const TEST_VALUE_BOOL: bool = TEST_VALUE_MAX > 1000;
+const TEST_VALUE_CHAR: char = 'm';
+const TEST_VALUE_FLOAT: f32 = 0.5;
+const TEST_VALUE_STR: &str = "not a scalar";
+
+static TEST_VALUE_STATIC: u64 = TEST_VALUE_MAX as u64 * 2;
+static mut TEST_VALUE_STATIC_MUT: i16 = -1;
+
+struct Wrapper<T>(T);
+
+impl<T> Wrapper<T> {
+    // Depends on the generic parameter
+    const TEST_VALUE_GENERIC: usize = std::mem::size_of::<T>();
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+fn test_crate_post_first() {}
+
+mod nested {
+    pub fn test_crate_post_second() {}
+}
+
+struct Other;
+
+impl Other {
+    // Associated items are checked like other items
+    fn test_crate_post_assoc() {}
+}
+
+fn test_crate_post_third() {}
+
+fn main() {}
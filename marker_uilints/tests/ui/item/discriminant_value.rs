@@ -0,0 +1,20 @@
+enum Implicit {
+    TestDiscrA,
+    TestDiscrB,
+    TestDiscrC(u32),
+}
+
+#[repr(i8)]
+enum Explicit {
+    TestDiscrMinusTwo = -2,
+    TestDiscrMinusOne,
+    TestDiscrFive = 2 + 3,
+    TestDiscrSix,
+}
+
+#[repr(u64)]
+enum Large {
+    TestDiscrMax = u64::MAX,
+}
+
+fn main() {}
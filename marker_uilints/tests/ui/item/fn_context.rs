@@ -0,0 +1,27 @@
+fn test_fn_context_free() {}
+
+struct Duck;
+
+impl Duck {
+    fn test_fn_context_inherent(&self) {}
+}
+
+trait Bird {
+    fn test_fn_context_required(&self) -> Self;
+
+    fn test_fn_context_provided(&self) {}
+}
+
+impl Bird for Duck {
+    fn test_fn_context_required(&self) -> Self {
+        Duck
+    }
+}
+
+extern "C" {
+    fn test_fn_context_extern();
+}
+
+fn main() {
+    fn test_fn_context_nested() {}
+}
@@ -0,0 +1,25 @@
+#![allow(unused)]
+
+struct Counter(u32);
+
+impl Counter {
+    fn test_return_ty_self() -> Self {
+        Counter(0)
+    }
+
+    fn test_return_ty_named() -> Counter {
+        Counter(0)
+    }
+
+    fn test_return_ty_impl_trait(&self) -> impl Iterator<Item = u32> {
+        0..self.0
+    }
+}
+
+trait Builder {
+    fn test_return_ty_trait_self(self) -> Self;
+}
+
+fn test_return_ty_none() {}
+
+fn main() {}
@@ -0,0 +1,17 @@
+trait LocalTrait {}
+
+struct TestImplTraitLocal;
+impl LocalTrait for TestImplTraitLocal {}
+
+// Items from other crates are not available, this should return `None`
+struct TestImplTraitStd;
+impl Default for TestImplTraitStd {
+    fn default() -> Self {
+        Self
+    }
+}
+
+struct TestImplTraitInherent;
+impl TestImplTraitInherent {}
+
+fn main() {}
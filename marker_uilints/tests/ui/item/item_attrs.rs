@@ -0,0 +1,24 @@
+#![allow(unused, non_snake_case)]
+
+/// A documented struct
+#[derive(Debug, Clone)]
+#[rustfmt::skip]
+struct TestAttrsStruct {
+    #[doc = "A documented field"]
+    test_attrs_field: u32,
+}
+
+#[repr(u8)]
+enum TestAttrsEnum {
+    #[allow(dead_code)]
+    TestAttrsVariant = 1,
+}
+
+#[inline]
+#[must_use = "the result is important"]
+#[deprecated(since = "1.0", note = "use something else")]
+fn TestAttrsFn() -> u32 {
+    0
+}
+
+fn main() {}
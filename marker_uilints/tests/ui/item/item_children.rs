@@ -0,0 +1,19 @@
+#![allow(unused)]
+
+mod test_item_children {
+    pub fn first() {}
+
+    pub struct Second;
+
+    mod nested {
+        fn third() {}
+
+        mod empty {}
+    }
+
+    impl Second {
+        fn not_a_child() {}
+    }
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+fn uilints_configured_fn() {}
+
+fn unconfigured_fn() {}
+
+mod uilints_configured_mod {}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![allow(unused)]
+
+type Alias = Vec<u32>;
+type GenericAlias<T> = Option<T>;
+
+struct Fields<T> {
+    resolve_ty_alias: Alias,
+    resolve_ty_generic_alias: GenericAlias<T>,
+    resolve_ty_array: [u8; 4],
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+mod test_serialize {
+    struct Point {
+        x: i32,
+    }
+
+    impl Point {
+        fn new(x: i32) -> Self {
+            Self { x }
+        }
+    }
+
+    const LIMIT: i32 = 1 + 2;
+
+    fn clamp(point: &Point) -> i32 {
+        if point.x > LIMIT { LIMIT } else { point.x }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[path = "auxiliary/source_file_mod.rs"]
+mod test_source_file_external;
+
+mod test_source_file_inline {
+    pub fn test_source_file_inline_fn() {}
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+static TEST_STATIC_MUT_IMMUTABLE: u32 = 1;
+
+static mut TEST_STATIC_MUT_COUNTER: u32 = 0;
+
+pub static mut TEST_STATIC_MUT_NAMES: Vec<String> = Vec::new();
+
+fn main() {}
@@ -0,0 +1,5 @@
+#![allow(unused)]
+
+fn test_try_span_fn() {}
+
+fn main() {}
@@ -11,7 +11,13 @@ mod module {
         pub(super) fn test_vis_pub_super() {}
 
         pub(in crate::module) fn test_vis_pub_in_path() {}
+
+        pub(self) fn test_vis_pub_self() {}
     }
 }
 
+mod foo {
+    pub(in crate::foo) fn test_vis_pub_in_foo() {}
+}
+
 fn main() {}
@@ -5,3 +5,36 @@ const FIND_ME_ALLOW: i32 = 0;
 
 #[deny(marker::marker_uilints::item_with_test_name)]
 const FIND_ME_DENY: i32 = 0;
+
+#[allow(marker::marker_uilints::item_with_test_name)]
+mod allow_in_mod {
+    const FIND_ME_IN_ALLOW_MOD: i32 = 0;
+
+    #[warn(marker::marker_uilints::item_with_test_name)]
+    const FIND_ME_WARN_IN_ALLOW_MOD: i32 = 0;
+}
+
+#[deny(marker::marker_uilints::item_with_test_name)]
+mod deny_in_mod {
+    const FIND_ME_IN_DENY_MOD: i32 = 0;
+
+    #[allow(marker::marker_uilints::item_with_test_name)]
+    const FIND_ME_ALLOW_IN_DENY_MOD: i32 = 0;
+}
+
+#[cfg(test)]
+#[allow(marker::marker_uilints::item_with_test_name)]
+mod tests {
+    const FIND_ME_IN_TESTS: i32 = 0;
+}
+
+#[cfg_attr(not(test), deny(marker::marker_uilints::item_with_test_name))]
+mod deny_outside_of_tests {
+    const FIND_ME_OUTSIDE_OF_TESTS: i32 = 0;
+}
+
+struct FieldLevels {
+    #[allow(marker::marker_uilints::item_with_test_name)]
+    find_me_allow: i32,
+    find_me_default: i32,
+}
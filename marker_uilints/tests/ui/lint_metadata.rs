@@ -0,0 +1,3 @@
+fn test_lint_metadata() {}
+
+fn main() {}
@@ -0,0 +1,22 @@
+#![allow(unused)]
+
+fn test_pat_ty_bindings(value: Option<String>, (x, y): (u32, bool)) {
+    if let Some(text) = &value {}
+
+    let ref r = 3_u8;
+    let (first, ..) = (1_i32, "text", true);
+    let [head, rest @ ..] = [1_u16, 2, 3];
+    let [a, .., z] = &[true, false, true][..] else {
+        return;
+    };
+}
+
+trait TestPatTy {
+    fn test_pat_ty_no_body(value: u32, _: bool);
+
+    fn test_pat_ty_with_body(&self, value: &str) {}
+}
+
+fn main() {
+    let test_pat_ty_closure = |x: u32, (a, b): (bool, &str)| {};
+}
@@ -0,0 +1,11 @@
+#![feature(extern_types)]
+
+extern "C" {
+    type Opaque;
+}
+
+fn use_opaque(ptr: *mut Opaque) {
+    let _ty_foreign: *mut Opaque = ptr;
+}
+
+fn main() {}
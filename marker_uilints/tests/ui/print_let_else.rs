@@ -0,0 +1,15 @@
+fn parse(input: &str) -> Option<u32> {
+    let Some(_print_let_else) = input.parse::<u32>().ok() else {
+        return None;
+    };
+
+    let Ok(_print_let_else_panic) = input.parse::<u32>() else {
+        panic!("invalid input")
+    };
+
+    Some(1)
+}
+
+fn main() {
+    let _ = parse("1");
+}
@@ -0,0 +1,11 @@
+#![allow(unused)]
+
+fn test_ref_lifetime_fn<'a>(elided: &u8, anonymous: &'_ u8, named: &'a u8, stat: &'static mut u8, not_a_ref: u8) {}
+
+struct Foo;
+
+impl Foo {
+    fn test_ref_lifetime_method(&self, other: &'_ Self) {}
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+fn main() {
+    let mut _check_subspan = "héllo";
+    _check_subspan = "";
+}
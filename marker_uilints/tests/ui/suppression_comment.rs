@@ -0,0 +1,19 @@
+fn find_me_reported() {}
+
+fn find_me_trailing() {} // marker:ignore item_with_test_name
+
+// marker:ignore item_with_test_name
+fn find_me_line_above() {}
+
+// marker:ignore marker::marker_uilints::item_with_test_name -- with a reason
+fn find_me_full_name() {}
+
+fn find_me_other_lint() {} // marker:ignore test_lint
+
+fn find_me_multiline(
+    _a: u32,
+    _b: u32,
+) {
+} // marker:ignore item_with_test_name
+
+fn main() {}
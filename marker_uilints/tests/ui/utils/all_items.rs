@@ -0,0 +1,35 @@
+#![allow(unused, non_camel_case_types, non_upper_case_globals)]
+
+fn test_all_items_top_level() {
+    fn test_all_items_in_body() {}
+
+    let _ = || {
+        struct test_all_items_in_closure;
+    };
+}
+
+mod test_all_items_mod {
+    mod nested {
+        pub fn test_all_items_in_nested_mod() {}
+    }
+}
+
+trait Foo {
+    fn test_all_items_trait_fn();
+}
+
+struct Bar;
+
+impl Bar {
+    const test_all_items_assoc_const: u32 = 1;
+
+    fn test_all_items_method(&self) {
+        const test_all_items_in_method: u32 = 2;
+    }
+}
+
+extern "C" {
+    fn test_all_items_extern_fn();
+}
+
+fn main() {}
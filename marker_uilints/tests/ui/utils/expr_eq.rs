@@ -0,0 +1,83 @@
+const LIMIT: u32 = 10;
+
+enum Animal {
+    Duck,
+    Goose,
+}
+
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn defaults<T: Default, U: Default>() {
+    let _check_expr_eq_generic_ty_relative = (T::default(), T::default());
+    let _check_expr_eq_generic_ty_relative_other = (T::default(), U::default());
+}
+
+fn main() {
+    let a = 1_u32;
+    let b = 2_u32;
+    let point = Point { x: 1, y: 2 };
+    let text = String::from("duck");
+    let list = [1, 2, 3];
+
+    // Literals
+    let _check_expr_eq_int = (1, 1);
+    let _check_expr_eq_int_suffix = (1_u8, 1_i8);
+    let _check_expr_eq_int_value = (1, 2);
+    let _check_expr_eq_float = (1.5, 1.5);
+    let _check_expr_eq_str = ("duck", "duck");
+    let _check_expr_eq_str_value = ("duck", "goose");
+    let _check_expr_eq_byte_str = (b"duck", b"duck");
+    let _check_expr_eq_char = ('a', 'a');
+    let _check_expr_eq_bool = (true, false);
+
+    // Paths
+    let _check_expr_eq_local = (a, a);
+    let _check_expr_eq_local_other = (a, b);
+    let _check_expr_eq_const = (LIMIT, LIMIT);
+    let _check_expr_eq_variant = (Animal::Duck, Animal::Duck);
+    let _check_expr_eq_variant_other = (Animal::Duck, Animal::Goose);
+    let _check_expr_eq_ctor = (Point { x: 1, y: a }, Point { x: 1, y: a });
+    let _check_expr_eq_ctor_field = (Point { x: 1, y: a }, Point { x: 1, y: b });
+    let _check_expr_eq_fn_path = (double, self::double);
+    let _check_expr_eq_ty_relative = (u32::default(), u32::default());
+    let _check_expr_eq_ty_relative_other = (u32::default(), u64::default());
+    let _check_expr_eq_qualified = (<u32 as Default>::default(), <u32 as Default>::default());
+    let _check_expr_eq_qualified_other = (<u32 as Default>::default(), <u64 as Default>::default());
+
+    // Operators and places
+    let _check_expr_eq_bin_op = (a + b * 2, a + b * 2);
+    let _check_expr_eq_bin_op_kind = (a + b, a - b);
+    let _check_expr_eq_bin_op_order = (a + b, b + a);
+    let _check_expr_eq_unary = (!a, !a);
+    let _check_expr_eq_ref = (&a, &a);
+    let _check_expr_eq_field = (point.x, point.x);
+    let _check_expr_eq_field_other = (point.x, point.y);
+    let _check_expr_eq_index = (list[0], list[0]);
+    let _check_expr_eq_cast = (a as u64, a as u64);
+    let _check_expr_eq_cast_ty = (a as u64, a as i64);
+
+    // Calls
+    let _check_expr_eq_call = (double(a), double(a));
+    let _check_expr_eq_call_arg = (double(a), double(b));
+    let _check_expr_eq_method = (text.len(), text.len());
+    let _check_expr_eq_method_other = (text.len(), text.capacity());
+    let _check_expr_eq_method_chain = (text.trim().len(), text.trim().len());
+    let _check_expr_eq_generic_method = (list.iter().sum::<u32>(), list.iter().sum::<u32>());
+
+    // Control flow
+    let _check_expr_eq_if = (if a == b { a } else { b }, if a == b { a } else { b });
+    let _check_expr_eq_block = ({ a }, { a });
+
+    // Local variable identity, the bodies of closures are compared
+    let _check_expr_eq_closure_locals = (|x: u32| x + a, |x: u32| x + a);
+    let _check_expr_eq_ignore_locals = (|x: u32| x + a, |x: u32| x + a);
+    let _check_expr_eq_ignore_locals_name = (|x: u32| x + a, |y: u32| y + a);
+    let _check_expr_eq_ignore_locals_captured = (|x: u32| x + a, |x: u32| x + b);
+}
@@ -0,0 +1,69 @@
+#![allow(unused, unreachable_code, clippy::diverging_sub_expression)]
+
+fn test_is_unreachable_after_return() -> u32 {
+    let a = 1;
+    return 2;
+    let b = 3;
+    4
+}
+
+fn test_is_unreachable_after_panic() {
+    let a = 1;
+    panic!("{}", 2);
+    let b = 3;
+}
+
+fn test_is_unreachable_after_loop() {
+    let a = 1;
+    loop {}
+    let b = 2;
+}
+
+fn test_is_unreachable_in_loop(cond: bool) {
+    for _ in 0..1 {
+        if cond {
+            let a = 1;
+            break;
+            let b = 2;
+        }
+        let c = 3;
+        continue;
+        let d = 4;
+    }
+    let e = 5;
+}
+
+fn test_is_unreachable_nested_block() {
+    let a = {
+        return;
+        1
+    };
+    let b = 2;
+}
+
+fn test_is_unreachable_labeled_block() {
+    let a = 'label: {
+        break 'label 1;
+    };
+    let b = 2;
+    let c = async {
+        return 3;
+    };
+    let d = 4;
+}
+
+fn test_is_unreachable_let_else(x: Option<u32>) {
+    let Some(a) = x else {
+        return;
+    };
+    let b = 1;
+    let c: u32 = return;
+    let d = 2;
+}
+
+fn test_is_unreachable_closure() {
+    return;
+    let f = || 1;
+}
+
+fn main() {}
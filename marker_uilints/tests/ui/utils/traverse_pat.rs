@@ -0,0 +1,28 @@
+#![allow(unused)]
+
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn test_traverse_pat_params((a, b): (u32, u32), Point { x, y: ref mut y_ref }: Point) {}
+
+fn test_traverse_pat_bodies(slice: &[u32], opt: Option<u32>) {
+    let (first, _) = (1, 2);
+
+    match opt {
+        Some(0 | 1) => {},
+        Some(val @ 2..=5) => {},
+        _ => {},
+    }
+
+    if let [head, .., tail] = slice {}
+
+    for (index, item) in slice.iter().enumerate() {}
+
+    let closure = |&val: &u32| val;
+
+    let mut c = 0;
+    let mut d = [0, 0];
+    (c, d[1]) = (1, 2);
+}
@@ -0,0 +1,36 @@
+#![allow(unused)]
+
+mod old {
+    pub fn deprecated_fn() {}
+
+    pub struct Point(pub i32, pub i32);
+
+    pub enum Shape {
+        Circle,
+        Square { len: u32 },
+    }
+}
+
+mod test_path_visitor_uses {
+    use super::old::deprecated_fn;
+    use super::old::Shape::*;
+    use std::collections;
+}
+
+fn test_path_visitor_exprs(shape: old::Shape) {
+    old::deprecated_fn();
+    let fn_ptr: fn() = old::deprecated_fn;
+    let point = old::Point(1, 2);
+    let value = point.0;
+
+    match shape {
+        old::Shape::Circle => {},
+        old::Shape::Square { len } => {},
+    }
+
+    let _ = <Vec<u8> as Default>::default();
+
+    // The generic arguments of expression paths are visited as well
+    let _ = Vec::<old::Point>::new();
+    let _ = std::iter::empty::<old::Shape>();
+}
@@ -4,6 +4,15 @@ use std::env;
 fn main() -> color_eyre::Result<()> {
     let mut config: Config = marker_uitest::simple_ui_test_config!("tests/ui", "../target")?;
 
+    // The lint crate configuration, usually provided by `cargo-marker`
+    env::set_var(
+        "MARKER_LINT_CRATES_CONFIG",
+        r#"{"marker_uilints":{"item_prefix":"uilints_configured_"}}"#,
+    );
+
+    // Files excluded from linting, usually provided by `cargo-marker`
+    env::set_var("MARKER_EXCLUDED_FILES", r#"["**/excluded_*.rs"]"#);
+
     config.filter(r"\\/", "/");
     config.filter(r"\\\\", "/");
 
@@ -10,6 +10,9 @@ use std::{
 use semver::Version;
 pub use ui_test;
 
+mod lint_test;
+pub use lint_test::*;
+
 #[derive(Debug)]
 struct TestSetup {
     rustc_path: String,
@@ -109,6 +112,38 @@ pub fn create_ui_test_config(
     Ok(config)
 }
 
+/// Additional builder methods for [`ui_test::Config`], to configure Marker's UI tests.
+pub trait ConfigExt {
+    /// This method registers an additional normalization for the stderr output.
+    /// All matches of the regex `pattern` are replaced with `replacement`,
+    /// before the output is compared with the `.stderr` file. This can be used
+    /// to normalize volatile output, like absolute paths, line numbers from the
+    /// standard library or durations.
+    ///
+    /// ```rust,ignore
+    /// let config = marker_uitest::simple_ui_test_config!()?
+    ///     .normalize_stderr(r"finished in \d+ms", "finished in $$TIME");
+    /// ```
+    ///
+    /// The replacement can reference capture groups, like `$1`. A literal `$`
+    /// has to be written as `$$`. Note that Unicode-aware character classes,
+    /// like `\w`, are not supported by the regex engine used by `ui_test`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `pattern` is not a valid regex.
+    #[must_use]
+    fn normalize_stderr(self, pattern: &str, replacement: &'static str) -> Self;
+}
+
+impl ConfigExt for ui_test::Config {
+    #[track_caller]
+    fn normalize_stderr(mut self, pattern: &str, replacement: &'static str) -> Self {
+        self.stderr_filter(pattern, replacement);
+        self
+    }
+}
+
 /// This function calls `cargo-marker` for the basic test setup.
 fn retrieve_test_setup(crate_name: &str, pkg_dir: &Path) -> TestSetup {
     #[cfg(not(feature = "dev-build"))]
@@ -0,0 +1,208 @@
+//! A lightweight harness, to run lints on code snippets and assert the emitted
+//! lints programmatically. This is an alternative to golden-file tests with
+//! [`ui_test`], for small unit assertions.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{retrieve_test_setup, verify_driver};
+
+/// This macro automatically fills the parameters of [`LintTest::new`] with
+/// environment values and default values.
+///
+/// It assumes a dependency to `marker_api`.
+#[macro_export]
+macro_rules! simple_lint_test {
+    () => {
+        $crate::simple_lint_test!(&std::env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "./target".into()))
+    };
+    ($target_dir:expr) => {
+        $crate::LintTest::new(
+            std::path::Path::new($target_dir),
+            env!("CARGO_PKG_NAME"),
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+            marker_api::MARKER_API_VERSION,
+        )
+    };
+}
+
+/// A builder to run Marker's driver with a lint crate on code snippets. The
+/// driver is executed for every snippet, and the emitted lints are returned as
+/// [`Emission`]s:
+///
+/// ```rust,ignore
+/// #[test]
+/// fn test_lint() {
+///     let test = marker_uitest::simple_lint_test!().unwrap();
+///     let emissions = test.run("fn foo() {\n    let x = 1;\n}").unwrap();
+///
+///     let lints: Vec<_> = emissions.iter().map(|e| (e.lint.as_str(), e.line)).collect();
+///     assert_eq!(lints, [("marker::my_lints::my_lint", 2)]);
+/// }
+/// ```
+///
+/// The snippets are compiled as library crates with the 2021 edition, like the
+/// ui-tests, the `unused` lints of rustc are allowed by default.
+#[derive(Debug)]
+pub struct LintTest {
+    driver_path: PathBuf,
+    out_dir: PathBuf,
+    env_vars: HashMap<String, String>,
+    args: Vec<String>,
+}
+
+impl LintTest {
+    /// This function retrieves the test setup from `cargo-marker`, with the
+    /// given crate as a lint crate. It's recommended to use the
+    /// [`simple_lint_test`] macro to fill all parameters automatically.
+    pub fn new(
+        target_dir: &Path,
+        crate_name: &str,
+        crate_dir: &Path,
+        marker_api_version: &str,
+    ) -> ui_test::color_eyre::Result<Self> {
+        let setup = retrieve_test_setup(crate_name, &std::fs::canonicalize(crate_dir)?);
+        verify_driver(&setup, marker_api_version);
+
+        let out_dir = target_dir.join("lint_test");
+        std::fs::create_dir_all(&out_dir)?;
+
+        Ok(Self {
+            driver_path: PathBuf::from(setup.rustc_path),
+            out_dir: std::fs::canonicalize(out_dir)?,
+            env_vars: setup.env_vars,
+            args: vec![
+                "--edition=2021".into(),
+                "--crate-type=lib".into(),
+                "--emit=metadata".into(),
+                "-Aunused".into(),
+            ],
+        })
+    }
+
+    /// Adds an argument, which is passed to the driver like to rustc.
+    #[must_use]
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Sets an environment value for the driver. This can be used to
+    /// configure the lint crate, with the `MARKER_LINT_CRATES_CONFIG` value.
+    #[must_use]
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env_vars.insert(key.into(), value.into());
+        self
+    }
+
+    /// Runs the driver on the given code and returns all emitted lints, in the
+    /// order of their emission. Compiler errors in the snippet are returned as
+    /// an error.
+    pub fn run(&self, code: &str) -> ui_test::color_eyre::Result<Vec<Emission>> {
+        // Tests are executed in parallel, every run therefore needs a unique directory
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let run_dir = self.out_dir.join(format!(
+            "{}_{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&run_dir)?;
+        let file = run_dir.join("snippet.rs");
+        std::fs::write(&file, code)?;
+
+        let output = Command::new(&self.driver_path)
+            .envs(&self.env_vars)
+            .arg(&file)
+            .args(&self.args)
+            .arg("--error-format=json")
+            .arg("--out-dir")
+            .arg(&run_dir)
+            .output()?;
+        std::fs::remove_dir_all(&run_dir)?;
+
+        let stderr = String::from_utf8(output.stderr)?;
+        if !output.status.success() {
+            let rendered = parse_rendered(&stderr);
+            ui_test::color_eyre::eyre::bail!("the snippet failed to compile:\n\n{rendered}");
+        }
+
+        Ok(parse_emissions(&stderr))
+    }
+}
+
+/// A lint emission, returned by [`LintTest::run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Emission {
+    /// The full name of the lint, like `marker::lint_crate::lint_name`.
+    pub lint: String,
+    /// The level of the emission, like `warning` or `error`.
+    pub level: String,
+    /// The main message of the emission.
+    pub message: String,
+    /// The 1-based line of the primary span in the snippet.
+    pub line: usize,
+}
+
+/// Parses the JSON diagnostics of the driver. Diagnostics without a lint name,
+/// like the summary of emitted warnings, are skipped.
+fn parse_emissions(stderr: &str) -> Vec<Emission> {
+    stderr
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter_map(|diag| {
+            let lint = diag["code"]["code"].as_str()?;
+            let line = diag["spans"]
+                .as_array()?
+                .iter()
+                .find(|span| span["is_primary"].as_bool() == Some(true))?["line_start"]
+                .as_u64()?;
+            Some(Emission {
+                lint: lint.to_string(),
+                level: diag["level"].as_str()?.to_string(),
+                message: diag["message"].as_str()?.to_string(),
+                line: usize::try_from(line).ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Collects the rendered text of JSON diagnostics, for error messages.
+fn parse_rendered(stderr: &str) -> String {
+    stderr
+        .lines()
+        .map(|line| match serde_json::from_str::<serde_json::Value>(line) {
+            Ok(diag) => diag["rendered"].as_str().unwrap_or_default().to_string(),
+            Err(_) => format!("{line}\n"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_emissions() {
+        let stderr = concat!(
+            r#"{"message":"found a test item","code":{"code":"marker::marker_uilints::test_lint","explanation":null},"level":"warning","spans":[{"line_start":1,"is_primary":false},{"line_start":3,"is_primary":true}],"children":[],"rendered":"..."}"#,
+            "\n",
+            r#"{"message":"1 warning emitted","code":null,"level":"warning","spans":[],"children":[],"rendered":"..."}"#,
+            "\n",
+        );
+
+        assert_eq!(
+            parse_emissions(stderr),
+            [Emission {
+                lint: "marker::marker_uilints::test_lint".to_string(),
+                level: "warning".to_string(),
+                message: "found a test item".to_string(),
+                line: 3,
+            }]
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use marker_uitest::ui_test::*;
+use marker_uitest::ConfigExt;
+use std::path::PathBuf;
+
+/// Runs `rustc` on the files in `tests/normalize_stderr` and checks that the
+/// normalization registered by [`ConfigExt::normalize_stderr`] is applied to
+/// the captured stderr output, before it's compared to the `.stderr` files.
+#[test]
+fn test_normalize_stderr() -> color_eyre::Result<()> {
+    let config = Config {
+        mode: Mode::Yolo {
+            rustfix: RustfixMode::Disabled,
+        },
+        out_dir: PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("normalize_stderr"),
+        output_conflict_handling: if std::env::var_os("RUST_BLESS").is_some() {
+            OutputConflictHandling::Bless
+        } else {
+            OutputConflictHandling::Error("RUST_BLESS=1 cargo test".into())
+        },
+        ..Config::rustc("tests/normalize_stderr")
+    }
+    .normalize_stderr(r"error\[E\d+\]", "error[$$CODE]")
+    .normalize_stderr(r"found `(&[a-z]+)`", "found `$1` (normalized)");
+
+    run_tests_generic(
+        vec![config],
+        default_file_filter,
+        default_per_file_config,
+        status_emitter::Text::quiet(),
+    )
+}
@@ -0,0 +1,23 @@
+//! This module contains utilities to reason about constant values, like the
+//! values of literals.
+
+use marker_api::ast::IntLitExpr;
+
+/// Returns `true`, if the value of the given integer literal fits into the
+/// type specified by its suffix. `255_u8` fits, while `256_u8` doesn't.
+///
+/// `None` is returned, if the literal doesn't have a suffix. The type of these
+/// literals is inferred and can be checked with
+/// [`IntLitExpr::fits_suffix`]. `None` is also returned for `isize` and `usize`
+/// suffixes, if the result depends on the pointer width of the target.
+///
+/// The value of a literal is always positive, negative numbers have a unary
+/// negation as their parent. For signed suffixes, the value is therefore
+/// only compared against the positive range. For `-128_i8` this function will
+/// return `false`, as `128` doesn't fit into an `i8`. Lints need to check the
+/// parent expression, to handle this case.
+#[must_use]
+pub fn lit_fits_suffix(expr: &IntLitExpr<'_>) -> Option<bool> {
+    expr.suffix()?;
+    expr.fits_suffix()
+}
@@ -0,0 +1,92 @@
+//! This module contains utilities to reason about the control flow inside a
+//! body. These are useful for lints, which want to detect dead code, like
+//! statements after a `return` or `panic!()`.
+
+use marker_api::{
+    ast::{ExprKind, StmtKind},
+    prelude::*,
+};
+
+/// Returns `true`, if control flow never continues after the given expression.
+///
+/// This is the case, if [`MarkerContext::expr_diverges`] returns `true`, or if
+/// the expression is a `return`, `break` or `continue` expression. Blocks
+/// diverge, if one of their statements or their tail expression diverges, like
+/// `{ return; 1 }`. The statements of labeled blocks are not checked, since a
+/// `break` can target the label. Async blocks never diverge, as their body is
+/// only evaluated when they're awaited.
+#[must_use]
+pub fn expr_diverges<'ast>(cx: &MarkerContext<'ast>, expr: ExprKind<'ast>) -> bool {
+    match expr {
+        ExprKind::Return(_) | ExprKind::Break(_) | ExprKind::Continue(_) => true,
+        ExprKind::Block(block) if block.syncness().is_async() => false,
+        ExprKind::Block(block) if block.label().is_none() => {
+            block.stmts().iter().any(|stmt| stmt_diverges(cx, stmt))
+                || block.expr().is_some_and(|expr| expr_diverges(cx, expr))
+                || cx.expr_diverges(expr)
+        },
+        _ => cx.expr_diverges(expr),
+    }
+}
+
+/// Returns `true`, if the given expression can't be reached, since a previous
+/// statement of a surrounding block diverges, as defined by [`expr_diverges`]:
+///
+/// ```ignore
+/// fn foo() -> u32 {
+///     return 1;
+///     // Every expression in this statement is unreachable
+///     bar(2)
+/// }
+/// ```
+///
+/// The check is conservative. Only the statements of blocks, which contain the
+/// expression, are considered. Diverging operands, like in `bar(return, 2)`, or
+/// diverging conditions are ignored. The search stops at the boundary of the
+/// containing body or closure.
+#[must_use]
+pub fn is_unreachable<'ast>(cx: &MarkerContext<'ast>, expr: ExprKind<'ast>) -> bool {
+    let mut child = expr.id();
+    while let Some(parent) = cx.ast().parent_expr(child) {
+        match parent {
+            ExprKind::Block(block) => {
+                // For the tail expression, all statements precede it
+                let diverges = block
+                    .stmts()
+                    .iter()
+                    .take_while(|stmt| !stmt_contains_expr(stmt, child))
+                    .any(|stmt| stmt_diverges(cx, stmt));
+                if diverges {
+                    return true;
+                }
+            },
+            ExprKind::Closure(_) => return false,
+            _ => {},
+        }
+        child = parent.id();
+    }
+
+    false
+}
+
+fn stmt_contains_expr(stmt: &StmtKind<'_>, expr: ExprId) -> bool {
+    match stmt {
+        StmtKind::Let(stmt) => [stmt.init(), stmt.els()]
+            .into_iter()
+            .flatten()
+            .any(|init| init.id() == expr),
+        StmtKind::Expr(stmt) => stmt.expr().id() == expr,
+        _ => false,
+    }
+}
+
+fn stmt_diverges<'ast>(cx: &MarkerContext<'ast>, stmt: &StmtKind<'ast>) -> bool {
+    match stmt {
+        // The `else` block of a `let ... else` diverges by definition, but it's only
+        // executed on a mismatch. Only the initializer decides, if the following
+        // statements are reachable.
+        StmtKind::Let(stmt) => stmt.init().is_some_and(|init| expr_diverges(cx, init)),
+        StmtKind::Expr(stmt) => expr_diverges(cx, stmt.expr()),
+        _ => false,
+    }
+}
@@ -0,0 +1,225 @@
+//! This module contains utilities to compare AST nodes structurally. Spans are
+//! ignored by these comparisons. This allows lints to detect repeated code,
+//! like `if a == b { a } else { b }` or `x.len() + x.len()`.
+
+use marker_api::{
+    ast::{AstPathSegment, AstPathTarget, AstQPath, BlockExpr, ExprKind, TyKind},
+    prelude::*,
+};
+
+/// Returns `true`, if the given expressions are structurally equal. This is a
+/// shorthand for [`ExprEq::eq_expr`] with the default configuration.
+///
+/// See [`ExprEq`] for a description of the comparison.
+#[must_use]
+pub fn expr_eq<'ast>(cx: &'ast MarkerContext<'ast>, a: ExprKind<'ast>, b: ExprKind<'ast>) -> bool {
+    ExprEq::new(cx).eq_expr(a, b)
+}
+
+/// A configurable structural comparison of expressions.
+///
+/// Two expressions are equal, if they have the same kind and all their child
+/// nodes are equal. Spans are ignored. Literals are compared by their values
+/// and suffixes, paths and method calls by their resolved targets. Qualified
+/// and type relative paths, like `<u32 as Default>::default` or `T::default`,
+/// also need to have equal types and segments. By default, local variables are
+/// compared by their [`VarId`], this can be changed with
+/// [`ExprEq::ignore_local_identity`].
+///
+/// The comparison is conservative. Expressions which can't be compared are
+/// considered different. This includes:
+/// * Paths and method calls with explicit generic arguments
+/// * Expressions which may have side effects, like closures, loops or assignments
+/// * Blocks with statements
+/// * Casts to non-primitive types
+/// * [`Unstable`](ExprKind::Unstable) expressions
+///
+/// Note that structurally equal expressions can still evaluate to different
+/// values, for example, if they call functions with side effects.
+#[derive(Debug, Clone, Copy)]
+pub struct ExprEq<'ast> {
+    cx: &'ast MarkerContext<'ast>,
+    ignore_local_identity: bool,
+}
+
+impl<'ast> ExprEq<'ast> {
+    #[must_use]
+    pub fn new(cx: &'ast MarkerContext<'ast>) -> Self {
+        Self {
+            cx,
+            ignore_local_identity: false,
+        }
+    }
+
+    /// If set to `true`, local variables are compared by their names instead
+    /// of their identity. This is useful to compare expressions from different
+    /// scopes, like the bodies of `|x| x + 1` and `|x| x + 1`, which refer to
+    /// different variables with the same name.
+    #[must_use]
+    pub fn ignore_local_identity(mut self, ignore: bool) -> Self {
+        self.ignore_local_identity = ignore;
+        self
+    }
+
+    /// Returns `true`, if the given expressions are structurally equal.
+    #[must_use]
+    pub fn eq_expr(&self, a: ExprKind<'ast>, b: ExprKind<'ast>) -> bool {
+        match (a, b) {
+            (ExprKind::IntLit(a), ExprKind::IntLit(b)) => a.value() == b.value() && a.suffix() == b.suffix(),
+            #[allow(clippy::float_cmp)] // Literal values can be compared exactly
+            (ExprKind::FloatLit(a), ExprKind::FloatLit(b)) => a.value() == b.value() && a.suffix() == b.suffix(),
+            (ExprKind::StrLit(a), ExprKind::StrLit(b)) => {
+                a.is_byte_str() == b.is_byte_str() && a.byte_value() == b.byte_value()
+            },
+            (ExprKind::CharLit(a), ExprKind::CharLit(b)) => a.value() == b.value(),
+            (ExprKind::BoolLit(a), ExprKind::BoolLit(b)) => a.value() == b.value(),
+            (ExprKind::Block(a), ExprKind::Block(b)) => self.eq_block(a, b),
+            (ExprKind::UnaryOp(a), ExprKind::UnaryOp(b)) => a.kind() == b.kind() && self.eq_expr(a.expr(), b.expr()),
+            (ExprKind::Ref(a), ExprKind::Ref(b)) => {
+                a.mutability() == b.mutability() && self.eq_expr(a.expr(), b.expr())
+            },
+            (ExprKind::BinaryOp(a), ExprKind::BinaryOp(b)) => {
+                a.kind() == b.kind() && self.eq_expr(a.left(), b.left()) && self.eq_expr(a.right(), b.right())
+            },
+            (ExprKind::Try(a), ExprKind::Try(b)) => self.eq_expr(a.expr(), b.expr()),
+            (ExprKind::As(a), ExprKind::As(b)) => {
+                eq_prim_ty(a.cast_ty(), b.cast_ty()) && self.eq_expr(a.expr(), b.expr())
+            },
+            (ExprKind::Path(a), ExprKind::Path(b)) => self.eq_path(a.path(), b.path()),
+            (ExprKind::Call(a), ExprKind::Call(b)) => {
+                self.eq_expr(a.func(), b.func()) && self.eq_exprs(a.args(), b.args())
+            },
+            (ExprKind::Method(a), ExprKind::Method(b)) => {
+                self.eq_segment(a.method(), b.method())
+                    && self.cx.resolve_method_target(a).is_some()
+                    && self.cx.resolve_method_target(a) == self.cx.resolve_method_target(b)
+                    && self.eq_expr(a.receiver(), b.receiver())
+                    && self.eq_exprs(a.args(), b.args())
+            },
+            (ExprKind::Array(a), ExprKind::Array(b)) => {
+                // Repeat expressions, like `[0; N]`, have a length expression
+                a.len().is_none() && b.len().is_none() && self.eq_exprs(a.elements(), b.elements())
+            },
+            (ExprKind::Tuple(a), ExprKind::Tuple(b)) => self.eq_exprs(a.elements(), b.elements()),
+            (ExprKind::Ctor(a), ExprKind::Ctor(b)) => {
+                // Fields are compared in source order, `Point { x, y }` is
+                // therefore different from `Point { y, x }`
+                self.eq_path(a.path(), b.path())
+                    && a.fields().len() == b.fields().len()
+                    && a.fields()
+                        .iter()
+                        .zip(b.fields())
+                        .all(|(a, b)| a.ident().name() == b.ident().name() && self.eq_expr(a.expr(), b.expr()))
+                    && self.eq_opt_expr(a.base(), b.base())
+            },
+            (ExprKind::Range(a), ExprKind::Range(b)) => {
+                a.is_inclusive() == b.is_inclusive()
+                    && self.eq_opt_expr(a.start(), b.start())
+                    && self.eq_opt_expr(a.end(), b.end())
+            },
+            (ExprKind::Index(a), ExprKind::Index(b)) => {
+                self.eq_expr(a.operand(), b.operand()) && self.eq_expr(a.index(), b.index())
+            },
+            (ExprKind::Field(a), ExprKind::Field(b)) => {
+                a.field().name() == b.field().name() && self.eq_expr(a.operand(), b.operand())
+            },
+            (ExprKind::If(a), ExprKind::If(b)) => {
+                self.eq_expr(a.condition(), b.condition())
+                    && self.eq_expr(a.then(), b.then())
+                    && self.eq_opt_expr(a.els(), b.els())
+            },
+            (ExprKind::Await(a), ExprKind::Await(b)) => self.eq_expr(a.expr(), b.expr()),
+            _ => false,
+        }
+    }
+
+    fn eq_exprs(&self, a: &[ExprKind<'ast>], b: &[ExprKind<'ast>]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| self.eq_expr(*a, *b))
+    }
+
+    fn eq_opt_expr(&self, a: Option<ExprKind<'ast>>, b: Option<ExprKind<'ast>>) -> bool {
+        match (a, b) {
+            (Some(a), Some(b)) => self.eq_expr(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn eq_block(&self, a: &BlockExpr<'ast>, b: &BlockExpr<'ast>) -> bool {
+        a.stmts().is_empty()
+            && b.stmts().is_empty()
+            && a.label().is_none()
+            && b.label().is_none()
+            && a.safety() == b.safety()
+            && a.syncness() == b.syncness()
+            && self.eq_opt_expr(a.expr(), b.expr())
+    }
+
+    fn eq_path(&self, a: &AstQPath<'ast>, b: &AstQPath<'ast>) -> bool {
+        if a.segments()
+            .iter()
+            .chain(b.segments())
+            .any(|seg| !seg.generics().is_empty())
+        {
+            return false;
+        }
+
+        // Qualified and type relative paths can resolve to the same trait item
+        // for different types. `u32::default` and `u64::default` are, for example,
+        // both resolved to `Default::default`.
+        let is_qualified = |path: &AstQPath<'ast>| path.self_ty().is_some() || path.path_ty().is_some();
+        if (is_qualified(a) || is_qualified(b))
+            && !(self.eq_opt_ty(a.self_ty(), b.self_ty())
+                && self.eq_opt_ty(a.path_ty(), b.path_ty())
+                && a.segments().len() == b.segments().len()
+                && a.segments()
+                    .iter()
+                    .zip(b.segments())
+                    .all(|(a, b)| self.eq_segment(a, b)))
+        {
+            return false;
+        }
+
+        match (a.resolve(), b.resolve()) {
+            (AstPathTarget::Var(a_var), AstPathTarget::Var(b_var)) => {
+                if self.ignore_local_identity {
+                    // Local variables are always referenced by a single segment
+                    a.segments().last().map(|seg| seg.ident().name())
+                        == b.segments().last().map(|seg| seg.ident().name())
+                } else {
+                    a_var == b_var
+                }
+            },
+            (AstPathTarget::SelfTy(a), AstPathTarget::SelfTy(b)) | (AstPathTarget::Item(a), AstPathTarget::Item(b)) => {
+                a == b
+            },
+            (AstPathTarget::Variant(a), AstPathTarget::Variant(b)) => a == b,
+            (AstPathTarget::Generic(a), AstPathTarget::Generic(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    fn eq_opt_ty(&self, a: Option<TyKind<'ast>>, b: Option<TyKind<'ast>>) -> bool {
+        match (a, b) {
+            (Some(TyKind::Path(a)), Some(TyKind::Path(b))) => self.eq_path(a.path(), b.path()),
+            (Some(a), Some(b)) => eq_prim_ty(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn eq_segment(&self, a: &AstPathSegment<'ast>, b: &AstPathSegment<'ast>) -> bool {
+        a.generics().is_empty() && b.generics().is_empty() && a.ident().name() == b.ident().name()
+    }
+}
+
+/// The types of casts are only compared, if they are primitive types, like
+/// `u32` or `f64`, which are the most common targets.
+fn eq_prim_ty(a: TyKind<'_>, b: TyKind<'_>) -> bool {
+    match (a, b) {
+        (TyKind::Bool(_), TyKind::Bool(_)) => true,
+        (TyKind::Num(a), TyKind::Num(b)) => a.numeric_kind() == b.numeric_kind(),
+        (TyKind::Text(a), TyKind::Text(b)) => a.textual_kind() == b.textual_kind(),
+        _ => false,
+    }
+}
@@ -0,0 +1,182 @@
+//! This module contains utilities to look through expressions, that don't
+//! change the value they wrap, like references or blocks with a single
+//! expression. These are pure AST walks, which don't require a driver.
+//!
+//! Parentheses are not represented in Marker's AST. An expression like
+//! `((x))` is already represented by the path `x`, lints therefore don't
+//! have to peel them explicitly.
+
+use marker_api::ast::{ExprKind, UnaryOpKind};
+
+/// Removes all leading `&` and `&mut` operators from the given expression.
+///
+/// ```ignore
+/// // For `&&mut x`, this returns the expression of `x`
+/// let expr = marker_utils::expr::peel_refs(expr);
+/// ```
+#[must_use]
+pub fn peel_refs(mut expr: ExprKind<'_>) -> ExprKind<'_> {
+    while let ExprKind::Ref(ref_expr) = expr {
+        expr = ref_expr.expr();
+    }
+    expr
+}
+
+/// Removes all leading `*` operators from the given expression.
+///
+/// ```ignore
+/// // For `**x`, this returns the expression of `x`
+/// let expr = marker_utils::expr::peel_derefs(expr);
+/// ```
+#[must_use]
+pub fn peel_derefs(mut expr: ExprKind<'_>) -> ExprKind<'_> {
+    loop {
+        match expr {
+            ExprKind::UnaryOp(op) if op.kind() == UnaryOpKind::Deref => expr = op.expr(),
+            _ => return expr,
+        }
+    }
+}
+
+/// Removes all leading `&`, `&mut` and `*` operators from the given
+/// expression, in any combination.
+///
+/// ```ignore
+/// // For `&*&x`, this returns the expression of `x`
+/// let expr = marker_utils::expr::peel_ref_operators(expr);
+/// ```
+#[must_use]
+pub fn peel_ref_operators(mut expr: ExprKind<'_>) -> ExprKind<'_> {
+    loop {
+        match expr {
+            ExprKind::Ref(ref_expr) => expr = ref_expr.expr(),
+            ExprKind::UnaryOp(op) if op.kind() == UnaryOpKind::Deref => expr = op.expr(),
+            _ => return expr,
+        }
+    }
+}
+
+/// Removes all blocks, which only consist of a single expression, like
+/// `{ { x } }`. Blocks with statements, labels, or `unsafe` and `async`
+/// blocks are not removed, since they change the meaning of the expression.
+///
+/// ```ignore
+/// // For `{ { x } }`, this returns the expression of `x`
+/// let expr = marker_utils::expr::peel_blocks(expr);
+/// ```
+#[must_use]
+pub fn peel_blocks(mut expr: ExprKind<'_>) -> ExprKind<'_> {
+    loop {
+        match expr {
+            ExprKind::Block(block)
+                if block.stmts().is_empty()
+                    && block.label().is_none()
+                    && !block.safety().is_unsafe()
+                    && block.syncness().is_sync() =>
+            {
+                let Some(inner) = block.expr() else {
+                    return expr;
+                };
+                expr = inner;
+            },
+            _ => return expr,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use marker_api::{
+        ast::{BlockExpr, CaptureKind, CommonExprData, IntLitExpr, RefExpr, UnaryOpExpr},
+        common::{ExprId, Mutability, Safety, SpanId, Syncness},
+    };
+
+    fn data() -> CommonExprData<'static> {
+        CommonExprData::new(ExprId::new(0), SpanId::new(0))
+    }
+
+    fn lit() -> ExprKind<'static> {
+        ExprKind::IntLit(Box::leak(Box::new(IntLitExpr::new(data(), 1, None))))
+    }
+
+    fn reference(expr: ExprKind<'static>) -> ExprKind<'static> {
+        ExprKind::Ref(Box::leak(Box::new(RefExpr::new(data(), expr, Mutability::Unmut))))
+    }
+
+    fn deref(expr: ExprKind<'static>) -> ExprKind<'static> {
+        ExprKind::UnaryOp(Box::leak(Box::new(UnaryOpExpr::new(data(), expr, UnaryOpKind::Deref))))
+    }
+
+    fn neg(expr: ExprKind<'static>) -> ExprKind<'static> {
+        ExprKind::UnaryOp(Box::leak(Box::new(UnaryOpExpr::new(data(), expr, UnaryOpKind::Neg))))
+    }
+
+    fn block(expr: ExprKind<'static>, safety: Safety) -> ExprKind<'static> {
+        ExprKind::Block(Box::leak(Box::new(BlockExpr::new(
+            data(),
+            &[],
+            Some(expr),
+            None,
+            safety,
+            Syncness::Sync,
+            CaptureKind::Default,
+        ))))
+    }
+
+    fn is_lit(expr: ExprKind<'_>) -> bool {
+        matches!(expr, ExprKind::IntLit(_))
+    }
+
+    #[test]
+    fn test_peel_refs() {
+        // `&&1`
+        assert!(is_lit(peel_refs(reference(reference(lit())))));
+        // `&*1`
+        assert!(matches!(peel_refs(reference(deref(lit()))), ExprKind::UnaryOp(_)));
+        // `1`
+        assert!(is_lit(peel_refs(lit())));
+    }
+
+    #[test]
+    fn test_peel_derefs() {
+        // `**1`
+        assert!(is_lit(peel_derefs(deref(deref(lit())))));
+        // `*&1`
+        assert!(matches!(peel_derefs(deref(reference(lit()))), ExprKind::Ref(_)));
+        // `-1`
+        assert!(matches!(peel_derefs(neg(lit())), ExprKind::UnaryOp(_)));
+    }
+
+    #[test]
+    fn test_peel_ref_operators() {
+        // `&*&*1`
+        assert!(is_lit(peel_ref_operators(reference(deref(reference(deref(lit())))))));
+        // `&-1`
+        assert!(matches!(
+            peel_ref_operators(reference(neg(lit()))),
+            ExprKind::UnaryOp(_)
+        ));
+        // `&{ 1 }`
+        assert!(matches!(
+            peel_ref_operators(reference(block(lit(), Safety::Safe))),
+            ExprKind::Block(_)
+        ));
+    }
+
+    #[test]
+    fn test_peel_blocks() {
+        // `{ { 1 } }`
+        assert!(is_lit(peel_blocks(block(block(lit(), Safety::Safe), Safety::Safe))));
+        // `{ &{ 1 } }`
+        assert!(matches!(
+            peel_blocks(block(reference(block(lit(), Safety::Safe)), Safety::Safe)),
+            ExprKind::Ref(_)
+        ));
+        // `{ unsafe { 1 } }`
+        assert!(matches!(
+            peel_blocks(block(block(lit(), Safety::Unsafe), Safety::Safe)),
+            ExprKind::Block(_)
+        ));
+    }
+}
@@ -5,4 +5,9 @@
 #![allow(clippy::unused_self)] // `self` is needed to potentualy change the behavior later
 #![allow(clippy::trivially_copy_pass_by_ref)] // Needed to potentualy change the behavior later
 
+pub mod consts;
+pub mod control_flow;
+pub mod eq;
+pub mod expr;
+pub mod ops;
 pub mod visitor;
@@ -0,0 +1,167 @@
+//! This module contains utilities to reason about the precedence and
+//! associativity of operators. These are useful when suggestions rewrite
+//! operator expressions and need to decide, where parentheses are required.
+
+use marker_api::ast::BinaryOpKind;
+
+/// Returns `true`, if the given operator is associative. For an associative
+/// operator `op`, the expressions `(a op b) op c` and `a op (b op c)` are
+/// equivalent.
+///
+/// ```
+/// # use marker_api::ast::BinaryOpKind;
+/// # use marker_utils::ops::is_associative;
+/// // `a + (b + c)` is the same as `a + b + c`
+/// assert!(is_associative(BinaryOpKind::Add));
+/// // `a - (b - c)` is different from `a - b - c`
+/// assert!(!is_associative(BinaryOpKind::Sub));
+/// ```
+///
+/// Note that this only considers the mathematical meaning of the operator.
+/// Rearranging floating point operations, or integer operations that might
+/// overflow, can still change the result.
+#[must_use]
+pub fn is_associative(op: BinaryOpKind) -> bool {
+    matches!(
+        op,
+        BinaryOpKind::Add
+            | BinaryOpKind::Mul
+            | BinaryOpKind::BitAnd
+            | BinaryOpKind::BitXor
+            | BinaryOpKind::BitOr
+            | BinaryOpKind::And
+            | BinaryOpKind::Or
+    )
+}
+
+/// Returns `true`, if a binary expression with the `child` operator has to be
+/// wrapped in parentheses, when it's used as an operand of a binary expression
+/// with the `parent` operator. `is_right` specifies if the child is the right
+/// operand of the parent.
+///
+/// ```
+/// # use marker_api::ast::BinaryOpKind;
+/// # use marker_utils::ops::needs_parens_as_child;
+/// // `(a - b) - c` can be written as `a - b - c`
+/// assert!(!needs_parens_as_child(BinaryOpKind::Sub, BinaryOpKind::Sub, false));
+/// // `a - (b - c)` requires the parentheses
+/// assert!(needs_parens_as_child(BinaryOpKind::Sub, BinaryOpKind::Sub, true));
+/// // `a * (b + c)` requires the parentheses
+/// assert!(needs_parens_as_child(BinaryOpKind::Mul, BinaryOpKind::Add, true));
+/// ```
+///
+/// This follows Rust's [precedence table]. Binary operators with the same
+/// precedence are left-associative, except for comparison operators, which
+/// can't be chained without parentheses. Parentheses are always kept for
+/// operators, which are unknown to this version of `marker_utils`.
+///
+/// [precedence table]: <https://doc.rust-lang.org/reference/expressions.html#expression-precedence>
+#[must_use]
+pub fn needs_parens_as_child(parent: BinaryOpKind, child: BinaryOpKind, is_right: bool) -> bool {
+    let (Some(parent_prec), Some(child_prec)) = (precedence(parent), precedence(child)) else {
+        return true;
+    };
+
+    if child_prec != parent_prec {
+        return child_prec < parent_prec;
+    }
+
+    if is_comparison(parent) {
+        // Comparison operators are non-associative: `a == b == c` is a syntax error
+        return true;
+    }
+
+    // All other binary operators are left-associative. The left operand can
+    // therefore omit the parentheses. The right operand can only omit them,
+    // if the expression stays the same, like in `a + (b + c)`.
+    is_right && !(parent == child && is_associative(parent))
+}
+
+fn is_comparison(op: BinaryOpKind) -> bool {
+    matches!(
+        op,
+        BinaryOpKind::Eq
+            | BinaryOpKind::NotEq
+            | BinaryOpKind::Greater
+            | BinaryOpKind::GreaterEq
+            | BinaryOpKind::Lesser
+            | BinaryOpKind::LesserEq
+    )
+}
+
+/// The precedence of binary operators, a higher value binds stronger. `None` is
+/// returned for operators, which were added after this function was written.
+fn precedence(op: BinaryOpKind) -> Option<u8> {
+    let prec = match op {
+        BinaryOpKind::Mul | BinaryOpKind::Div | BinaryOpKind::Rem => 10,
+        BinaryOpKind::Add | BinaryOpKind::Sub => 9,
+        BinaryOpKind::Shr | BinaryOpKind::Shl => 8,
+        BinaryOpKind::BitAnd => 7,
+        BinaryOpKind::BitXor => 6,
+        BinaryOpKind::BitOr => 5,
+        BinaryOpKind::Eq
+        | BinaryOpKind::NotEq
+        | BinaryOpKind::Greater
+        | BinaryOpKind::GreaterEq
+        | BinaryOpKind::Lesser
+        | BinaryOpKind::LesserEq => 4,
+        BinaryOpKind::And => 3,
+        BinaryOpKind::Or => 2,
+        _ => return None,
+    };
+    Some(prec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subtraction_chain() {
+        // `(a - b) - c` == `a - b - c`
+        assert!(!needs_parens_as_child(BinaryOpKind::Sub, BinaryOpKind::Sub, false));
+        // `a - (b - c)` != `a - b - c`
+        assert!(needs_parens_as_child(BinaryOpKind::Sub, BinaryOpKind::Sub, true));
+        // `a - (b + c)` != `a - b + c`
+        assert!(needs_parens_as_child(BinaryOpKind::Sub, BinaryOpKind::Add, true));
+        // `a + (b - c)` requires parentheses, as the operators differ
+        assert!(needs_parens_as_child(BinaryOpKind::Add, BinaryOpKind::Sub, true));
+        // `a + (b + c)` == `a + b + c`
+        assert!(!needs_parens_as_child(BinaryOpKind::Add, BinaryOpKind::Add, true));
+        // `a - (b * c)` == `a - b * c`
+        assert!(!needs_parens_as_child(BinaryOpKind::Sub, BinaryOpKind::Mul, true));
+        // `(a - b) * c` != `a - b * c`
+        assert!(needs_parens_as_child(BinaryOpKind::Mul, BinaryOpKind::Sub, false));
+    }
+
+    #[test]
+    fn test_comparison_chain() {
+        // `a == b == c` is invalid syntax
+        assert!(needs_parens_as_child(BinaryOpKind::Eq, BinaryOpKind::Eq, false));
+        assert!(needs_parens_as_child(BinaryOpKind::Eq, BinaryOpKind::Eq, true));
+        assert!(needs_parens_as_child(
+            BinaryOpKind::Lesser,
+            BinaryOpKind::Greater,
+            false
+        ));
+        // `(a < b) && (b < c)` == `a < b && b < c`
+        assert!(!needs_parens_as_child(BinaryOpKind::And, BinaryOpKind::Lesser, false));
+        assert!(!needs_parens_as_child(BinaryOpKind::And, BinaryOpKind::Lesser, true));
+        // `(a && b) == c` != `a && b == c`
+        assert!(needs_parens_as_child(BinaryOpKind::Eq, BinaryOpKind::And, false));
+        // `(a + b) == c` == `a + b == c`
+        assert!(!needs_parens_as_child(BinaryOpKind::Eq, BinaryOpKind::Add, false));
+    }
+
+    #[test]
+    fn test_is_associative() {
+        assert!(is_associative(BinaryOpKind::Add));
+        assert!(is_associative(BinaryOpKind::Mul));
+        assert!(is_associative(BinaryOpKind::And));
+        assert!(is_associative(BinaryOpKind::Or));
+        assert!(!is_associative(BinaryOpKind::Sub));
+        assert!(!is_associative(BinaryOpKind::Div));
+        assert!(!is_associative(BinaryOpKind::Shl));
+        assert!(!is_associative(BinaryOpKind::Eq));
+    }
+}
@@ -1,7 +1,10 @@
 use std::ops::ControlFlow;
 
 use marker_api::{
-    ast::{EnumVariant, ItemField},
+    ast::{
+        AstPath, AstPathTarget, AstQPath, EnumVariant, GenericArgKind, GenericArgs, GenericParamKind, GenericParams,
+        ItemField, TyKind, TyParamBound,
+    },
     prelude::*,
 };
 
@@ -89,8 +92,44 @@ pub trait Visitor<B> {
     fn visit_expr<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _expr: ExprKind<'ast>) -> ControlFlow<B> {
         ControlFlow::Continue(())
     }
+
+    fn visit_pat<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _pat: PatKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_ty<'ast>(&mut self, _cx: &'ast MarkerContext<'ast>, _ty: TyKind<'ast>) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    /// This function is called for every generic parameter declared by an item.
+    /// The `generics` are the parameters of the declaring item, they contain the
+    /// bounds of the parameter.
+    fn visit_generic_param<'ast>(
+        &mut self,
+        _cx: &'ast MarkerContext<'ast>,
+        _param: &'ast GenericParamKind<'ast>,
+        _generics: &'ast GenericParams<'ast>,
+    ) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
+
+    /// This function is called for every path in expressions, patterns, types
+    /// and `use` items, together with the resolved target of the path. It can
+    /// be used to find all references to a specific node.
+    ///
+    /// For [`AstQPath`]s, the lossy path from [`AstQPath::as_path_lossy`] is
+    /// passed to this function. The optional `Self` type is visited as a type.
+    fn visit_path<'ast>(
+        &mut self,
+        _cx: &'ast MarkerContext<'ast>,
+        _path: &'ast AstPath<'ast>,
+        _target: AstPathTarget,
+    ) -> ControlFlow<B> {
+        ControlFlow::Continue(())
+    }
 }
 
+#[allow(clippy::too_many_lines)]
 pub fn traverse_item<'ast, B>(
     cx: &'ast MarkerContext<'ast>,
     visitor: &mut dyn Visitor<B>,
@@ -126,38 +165,64 @@ pub fn traverse_item<'ast, B>(
             }
         },
         ItemKind::Static(item) => {
+            traverse_ty(cx, visitor, item.ty())?;
             traverse_body_id(cx, visitor, item.body_id())?;
         },
         ItemKind::Const(item) => {
+            traverse_ty(cx, visitor, item.ty())?;
             traverse_body_id(cx, visitor, item.body_id())?;
         },
         ItemKind::Fn(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
+            // The parameters are part of the function signature, they are
+            // therefore visited regardless of the scope.
+            for param in item.params() {
+                traverse_pat(cx, visitor, param.pat())?;
+                traverse_ty(cx, visitor, param.ty())?;
+            }
+            if let Some(return_ty) = item.return_ty() {
+                traverse_ty(cx, visitor, *return_ty)?;
+            }
             traverse_body_id(cx, visitor, item.body_id())?;
         },
         ItemKind::Struct(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
             for field in item.fields() {
                 visitor.visit_field(cx, field)?;
+                traverse_ty(cx, visitor, field.ty())?;
             }
         },
         ItemKind::Union(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
             for field in item.fields() {
                 visitor.visit_field(cx, field)?;
+                traverse_ty(cx, visitor, field.ty())?;
             }
         },
         ItemKind::Enum(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
             for variant in item.variants() {
                 visitor.visit_variant(cx, variant)?;
+                for field in variant.fields() {
+                    traverse_ty(cx, visitor, field.ty())?;
+                }
                 if let Some(const_expr) = variant.discriminant() {
                     traverse_expr(cx, visitor, const_expr.expr())?;
                 }
             }
         },
         ItemKind::Trait(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
             for assoc_item in item.items() {
                 traverse_item(cx, visitor, assoc_item.as_item())?;
             }
         },
         ItemKind::Impl(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
+            if let Some(trait_ref) = item.trait_ref() {
+                traverse_generic_args(cx, visitor, trait_ref.generics())?;
+            }
+            traverse_ty(cx, visitor, item.ty())?;
             for assoc_item in item.items() {
                 traverse_item(cx, visitor, assoc_item.as_item())?;
             }
@@ -167,7 +232,16 @@ pub fn traverse_item<'ast, B>(
                 traverse_item(cx, visitor, ext_item.as_item())?;
             }
         },
-        ItemKind::ExternCrate(_) | ItemKind::Use(_) | ItemKind::Unstable(_) | ItemKind::TyAlias(_) => {
+        ItemKind::TyAlias(item) => {
+            traverse_generic_params(cx, visitor, item.generics())?;
+            if let Some(aliased_ty) = item.aliased_ty() {
+                traverse_ty(cx, visitor, aliased_ty)?;
+            }
+        },
+        ItemKind::Use(item) => {
+            visitor.visit_path(cx, item.use_path(), item.resolve())?;
+        },
+        ItemKind::ExternCrate(_) | ItemKind::Unstable(_) => {
             // These items have no sub nodes, which are visited by this visitor
         },
         _ => unreachable!("all items are covered"),
@@ -175,6 +249,56 @@ pub fn traverse_item<'ast, B>(
     ControlFlow::Continue(())
 }
 
+/// Returns an iterator over every item in the given crate, including items nested
+/// in modules, traits, impls, extern blocks and bodies. The root module of the
+/// crate is not included.
+///
+/// The items are collected with a [`Visitor`] using the
+/// [`AllBodies`](VisitorScope::AllBodies) scope, and are then requested from
+/// the [`AstMap`](marker_api::context::AstMap). They are yielded in the order in
+/// which they are visited, meaning that an item comes before its nested items.
+///
+/// This example counts all functions in a crate:
+///
+/// ```
+/// # use marker_api::prelude::*;
+/// # use marker_api::ast::Crate;
+/// # use marker_utils::visitor::all_items;
+/// fn count_fns<'ast>(cx: &'ast MarkerContext<'ast>, krate: &'ast Crate<'ast>) -> usize {
+///     all_items(cx, krate)
+///         .filter(|item| matches!(item, ItemKind::Fn(_)))
+///         .count()
+/// }
+/// ```
+pub fn all_items<'ast>(
+    cx: &'ast MarkerContext<'ast>,
+    krate: &'ast ast::Crate<'ast>,
+) -> impl Iterator<Item = ItemKind<'ast>> {
+    struct ItemCollector {
+        ids: Vec<ItemId>,
+    }
+    impl Visitor<()> for ItemCollector {
+        fn scope(&self) -> VisitorScope {
+            VisitorScope::AllBodies
+        }
+
+        fn visit_item<'v_ast>(
+            &mut self,
+            _cx: &'v_ast MarkerContext<'v_ast>,
+            item: ItemKind<'v_ast>,
+        ) -> ControlFlow<()> {
+            self.ids.push(item.id());
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut collector = ItemCollector { ids: vec![] };
+    for item in krate.root_mod().items() {
+        let _ = traverse_item(cx, &mut collector, *item);
+    }
+    collector.ids.into_iter().filter_map(|id| cx.ast().item(id))
+}
+
 pub fn traverse_body<'ast, B>(
     cx: &'ast MarkerContext<'ast>,
     visitor: &mut dyn Visitor<B>,
@@ -199,6 +323,10 @@ pub fn traverse_stmt<'ast, B>(
             traverse_item(cx, visitor, item.item())?;
         },
         StmtKind::Let(lt) => {
+            traverse_pat(cx, visitor, lt.pat())?;
+            if let Some(ty) = lt.ty() {
+                traverse_ty(cx, visitor, ty)?;
+            }
             if let Some(init) = lt.init() {
                 traverse_expr(cx, visitor, init)?;
             }
@@ -234,6 +362,15 @@ pub fn traverse_expr<'ast, B>(
         },
         ExprKind::Closure(e) => {
             if let VisitorScope::AllBodies = visitor.scope() {
+                for param in e.params() {
+                    traverse_pat(cx, visitor, param.pat())?;
+                    if let Some(ty) = param.ty() {
+                        traverse_ty(cx, visitor, ty)?;
+                    }
+                }
+                if let Some(return_ty) = e.return_ty() {
+                    traverse_ty(cx, visitor, return_ty)?;
+                }
                 let body = cx.ast().body(e.body_id());
                 traverse_body(cx, visitor, body)?;
             }
@@ -252,10 +389,12 @@ pub fn traverse_expr<'ast, B>(
             traverse_expr(cx, visitor, e.expr())?;
         },
         ExprKind::Assign(e) => {
+            traverse_pat(cx, visitor, e.assignee())?;
             traverse_expr(cx, visitor, e.value())?;
         },
         ExprKind::As(e) => {
             traverse_expr(cx, visitor, e.expr())?;
+            traverse_ty(cx, visitor, e.cast_ty())?;
         },
         ExprKind::Call(e) => {
             traverse_expr(cx, visitor, e.func())?;
@@ -283,6 +422,7 @@ pub fn traverse_expr<'ast, B>(
             }
         },
         ExprKind::Ctor(e) => {
+            traverse_qpath(cx, visitor, e.path())?;
             for field in e.fields() {
                 traverse_expr(cx, visitor, field.expr())?;
             }
@@ -317,11 +457,13 @@ pub fn traverse_expr<'ast, B>(
             }
         },
         ExprKind::Let(e) => {
+            traverse_pat(cx, visitor, e.pat())?;
             traverse_expr(cx, visitor, e.scrutinee())?;
         },
         ExprKind::Match(e) => {
             traverse_expr(cx, visitor, e.scrutinee())?;
             for arm in e.arms() {
+                traverse_pat(cx, visitor, arm.pat())?;
                 if let Some(guard) = arm.guard() {
                     traverse_expr(cx, visitor, guard)?;
                 }
@@ -339,6 +481,7 @@ pub fn traverse_expr<'ast, B>(
             }
         },
         ExprKind::For(e) => {
+            traverse_pat(cx, visitor, e.pat())?;
             traverse_expr(cx, visitor, e.iterable())?;
             traverse_expr(cx, visitor, e.block())?;
         },
@@ -352,13 +495,18 @@ pub fn traverse_expr<'ast, B>(
         ExprKind::Await(e) => {
             traverse_expr(cx, visitor, e.expr())?;
         },
+        ExprKind::Yield(e) => {
+            traverse_expr(cx, visitor, e.expr())?;
+        },
+        ExprKind::Path(e) => {
+            traverse_qpath(cx, visitor, e.path())?;
+        },
         ExprKind::IntLit(_)
         | ExprKind::FloatLit(_)
         | ExprKind::StrLit(_)
         | ExprKind::CharLit(_)
         | ExprKind::BoolLit(_)
         | ExprKind::Unstable(_)
-        | ExprKind::Path(_)
         | ExprKind::Continue(_) => {
             // These expressions have no sub nodes, which are visited by this visitor
         },
@@ -368,6 +516,192 @@ pub fn traverse_expr<'ast, B>(
     ControlFlow::Continue(())
 }
 
+pub fn traverse_pat<'ast, B>(
+    cx: &'ast MarkerContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    pat: PatKind<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_pat(cx, pat)?;
+
+    match pat {
+        PatKind::Ident(p) => {
+            if let Some(binding_pat) = p.binding_pat() {
+                traverse_pat(cx, visitor, binding_pat)?;
+            }
+        },
+        PatKind::Ref(p) => {
+            traverse_pat(cx, visitor, p.pat())?;
+        },
+        PatKind::Struct(p) => {
+            traverse_qpath(cx, visitor, p.path())?;
+            for field in p.fields() {
+                traverse_pat(cx, visitor, field.pat())?;
+            }
+        },
+        PatKind::Tuple(p) => {
+            for el in p.elements() {
+                traverse_pat(cx, visitor, *el)?;
+            }
+        },
+        PatKind::Slice(p) => {
+            for el in p.elements() {
+                traverse_pat(cx, visitor, *el)?;
+            }
+        },
+        PatKind::Or(p) => {
+            for alt in p.pats() {
+                traverse_pat(cx, visitor, *alt)?;
+            }
+        },
+        PatKind::Place(p) => {
+            traverse_expr(cx, visitor, p.place())?;
+        },
+        PatKind::Range(p) => {
+            if let Some(start) = p.start() {
+                traverse_expr(cx, visitor, start)?;
+            }
+            if let Some(end) = p.end() {
+                traverse_expr(cx, visitor, end)?;
+            }
+        },
+        PatKind::Path(p) => {
+            traverse_qpath(cx, visitor, p.path())?;
+        },
+        PatKind::Wildcard(_) | PatKind::Rest(_) | PatKind::Lit(_) | PatKind::Unstable(_) => {
+            // These patterns have no sub nodes, which are visited by this visitor
+        },
+        _ => unreachable!("all patterns are covered"),
+    }
+
+    ControlFlow::Continue(())
+}
+
+pub fn traverse_ty<'ast, B>(
+    cx: &'ast MarkerContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    ty: TyKind<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_ty(cx, ty)?;
+
+    match ty {
+        TyKind::Tuple(t) => {
+            for ty in t.types() {
+                traverse_ty(cx, visitor, *ty)?;
+            }
+        },
+        TyKind::Array(t) => {
+            traverse_ty(cx, visitor, t.inner_ty())?;
+            if let Some(len) = t.len() {
+                traverse_expr(cx, visitor, len.expr())?;
+            }
+        },
+        TyKind::Slice(t) => {
+            traverse_ty(cx, visitor, t.inner_ty())?;
+        },
+        TyKind::Ref(t) => {
+            traverse_ty(cx, visitor, t.inner_ty())?;
+        },
+        TyKind::RawPtr(t) => {
+            traverse_ty(cx, visitor, t.inner_ty())?;
+        },
+        TyKind::FnPtr(t) => {
+            for param in t.params() {
+                traverse_ty(cx, visitor, param.ty())?;
+            }
+            if let Some(return_ty) = t.return_ty() {
+                traverse_ty(cx, visitor, *return_ty)?;
+            }
+        },
+        TyKind::TraitObj(t) => {
+            traverse_ty_param_bounds(cx, visitor, t.trait_bounds())?;
+        },
+        TyKind::ImplTrait(t) => {
+            traverse_ty_param_bounds(cx, visitor, t.trait_bounds())?;
+        },
+        TyKind::Path(t) => {
+            let path = t.path();
+            visitor.visit_path(cx, path.as_path_lossy(), path.resolve())?;
+            if let Some(self_ty) = path.self_ty() {
+                traverse_ty(cx, visitor, self_ty)?;
+            }
+            // The path type is not visited separately, since it's usually
+            // part of the segments, which are visited below.
+            for segment in path.segments() {
+                traverse_generic_args(cx, visitor, segment.generics())?;
+            }
+        },
+        TyKind::Bool(_) | TyKind::Num(_) | TyKind::Text(_) | TyKind::Never(_) | TyKind::Inferred(_) => {
+            // These types have no sub nodes, which are visited by this visitor
+        },
+        _ => unreachable!("all types are covered"),
+    }
+
+    ControlFlow::Continue(())
+}
+
+/// Visits the path of an [`AstQPath`] in expressions and patterns, including
+/// the generic arguments of its segments, like the turbofish in
+/// `Vec::<u8>::new()`. Type paths are handled by [`traverse_ty`].
+fn traverse_qpath<'ast, B>(
+    cx: &'ast MarkerContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    qpath: &'ast AstQPath<'ast>,
+) -> ControlFlow<B> {
+    visitor.visit_path(cx, qpath.as_path_lossy(), qpath.resolve())?;
+    if let Some(self_ty) = qpath.self_ty() {
+        traverse_ty(cx, visitor, self_ty)?;
+    }
+    for segment in qpath.segments() {
+        traverse_generic_args(cx, visitor, segment.generics())?;
+    }
+
+    ControlFlow::Continue(())
+}
+
+fn traverse_generic_params<'ast, B>(
+    cx: &'ast MarkerContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    generics: &'ast GenericParams<'ast>,
+) -> ControlFlow<B> {
+    for param in generics.params() {
+        visitor.visit_generic_param(cx, param, generics)?;
+    }
+
+    ControlFlow::Continue(())
+}
+
+fn traverse_ty_param_bounds<'ast, B>(
+    cx: &'ast MarkerContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    bounds: &'ast [TyParamBound<'ast>],
+) -> ControlFlow<B> {
+    for bound in bounds {
+        if let TyParamBound::TraitBound(bound) = bound {
+            traverse_generic_args(cx, visitor, bound.trait_ref().generics())?;
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
+fn traverse_generic_args<'ast, B>(
+    cx: &'ast MarkerContext<'ast>,
+    visitor: &mut dyn Visitor<B>,
+    args: &GenericArgs<'ast>,
+) -> ControlFlow<B> {
+    for arg in args.args() {
+        match arg {
+            GenericArgKind::Ty(arg) => traverse_ty(cx, visitor, arg.ty())?,
+            GenericArgKind::Binding(arg) => traverse_ty(cx, visitor, arg.ty())?,
+            GenericArgKind::Const(arg) => traverse_expr(cx, visitor, arg.expr().expr())?,
+            GenericArgKind::Lifetime(_) => {},
+            _ => unreachable!("all generic arguments are covered"),
+        }
+    }
+
+    ControlFlow::Continue(())
+}
+
 /// This trait is implemented for nodes, that can be traversed by a [`Visitor`].
 pub trait Traversable<'ast, B>
 where
@@ -442,6 +776,8 @@ impl_traversable_for!(ExprKind<'ast>, traverse_expr);
 impl_traversable_for!(StmtKind<'ast>, traverse_stmt);
 impl_traversable_for!(ItemKind<'ast>, traverse_item);
 impl_traversable_for!(&'ast ast::Body<'ast>, traverse_body);
+impl_traversable_for!(PatKind<'ast>, traverse_pat);
+impl_traversable_for!(TyKind<'ast>, traverse_ty);
 
 /// This trait extends the [`Traversable`] trait with more functions, specific to
 /// the `bool` return type.